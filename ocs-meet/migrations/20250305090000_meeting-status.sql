@@ -0,0 +1,3 @@
+ALTER TABLE meetings
+    ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'scheduled'
+    CONSTRAINT meetings_status_check CHECK (status IN ('scheduled', 'cancelled', 'ended'));
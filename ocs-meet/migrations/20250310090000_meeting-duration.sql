@@ -0,0 +1,3 @@
+ALTER TABLE meetings
+    ADD COLUMN IF NOT EXISTS duration_minutes INTEGER NOT NULL DEFAULT 60
+    CONSTRAINT meetings_duration_minutes_check CHECK (duration_minutes BETWEEN 1 AND 1440);
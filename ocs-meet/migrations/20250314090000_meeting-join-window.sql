@@ -0,0 +1,5 @@
+ALTER TABLE meetings
+    ADD COLUMN IF NOT EXISTS early_entry_minutes INTEGER NOT NULL DEFAULT 15
+    CONSTRAINT meetings_early_entry_minutes_check CHECK (early_entry_minutes BETWEEN 0 AND 240),
+    ADD COLUMN IF NOT EXISTS late_join_minutes INTEGER NOT NULL DEFAULT 0
+    CONSTRAINT meetings_late_join_minutes_check CHECK (late_join_minutes BETWEEN 0 AND 240);
@@ -0,0 +1,2 @@
+-- Listings are ordered by date then id, so the id completes the index to serve the cursor pagination
+CREATE INDEX IF NOT EXISTS meetings_studio_id_date_id_idx ON meetings (studio_id, date, id);
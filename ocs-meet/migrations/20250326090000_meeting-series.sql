@@ -0,0 +1,10 @@
+-- Recurring meetings, each occurrence is stored in meetings and points to its series
+CREATE TABLE IF NOT EXISTS meeting_series (
+    id UUID PRIMARY KEY NOT NULL,
+    studio_id UUID NOT NULL,
+    rrule TEXT NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL
+);
+
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS series_id UUID REFERENCES meeting_series (id);
+CREATE INDEX IF NOT EXISTS meetings_series_id_date_idx ON meetings (series_id, date);
@@ -0,0 +1,6 @@
+-- Secret tokens of the studio calendar subscriptions, only their SHA-256 is kept
+CREATE TABLE IF NOT EXISTS calendar_feeds (
+    studio_id UUID PRIMARY KEY NOT NULL,
+    token_hash BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS studios (
+    id UUID PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    timezone TEXT NOT NULL,
+    default_duration INTEGER NOT NULL CONSTRAINT studios_default_duration_check CHECK (default_duration BETWEEN 1 AND 1440),
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+-- The studios used so far only existed through their meetings, they keep working
+INSERT INTO studios (id, name, timezone, default_duration, created_at)
+SELECT studio_id, 'Studio', 'UTC', 60, MIN(date) FROM meetings GROUP BY studio_id
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS studio_members (
+    studio_id UUID NOT NULL REFERENCES studios (id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    display_name TEXT NULL,
+    role TEXT NOT NULL CONSTRAINT studio_members_role_check CHECK (role IN ('owner', 'admin', 'member', 'viewer')),
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (studio_id, user_id)
+);
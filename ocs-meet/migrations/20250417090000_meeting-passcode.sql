@@ -0,0 +1,9 @@
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS passcode_hash TEXT NULL;
+
+-- Wrong passcodes entered, counted for each caller to slow the guessing down
+CREATE TABLE IF NOT EXISTS meeting_passcode_failures (
+    meeting_id UUID NOT NULL REFERENCES meetings (id) ON DELETE CASCADE,
+    caller TEXT NOT NULL,
+    failed_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX IF NOT EXISTS meeting_passcode_failures_meeting_id_caller_failed_at_idx ON meeting_passcode_failures (meeting_id, caller, failed_at);
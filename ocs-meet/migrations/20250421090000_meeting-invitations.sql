@@ -0,0 +1,15 @@
+-- Invitations of guests without an account, their links carry the id signed by the server
+CREATE TABLE IF NOT EXISTS meeting_invitations (
+    id UUID PRIMARY KEY NOT NULL,
+    meeting_id UUID NOT NULL REFERENCES meetings (id) ON DELETE CASCADE,
+    studio_id UUID NOT NULL,
+    display_name TEXT NOT NULL,
+    role TEXT NOT NULL CONSTRAINT meeting_invitations_role_check CHECK (role IN ('host', 'speaker', 'viewer', 'recorder_bot')),
+    max_uses INTEGER NULL,
+    uses INTEGER NOT NULL DEFAULT 0,
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS meeting_invitations_meeting_id_idx ON meeting_invitations (meeting_id);
@@ -0,0 +1,16 @@
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS waiting_room BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Participants waiting for a host to let them in, along with the answer they got
+CREATE TABLE IF NOT EXISTS waiting_room_tickets (
+    id UUID PRIMARY KEY NOT NULL,
+    meeting_id UUID NOT NULL REFERENCES meetings (id) ON DELETE CASCADE,
+    studio_id UUID NOT NULL,
+    participant_identity TEXT NOT NULL,
+    participant_name TEXT NOT NULL,
+    role TEXT NOT NULL CONSTRAINT waiting_room_tickets_role_check CHECK (role IN ('host', 'speaker', 'viewer', 'recorder_bot')),
+    status TEXT NOT NULL CONSTRAINT waiting_room_tickets_status_check CHECK (status IN ('pending', 'admitted', 'denied')),
+    created_at TIMESTAMPTZ NOT NULL,
+    decided_at TIMESTAMPTZ NULL
+);
+
+CREATE INDEX IF NOT EXISTS waiting_room_tickets_meeting_id_created_at_idx ON waiting_room_tickets (meeting_id, created_at);
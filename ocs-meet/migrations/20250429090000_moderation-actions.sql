@@ -0,0 +1,15 @@
+-- Audit trail of what the hosts did to the participants of a meeting
+CREATE TABLE IF NOT EXISTS moderation_actions (
+    id UUID PRIMARY KEY NOT NULL,
+    meeting_id UUID NOT NULL REFERENCES meetings (id) ON DELETE CASCADE,
+    studio_id UUID NOT NULL,
+    performed_by TEXT NULL,
+    participant_identity TEXT NOT NULL,
+    action TEXT NOT NULL CONSTRAINT moderation_actions_action_check CHECK (action IN ('mute', 'update_permissions')),
+    track_source TEXT NULL CONSTRAINT moderation_actions_track_source_check CHECK (track_source IN ('microphone', 'camera', 'screen_share')),
+    can_publish BOOLEAN NULL,
+    can_publish_data BOOLEAN NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS moderation_actions_meeting_id_created_at_idx ON moderation_actions (meeting_id, created_at);
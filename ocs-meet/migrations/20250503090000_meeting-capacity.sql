@@ -0,0 +1,2 @@
+-- Participants the room of the meeting holds at once, without limit when null
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS max_participants INTEGER NULL CONSTRAINT meetings_max_participants_check CHECK (max_participants > 0);
@@ -0,0 +1,15 @@
+-- Meetings go live once somebody enters their room
+ALTER TABLE meetings DROP CONSTRAINT IF EXISTS meetings_status_check;
+ALTER TABLE meetings ADD CONSTRAINT meetings_status_check CHECK (status IN ('scheduled', 'live', 'cancelled', 'ended'));
+
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ NULL;
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS ended_at TIMESTAMPTZ NULL;
+
+-- Webhook events LiveKit delivered, kept so that its retries are only applied once
+CREATE TABLE IF NOT EXISTS room_events (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id UUID NOT NULL REFERENCES meetings (id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CONSTRAINT room_events_kind_check CHECK (kind IN ('room_started', 'room_finished', 'participant_joined', 'participant_left')),
+    occurred_at TIMESTAMPTZ NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,246 @@
+mod oidc;
+
+pub use oidc::OidcVerifier;
+
+use crate::{
+    domain::{
+        api_key::ApiKeyToken,
+        studio::StudioId,
+        user::{Identity, UserId},
+    },
+    ports::output::{
+        api_key_repository::ApiKeyRepository,
+        config::{JwtKey, OidcSettings},
+    },
+    services::{authenticate_api_key, AuthenticateApiKeyError},
+};
+use chrono::Utc;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::{future::Future, pin::Pin, sync::Arc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error(transparent)]
+    Token(#[from] jsonwebtoken::errors::Error),
+    #[error("The token is not signed by a known key")]
+    UnknownKey,
+    #[error("The claim {0} is missing or invalid")]
+    InvalidClaim(String),
+    #[error("Cannot reach the identity provider: {0}")]
+    Provider(String),
+    #[error(transparent)]
+    ApiKey(#[from] AuthenticateApiKeyError),
+}
+
+type ApiKeyFuture<'a> = Pin<Box<dyn Future<Output = Result<Identity, AuthError>> + Send + 'a>>;
+
+/// Looks the API keys up, kept object safe so the verifier does not depend on the repository type
+pub trait ApiKeyResolver: Send + Sync {
+    fn resolve<'a>(&'a self, token: &'a ApiKeyToken) -> ApiKeyFuture<'a>;
+}
+
+impl<R> ApiKeyResolver for R
+where
+    R: ApiKeyRepository + Send + Sync,
+{
+    fn resolve<'a>(&'a self, token: &'a ApiKeyToken) -> ApiKeyFuture<'a> {
+        Box::pin(async move {
+            let api_key = authenticate_api_key(self, token, Utc::now()).await?;
+            Ok(Identity {
+                studio_id: api_key.studio_id,
+                user_id: None,
+                display_name: None,
+                scopes: Some(api_key.scopes),
+                api_key_id: Some(api_key.id),
+            })
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct StudioClaims {
+    studio_id: uuid::Uuid,
+    sub: Option<String>,
+    name: Option<String>,
+}
+
+/// Checks the bearer tokens of the studios, shared with the extractors through the request data
+#[derive(Clone)]
+pub struct StudioTokenVerifier {
+    key: Option<(DecodingKey, Validation)>,
+    oidc: Option<OidcVerifier>,
+    api_keys: Option<Arc<dyn ApiKeyResolver>>,
+}
+
+impl StudioTokenVerifier {
+    pub fn new(key: Option<&JwtKey>, oidc: Option<OidcSettings>) -> Result<Self, AuthError> {
+        let key = match key {
+            Some(JwtKey::Hs256(secret)) => Some((
+                DecodingKey::from_secret(secret.as_bytes()),
+                Algorithm::HS256,
+            )),
+            Some(JwtKey::Rs256(public_key)) => Some((
+                DecodingKey::from_rsa_pem(public_key.as_bytes())?,
+                Algorithm::RS256,
+            )),
+            None => None,
+        };
+
+        // Only the configured algorithm is accepted, and tokens must expire
+        let key = key.map(|(key, algorithm)| {
+            let mut validation = Validation::new(algorithm);
+            validation.set_required_spec_claims(&["exp"]);
+            (key, validation)
+        });
+
+        Ok(StudioTokenVerifier {
+            key,
+            oidc: oidc.map(OidcVerifier::new),
+            api_keys: None,
+        })
+    }
+
+    /// Accepts the API keys of the studios next to the signed tokens
+    pub fn with_api_keys(mut self, api_keys: Arc<dyn ApiKeyResolver>) -> Self {
+        self.api_keys = Some(api_keys);
+        self
+    }
+
+    /// The identity the token has been issued for, if it is signed by a trusted key and not expired
+    ///
+    /// API keys are recognized by their prefix, tokens carrying a key id are checked against the
+    /// identity provider when there is one, and the others against the configured key.
+    pub async fn verify(&self, token: &str) -> Result<Identity, AuthError> {
+        if let Some(api_key) = ApiKeyToken::parse(token) {
+            return match &self.api_keys {
+                Some(api_keys) => api_keys.resolve(&api_key).await,
+                None => Err(AuthError::UnknownKey),
+            };
+        }
+
+        if let Some(oidc) = &self.oidc {
+            if decode_header(token)?.kid.is_some() {
+                return oidc.verify(token).await;
+            }
+        }
+
+        let Some((key, validation)) = &self.key else {
+            return Err(AuthError::UnknownKey);
+        };
+
+        let token = decode::<StudioClaims>(token, key, validation)?;
+        Ok(Identity {
+            studio_id: StudioId::from(token.claims.studio_id),
+            user_id: token.claims.sub.map(UserId::from),
+            display_name: token.claims.name,
+            scopes: None,
+            api_key_id: None,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::StudioTokenVerifier;
+    use crate::{
+        domain::{studio::StudioId, user::UserId},
+        ports::output::config::JwtKey,
+    };
+    use chrono::{TimeDelta, Utc};
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use serde::Serialize;
+
+    pub(crate) const TEST_SECRET: &str = "a secret only known by the tests";
+
+    #[derive(Serialize)]
+    struct Claims {
+        studio_id: uuid::Uuid,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sub: Option<String>,
+        exp: i64,
+    }
+
+    fn token(studio_id: &StudioId, sub: Option<&str>, validity: TimeDelta) -> String {
+        encode(
+            &Header::default(),
+            &Claims {
+                studio_id: *studio_id.as_ref(),
+                sub: sub.map(str::to_string),
+                exp: (Utc::now() + validity).timestamp(),
+            },
+            &EncodingKey::from_secret(TEST_SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    /// Token of the studio signed with the test secret, expiring after `validity`
+    pub(crate) fn studio_token(studio_id: &StudioId, validity: TimeDelta) -> String {
+        token(studio_id, None, validity)
+    }
+
+    /// Token of a user of the studio signed with the test secret, valid for an hour
+    pub(crate) fn user_token(studio_id: &StudioId, user_id: &str) -> String {
+        token(studio_id, Some(user_id), TimeDelta::hours(1))
+    }
+
+    pub(crate) fn test_verifier() -> StudioTokenVerifier {
+        StudioTokenVerifier::new(Some(&JwtKey::Hs256(TEST_SECRET.to_string())), None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_hs256() {
+        let verifier = test_verifier();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let token = studio_token(&studio_id, TimeDelta::hours(1));
+        let identity = verifier.verify(&token).await.unwrap();
+        assert_eq!(identity.studio_id, studio_id);
+        assert_eq!(identity.user_id, None);
+
+        let identity = verifier
+            .verify(&user_token(&studio_id, "alice"))
+            .await
+            .unwrap();
+        assert_eq!(identity.user_id, Some(UserId::from("alice".to_string())));
+
+        let expired = studio_token(&studio_id, -TimeDelta::hours(1));
+        assert!(verifier.verify(&expired).await.is_err());
+
+        let other_key =
+            StudioTokenVerifier::new(Some(&JwtKey::Hs256("another".to_string())), None).unwrap();
+        assert!(other_key.verify(&token).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rs256() {
+        let public_key = include_str!("../../../../../fixtures/jwt-rs256-public.pem");
+        let private_key = include_str!("../../../../../fixtures/jwt-rs256-private.pem");
+        let verifier =
+            StudioTokenVerifier::new(Some(&JwtKey::Rs256(public_key.to_string())), None).unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let claims = Claims {
+            studio_id: *studio_id.as_ref(),
+            sub: None,
+            exp: (Utc::now() + TimeDelta::hours(1)).timestamp(),
+        };
+        let token = encode(
+            &Header::new(Algorithm::RS256),
+            &claims,
+            &EncodingKey::from_rsa_pem(private_key.as_bytes()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(verifier.verify(&token).await.unwrap().studio_id, studio_id);
+
+        // A token signed with the public key as an HMAC secret must not pass
+        let forged = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(public_key.as_bytes()),
+        )
+        .unwrap();
+        assert!(verifier.verify(&forged).await.is_err());
+    }
+}
@@ -0,0 +1,386 @@
+use super::AuthError;
+use crate::{
+    domain::{
+        studio::StudioId,
+        user::{Identity, UserId},
+    },
+    ports::output::config::OidcSettings,
+};
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{Jwk, JwkSet},
+    Algorithm, DecodingKey, Validation,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::{
+    str::FromStr,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+/// How long the keys of the provider are used before being fetched again
+const KEYS_TTL: Duration = Duration::from_secs(60 * 60);
+/// Shortest delay between two fetches caused by unknown key ids, so forged tokens cannot flood the provider
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+struct Discovery {
+    issuer: String,
+    jwks_uri: String,
+}
+
+#[derive(Default)]
+struct KeyCache {
+    jwks_uri: Option<String>,
+    keys: Option<JwkSet>,
+    fetched_at: Option<Instant>,
+}
+
+/// Checks the tokens of an OpenID Connect provider against the keys it publishes
+///
+/// The discovery document is fetched once, the key set is kept for `KEYS_TTL` and fetched
+/// again earlier when a token is signed by a key it does not contain yet, as providers rotate
+/// their keys by publishing the new one before using it.
+#[derive(Clone)]
+pub struct OidcVerifier {
+    settings: Arc<OidcSettings>,
+    client: reqwest::Client,
+    cache: Arc<RwLock<KeyCache>>,
+    min_refresh_interval: Duration,
+}
+
+impl OidcVerifier {
+    pub fn new(settings: OidcSettings) -> Self {
+        OidcVerifier {
+            settings: Arc::new(settings),
+            client: reqwest::Client::new(),
+            cache: Arc::default(),
+            min_refresh_interval: MIN_REFRESH_INTERVAL,
+        }
+    }
+
+    pub async fn verify(&self, token: &str) -> Result<Identity, AuthError> {
+        let header = decode_header(token)?;
+        let Some(kid) = header.kid else {
+            return Err(AuthError::UnknownKey);
+        };
+
+        let jwk = self.find_key(&kid).await?;
+
+        // The key decides the algorithm when it is pinned, the family of the key is checked anyway
+        if let Some(key_algorithm) = jwk.common.key_algorithm {
+            if Algorithm::from_str(&key_algorithm.to_string()).ok() != Some(header.alg) {
+                return Err(AuthError::UnknownKey);
+            }
+        }
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.settings.issuer]);
+        validation.set_audience(&[&self.settings.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+
+        let claims =
+            decode::<Map<String, Value>>(token, &DecodingKey::from_jwk(&jwk)?, &validation)?.claims;
+
+        let studio_claim = &self.settings.studio_claim;
+        let studio_id = claims
+            .get(studio_claim)
+            .and_then(Value::as_str)
+            .and_then(|studio_id| uuid::Uuid::from_str(studio_id).ok())
+            .ok_or_else(|| AuthError::InvalidClaim(studio_claim.clone()))?;
+
+        let user_claim = &self.settings.user_claim;
+        let user_id = claims
+            .get(user_claim)
+            .and_then(Value::as_str)
+            .ok_or_else(|| AuthError::InvalidClaim(user_claim.clone()))?;
+
+        Ok(Identity {
+            studio_id: StudioId::from(studio_id),
+            user_id: Some(UserId::from(user_id.to_string())),
+            display_name: claims
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            scopes: None,
+            api_key_id: None,
+        })
+    }
+
+    async fn find_key(&self, kid: &str) -> Result<Jwk, AuthError> {
+        let (jwks_uri, expired) = {
+            let cache = self.cache.read().expect("The key cache lock is poisoned");
+            let age = cache.fetched_at.map(|fetched_at| fetched_at.elapsed());
+
+            if let Some(key) = cache.keys.as_ref().and_then(|keys| keys.find(kid)) {
+                if age.is_some_and(|age| age < KEYS_TTL) {
+                    return Ok(key.clone());
+                }
+            } else if age.is_some_and(|age| age < self.min_refresh_interval) {
+                return Err(AuthError::UnknownKey);
+            }
+
+            (cache.jwks_uri.clone(), age.is_some())
+        };
+
+        let jwks_uri = match jwks_uri {
+            Some(jwks_uri) => jwks_uri,
+            None => self.discover().await?,
+        };
+
+        let keys = match self.fetch::<JwkSet>(&jwks_uri).await {
+            Ok(keys) => keys,
+            // A provider down keeps the previous keys usable until the next refresh
+            Err(err) if expired => {
+                let cache = self.cache.read().expect("The key cache lock is poisoned");
+                return match cache.keys.as_ref().and_then(|keys| keys.find(kid)) {
+                    Some(key) => Ok(key.clone()),
+                    None => Err(err),
+                };
+            }
+            Err(err) => return Err(err),
+        };
+
+        let key = keys.find(kid).cloned();
+
+        let mut cache = self.cache.write().expect("The key cache lock is poisoned");
+        cache.jwks_uri = Some(jwks_uri);
+        cache.keys = Some(keys);
+        cache.fetched_at = Some(Instant::now());
+
+        key.ok_or(AuthError::UnknownKey)
+    }
+
+    /// Location of the key set, read from the discovery document of the issuer
+    async fn discover(&self) -> Result<String, AuthError> {
+        let issuer = &self.settings.issuer;
+        let discovery = self
+            .fetch::<Discovery>(&format!(
+                "{}/.well-known/openid-configuration",
+                issuer.trim_end_matches('/')
+            ))
+            .await?;
+
+        if &discovery.issuer != issuer {
+            return Err(AuthError::Provider(format!(
+                "the discovery document is issued by {}",
+                discovery.issuer
+            )));
+        }
+
+        Ok(discovery.jwks_uri)
+    }
+
+    async fn fetch<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, AuthError> {
+        let response = self
+            .client
+            .get(url)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|err| AuthError::Provider(err.to_string()))?;
+
+        response
+            .json()
+            .await
+            .map_err(|err| AuthError::Provider(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OidcVerifier;
+    use crate::{
+        adapters::input::http::auth::AuthError,
+        domain::{studio::StudioId, user::UserId},
+        ports::output::config::OidcSettings,
+    };
+    use chrono::{TimeDelta, Utc};
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use poem::{
+        endpoint::make_sync,
+        get,
+        listener::{Acceptor, Listener, TcpListener},
+        web::Json,
+        Response, Route, Server,
+    };
+    use serde_json::json;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+
+    const JWKS: &str = include_str!("../../../../../fixtures/oidc-jwks.json");
+    const PRIVATE_KEY: &str = include_str!("../../../../../fixtures/jwt-rs256-private.pem");
+    const STUDIO_CLAIM: &str = "https://meet.example.com/studio";
+
+    /// Issuer served on a random local port, publishing the key set it has been given
+    struct StubIssuer {
+        issuer: String,
+        jwks: Arc<Mutex<String>>,
+        jwks_requests: Arc<AtomicUsize>,
+    }
+
+    impl StubIssuer {
+        async fn start(jwks: &str) -> StubIssuer {
+            let acceptor = TcpListener::bind("127.0.0.1:0")
+                .into_acceptor()
+                .await
+                .unwrap();
+            let address = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+            let issuer = format!("http://{address}");
+
+            let stub = StubIssuer {
+                issuer: issuer.clone(),
+                jwks: Arc::new(Mutex::new(jwks.to_string())),
+                jwks_requests: Arc::default(),
+            };
+
+            let (served_jwks, jwks_requests) = (stub.jwks.clone(), stub.jwks_requests.clone());
+            let route = Route::new()
+                .at(
+                    "/.well-known/openid-configuration",
+                    get(make_sync(move |_| {
+                        Json(json!({
+                            "issuer": issuer,
+                            "jwks_uri": format!("{issuer}/jwks.json"),
+                        }))
+                    })),
+                )
+                .at(
+                    "/jwks.json",
+                    get(make_sync(move |_| {
+                        jwks_requests.fetch_add(1, Ordering::SeqCst);
+                        Response::builder()
+                            .content_type("application/json")
+                            .body(served_jwks.lock().unwrap().clone())
+                    })),
+                );
+            tokio::spawn(Server::new_with_acceptor(acceptor).run(route));
+
+            stub
+        }
+
+        fn verifier(&self) -> OidcVerifier {
+            OidcVerifier::new(OidcSettings {
+                issuer: self.issuer.clone(),
+                audience: "ocs-meet".to_string(),
+                studio_claim: STUDIO_CLAIM.to_string(),
+                user_claim: "sub".to_string(),
+            })
+        }
+
+        fn token(&self, claims: serde_json::Value) -> String {
+            let mut header = Header::new(Algorithm::RS256);
+            header.kid = Some("test-key-1".to_string());
+
+            let mut token_claims = json!({
+                "iss": self.issuer,
+                "aud": "ocs-meet",
+                "sub": "user-42",
+                "exp": (Utc::now() + TimeDelta::hours(1)).timestamp(),
+            });
+            token_claims
+                .as_object_mut()
+                .unwrap()
+                .extend(claims.as_object().unwrap().clone());
+
+            encode(
+                &header,
+                &token_claims,
+                &EncodingKey::from_rsa_pem(PRIVATE_KEY.as_bytes()).unwrap(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_ok() {
+        let stub = StubIssuer::start(JWKS).await;
+        let verifier = stub.verifier();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let token = stub.token(json!({ STUDIO_CLAIM: studio_id.as_ref().to_string() }));
+        let identity = verifier.verify(&token).await.unwrap();
+        assert_eq!(identity.studio_id, studio_id);
+        assert_eq!(identity.user_id, Some(UserId::from("user-42".to_string())));
+
+        // The keys are cached
+        verifier.verify(&token).await.unwrap();
+        assert_eq!(stub.jwks_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejected_claims() {
+        let stub = StubIssuer::start(JWKS).await;
+        let verifier = stub.verifier();
+        let studio_id = studio_claim();
+
+        for claims in [
+            json!({ STUDIO_CLAIM: studio_id, "aud": "another-app" }),
+            json!({ STUDIO_CLAIM: studio_id, "iss": "https://evil.example.com" }),
+            json!({ STUDIO_CLAIM: studio_id, "exp": (Utc::now() - TimeDelta::hours(1)).timestamp() }),
+        ] {
+            let Err(AuthError::Token(_)) = verifier.verify(&stub.token(claims)).await else {
+                panic!("The token should be rejected")
+            };
+        }
+
+        let Err(AuthError::InvalidClaim(claim)) = verifier
+            .verify(&stub.token(json!({ STUDIO_CLAIM: "not a uuid" })))
+            .await
+        else {
+            panic!("The token should not grant any studio")
+        };
+        assert_eq!(claim, STUDIO_CLAIM);
+    }
+
+    #[tokio::test]
+    async fn test_key_rotation() {
+        let stub = StubIssuer::start(r#"{"keys":[]}"#).await;
+        let mut verifier = stub.verifier();
+        let token = stub.token(json!({ STUDIO_CLAIM: studio_claim() }));
+
+        let Err(AuthError::UnknownKey) = verifier.verify(&token).await else {
+            panic!("The key is not published yet")
+        };
+
+        // Unknown keys do not cause a fetch on every token
+        *stub.jwks.lock().unwrap() = JWKS.to_string();
+        let Err(AuthError::UnknownKey) = verifier.verify(&token).await else {
+            panic!("The keys should not be fetched again so soon")
+        };
+        assert_eq!(stub.jwks_requests.load(Ordering::SeqCst), 1);
+
+        verifier.min_refresh_interval = Duration::ZERO;
+        verifier.verify(&token).await.unwrap();
+        assert_eq!(stub.jwks_requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_provider_unreachable() {
+        let verifier = OidcVerifier::new(OidcSettings {
+            issuer: "http://127.0.0.1:1".to_string(),
+            audience: "ocs-meet".to_string(),
+            studio_claim: STUDIO_CLAIM.to_string(),
+            user_claim: "sub".to_string(),
+        });
+        let stub = StubIssuer::start(JWKS).await;
+
+        let Err(AuthError::Provider(_)) = verifier
+            .verify(&stub.token(json!({ STUDIO_CLAIM: studio_claim() })))
+            .await
+        else {
+            panic!("The keys cannot be fetched")
+        };
+    }
+
+    fn studio_claim() -> String {
+        uuid::Uuid::new_v4().to_string()
+    }
+}
@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use crate::{domain::meeting::MeetingId, ports::output::meeting_repository::MeetingCursor};
+use anyhow::Context;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::DateTime;
+use poem_openapi::registry::MetaSchemaRef;
+use poem_openapi::types::{
+    ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type,
+};
+use uuid::Uuid;
+
+/// Cursors are opaque to clients: the date in microseconds and the id, base64 encoded
+fn encode(cursor: &MeetingCursor) -> String {
+    let raw = format!("{}:{}", cursor.date.timestamp_micros(), cursor.id.as_ref());
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+fn decode(value: &str) -> anyhow::Result<MeetingCursor> {
+    let raw = URL_SAFE_NO_PAD
+        .decode(value)
+        .context("Cannot decode cursor")?;
+    let raw = String::from_utf8(raw).context("Cannot decode cursor")?;
+    let (micros, id) = raw.split_once(':').context("Malformed cursor")?;
+    let micros = i64::from_str(micros).context("Malformed cursor date")?;
+    let date = DateTime::from_timestamp_micros(micros).context("Malformed cursor date")?;
+    let id = Uuid::from_str(id).context("Malformed cursor id")?;
+    Ok(MeetingCursor {
+        date,
+        id: MeetingId::from(id),
+    })
+}
+
+impl Type for MeetingCursor {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("MeetingCursor")
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        String::schema_ref()
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromParameter for MeetingCursor {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Ok(decode(value)?)
+    }
+}
+
+impl ParseFromJSON for MeetingCursor {
+    fn parse_from_json(value: Option<serde_json::Value>) -> ParseResult<Self> {
+        match value {
+            Some(serde_json::Value::String(value)) => Ok(decode(&value)?),
+            value => Err(ParseError::expected_type(value.unwrap_or_default())),
+        }
+    }
+}
+
+impl ToJSON for MeetingCursor {
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(serde_json::Value::String(encode(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+    use crate::{domain::meeting::MeetingId, ports::output::meeting_repository::MeetingCursor};
+    use chrono::{DateTime, Utc};
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = MeetingCursor {
+            date: DateTime::<Utc>::from_timestamp_micros(1_742_630_400_123_456).unwrap(),
+            id: MeetingId::new(),
+        };
+        assert_eq!(decode(&encode(&cursor)).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_cursor_garbage() {
+        assert!(decode("not a cursor").is_err());
+        assert!(decode(&encode_raw("12:not-a-uuid")).is_err());
+    }
+
+    fn encode_raw(raw: &str) -> String {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+}
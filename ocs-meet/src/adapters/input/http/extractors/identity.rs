@@ -0,0 +1,215 @@
+use crate::adapters::input::http::auth::{AuthError, StudioTokenVerifier};
+use crate::domain::user::Identity;
+use crate::services::AuthenticateApiKeyError;
+use poem::{http::StatusCode, Request, RequestBody};
+use poem_openapi::registry::{MetaSecurityScheme, Registry};
+use poem_openapi::{ApiExtractor, ApiExtractorType, ExtractParamOptions};
+
+const SECURITY_SCHEME: &str = "StudioToken";
+
+fn register_security_scheme(registry: &mut Registry) {
+    registry.create_security_scheme(
+        SECURITY_SCHEME,
+        MetaSecurityScheme {
+            ty: "http",
+            description: Some(
+                "JWT signed for the studio, carrying its id in `studio_id`, or an API key of the studio",
+            ),
+            name: None,
+            key_in: None,
+            scheme: Some("bearer"),
+            bearer_format: Some("JWT"),
+            flows: None,
+            openid_connect_url: None,
+        },
+    );
+}
+
+/// Checks the bearer token of the request with the verifier shared through the request data
+async fn authenticate(request: &Request) -> poem::Result<Identity> {
+    let Some(token) = request
+        .header("authorization")
+        .and_then(|authorization| authorization.split_once(' '))
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+        .map(|(_, token)| token.trim())
+    else {
+        return Err(poem::Error::from_status(StatusCode::UNAUTHORIZED));
+    };
+
+    let Some(verifier) = request.data::<StudioTokenVerifier>() else {
+        return Err(poem::Error::from_string(
+            "No token verifier configured",
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ));
+    };
+
+    match verifier.verify(token).await {
+        Ok(identity) => Ok(identity),
+        Err(AuthError::Provider(_)) => {
+            Err(poem::Error::from_status(StatusCode::SERVICE_UNAVAILABLE))
+        }
+        Err(AuthError::ApiKey(AuthenticateApiKeyError::ApiKeyRepository(_))) => {
+            Err(poem::Error::from_status(StatusCode::INTERNAL_SERVER_ERROR))
+        }
+        Err(_) => Err(poem::Error::from_status(StatusCode::UNAUTHORIZED)),
+    }
+}
+
+/// Who sends the request, what they may do on their studio is up to the `authorize` service
+impl<'a> ApiExtractor<'a> for Identity {
+    const TYPES: &'static [ApiExtractorType] = &[ApiExtractorType::SecurityScheme];
+
+    type ParamType = ();
+    type ParamRawType = ();
+
+    fn register(registry: &mut Registry) {
+        register_security_scheme(registry);
+    }
+
+    fn security_schemes() -> Vec<&'static str> {
+        vec![SECURITY_SCHEME]
+    }
+
+    async fn from_request(
+        request: &'a Request,
+        _body: &mut RequestBody,
+        _param_opts: ExtractParamOptions<Self::ParamType>,
+    ) -> poem::Result<Self> {
+        authenticate(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::input::http::auth::tests::{studio_token, test_verifier},
+        domain::{
+            api_key::{ApiKey, ApiKeyId, ApiKeyScope, ApiKeyToken},
+            studio::StudioId,
+            user::Identity,
+        },
+        ports::output::api_key_repository::MockApiKeyRepository,
+    };
+    use chrono::{TimeDelta, Utc};
+    use mockall::predicate::eq;
+    use poem::{http::StatusCode, test::TestClient, Endpoint, EndpointExt};
+    use poem_openapi::OpenApi;
+    use std::sync::Arc;
+
+    struct TestRouter {}
+
+    #[OpenApi]
+    impl TestRouter {
+        #[oai(path = "/test", method = "get")]
+        async fn test(&self, _identity: Identity) {}
+    }
+
+    fn client() -> TestClient<impl Endpoint> {
+        let api_service = poem_openapi::OpenApiService::new(TestRouter {}, "API", "1.0");
+        TestClient::new(api_service.data(test_verifier()))
+    }
+
+    #[tokio::test]
+    async fn test_auth_ok() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let res = client()
+            .get("/test")
+            .header(
+                "authorization",
+                format!("Bearer {}", studio_token(&studio_id, TimeDelta::hours(1))),
+            )
+            .send()
+            .await;
+        res.assert_status_is_ok();
+    }
+
+    #[tokio::test]
+    async fn test_auth_ko() {
+        let res = client().get("/test").send().await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_auth_expired() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let res = client()
+            .get("/test")
+            .header(
+                "authorization",
+                format!("Bearer {}", studio_token(&studio_id, -TimeDelta::hours(1))),
+            )
+            .send()
+            .await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_auth_plain_studio_id() {
+        let res = client()
+            .get("/test")
+            .header("authorization", format!("Bearer {}", uuid::Uuid::new_v4()))
+            .send()
+            .await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_security_scheme_in_spec() {
+        let api_service = poem_openapi::OpenApiService::new(TestRouter {}, "API", "1.0");
+        let spec = api_service.spec();
+        assert!(spec.contains("\"StudioToken\""));
+        assert!(spec.contains("\"bearer\""));
+    }
+
+    #[tokio::test]
+    async fn test_api_key() {
+        let token = ApiKeyToken::generate();
+
+        let mut mock_repo = MockApiKeyRepository::new();
+        mock_repo
+            .expect_find_api_key_by_hash()
+            .once()
+            .with(eq(token.hash()))
+            .returning(|token_hash| {
+                let token_hash = token_hash.clone();
+                Box::pin(async move {
+                    Ok(Some(ApiKey {
+                        id: ApiKeyId::new(),
+                        studio_id: StudioId::from(uuid::Uuid::new_v4()),
+                        name: "Dashboard".to_string(),
+                        token_hash,
+                        scopes: vec![ApiKeyScope::Read],
+                        expires_at: None,
+                        created_at: Utc::now(),
+                    }))
+                })
+            });
+        mock_repo
+            .expect_find_api_key_by_hash()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let verifier = test_verifier().with_api_keys(Arc::new(mock_repo));
+        let api_service = poem_openapi::OpenApiService::new(TestRouter {}, "API", "1.0");
+        let client = TestClient::new(api_service.data(verifier));
+        let authorization = format!("Bearer {}", token.as_ref());
+
+        let res = client
+            .get("/test")
+            .header("authorization", &authorization)
+            .send()
+            .await;
+        res.assert_status_is_ok();
+
+        let res = client
+            .get("/test")
+            .header(
+                "authorization",
+                format!("Bearer {}", ApiKeyToken::generate().as_ref()),
+            )
+            .send()
+            .await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+    }
+}
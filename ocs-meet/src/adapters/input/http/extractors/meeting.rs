@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::domain::meeting::MeetingId;
+use crate::domain::meeting::{MeetingId, MeetingSeriesId};
 use anyhow::Context;
 use poem_openapi::registry::MetaSchemaRef;
 use poem_openapi::types::{ParseFromParameter, ParseResult, Type};
@@ -39,6 +39,39 @@ impl ParseFromParameter for MeetingId {
     }
 }
 
+impl Type for MeetingSeriesId {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = uuid::Uuid;
+
+    type RawElementValueType = uuid::Uuid;
+
+    fn name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("MeetingSeriesId")
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        uuid::Uuid::schema_ref()
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Uuid::raw_element_iter(&self.0)
+    }
+}
+
+impl ParseFromParameter for MeetingSeriesId {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        let uuid = uuid::Uuid::from_str(value).context("Cannot parse uuid")?;
+        Ok(MeetingSeriesId::from(uuid))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::domain::meeting::MeetingId;
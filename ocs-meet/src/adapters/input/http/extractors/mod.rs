@@ -1,2 +1,6 @@
+pub mod api_key;
+pub mod cursor;
+pub mod identity;
+pub mod invitation;
 pub mod meeting;
-pub mod studio;
+pub mod waiting_room;
@@ -0,0 +1,218 @@
+use super::super::tags::ApiTags;
+use crate::{
+    adapters::input::http::models::api_key::{
+        CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeysResponse,
+    },
+    domain::{
+        api_key::{ApiKeyId, ApiKeyScope},
+        member::Permission,
+        user::Identity,
+    },
+    ports::output::{api_key_repository::ApiKeyRepository, member_repository::MemberRepository},
+    services::{authorize, create_api_key, list_api_keys, revoke_api_key},
+};
+use chrono::Utc;
+use poem::Result;
+use poem_openapi::{param::Path, payload::Json, OpenApi};
+use std::sync::Arc;
+
+pub struct ApiKeyRouter<R> {
+    pub repository: Arc<R>,
+}
+
+/// Managed by the owners and admins, API keys never manage the studio so a leaked key cannot mint
+/// new ones
+#[OpenApi]
+impl<R> ApiKeyRouter<R>
+where
+    R: ApiKeyRepository + MemberRepository + Send + Sync + 'static,
+{
+    /// Creates an API key for the studio, its secret is only part of this response
+    #[oai(path = "/api-keys", method = "post", tag = "ApiTags::ApiKey")]
+    pub async fn create_api_key(
+        &self,
+        identity: Identity,
+        Json(body): Json<CreateApiKeyRequest>,
+    ) -> Result<Json<CreateApiKeyResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let scopes = body.scopes.into_iter().map(ApiKeyScope::from).collect();
+        let created = create_api_key(
+            self.repository.as_ref(),
+            studio_id,
+            body.name,
+            scopes,
+            body.expires_at,
+            today,
+        )
+        .await?;
+        Ok(Json(created.into()))
+    }
+
+    #[oai(path = "/api-keys", method = "get", tag = "ApiTags::ApiKey")]
+    pub async fn list_api_keys(&self, identity: Identity) -> Result<Json<ListApiKeysResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?
+        .studio_id;
+        let api_keys = list_api_keys(self.repository.as_ref(), studio_id).await?;
+        Ok(Json(api_keys.into()))
+    }
+
+    /// Revokes the key, requests using it are rejected right away
+    #[oai(
+        path = "/api-keys/:api-key-id",
+        method = "delete",
+        tag = "ApiTags::ApiKey"
+    )]
+    pub async fn revoke_api_key(
+        &self,
+        identity: Identity,
+        Path(api_key_id): Path<ApiKeyId>,
+    ) -> Result<()> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?
+        .studio_id;
+        revoke_api_key(self.repository.as_ref(), studio_id, api_key_id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::input::http::auth::tests::{studio_token, TEST_SECRET},
+        domain::{api_key::ApiKey, studio::StudioId},
+        ports::output::{
+            config::{JwtKey, MockConfig},
+            repository::MockRepository,
+            room_manager::MockRoomManager,
+        },
+    };
+    use chrono::{TimeDelta, Utc};
+    use poem::{http::StatusCode, test::TestClient};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    fn config() -> MockConfig {
+        let mut config = MockConfig::new();
+        config
+            .expect_swagger_uri()
+            .returning(|| url::Url::parse("http://localhost:8000").unwrap());
+        config
+            .expect_jwt_key()
+            .returning(|| Some(JwtKey::Hs256(TEST_SECRET.to_string())));
+        config.expect_oidc().returning(|| None);
+        config
+            .expect_invitation_secret()
+            .returning(|| "an invitation secret".to_string());
+        config
+            .expect_livekit_api_key()
+            .returning(|| "a livekit key".to_string());
+        config
+            .expect_livekit_secret()
+            .returning(|| "a livekit secret".to_string());
+        config
+    }
+
+    #[tokio::test]
+    async fn test_created_key_authenticates() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let saved: Arc<Mutex<Option<ApiKey>>> = Arc::default();
+
+        let mut mock_repo = MockRepository::new();
+        let created = saved.clone();
+        mock_repo
+            .expect_create_api_key()
+            .once()
+            .returning(move |api_key| {
+                *created.lock().unwrap() = Some(api_key.clone());
+                Box::pin(async { Ok(()) })
+            });
+        let found = saved.clone();
+        mock_repo
+            .expect_find_api_key_by_hash()
+            .returning(move |token_hash| {
+                let api_key = found
+                    .lock()
+                    .unwrap()
+                    .clone()
+                    .filter(|api_key| &api_key.token_hash == token_hash);
+                Box::pin(async move { Ok(api_key) })
+            });
+        mock_repo.expect_list_api_keys().once().returning(move |_| {
+            let api_keys = saved.lock().unwrap().clone().into_iter().collect();
+            Box::pin(async move { Ok(api_keys) })
+        });
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+
+        let res = cli
+            .post("/api/api-keys")
+            .header(
+                "authorization",
+                format!("Bearer {}", studio_token(&studio_id, TimeDelta::hours(1))),
+            )
+            .body_json(&json!({
+                "name": "Booking backend",
+                "scopes": ["read"],
+                "expires_at": (Utc::now() + TimeDelta::days(30)).to_rfc3339(),
+            }))
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        let body = res.json().await;
+        let key = body.value().object().get("key").string().to_string();
+
+        let res = cli
+            .get("/api/meetings")
+            .header("authorization", format!("Bearer {key}"))
+            .send()
+            .await;
+        res.assert_status_is_ok();
+
+        // The key cannot manage the keys itself
+        let res = cli
+            .get("/api/api-keys")
+            .header("authorization", format!("Bearer {key}"))
+            .send()
+            .await;
+        res.assert_status(StatusCode::FORBIDDEN);
+
+        let res = cli
+            .get("/api/api-keys")
+            .header(
+                "authorization",
+                format!("Bearer {}", studio_token(&studio_id, TimeDelta::hours(1))),
+            )
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        let body = res.json().await;
+        let listed = body.value().object().get("api_keys").array();
+        listed.assert_len(1);
+        let listed_key = listed.get(0).object();
+        listed_key.get("scopes").array().assert_len(1);
+        assert!(listed_key.get_opt("key").is_none());
+    }
+}
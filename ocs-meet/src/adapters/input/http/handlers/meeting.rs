@@ -1,47 +1,496 @@
 use super::super::tags::ApiTags;
 use crate::{
+    adapters::input::http::models::calendar::{
+        parse_calendar, render_calendar, CalendarFeedResponse, CalendarResponse,
+        ImportCalendarRequest, ImportMeetingsResponse,
+    },
+    adapters::input::http::models::invitation::{
+        CreateInvitationRequest, CreateInvitationResponse,
+    },
     adapters::input::http::models::meeting::{
-        CreateMeetingRequest, CreateMeetingResponse, JoinMeetingResponse, ListMeetingsResponse,
+        CancelMeetingResponse, CancelMeetingSeriesResponse, CreateMeetingRequest,
+        CreateMeetingResponse, CreateMeetingSeriesRequest, CreateMeetingSeriesResponse,
+        EndMeetingResponse, GetMeetingResponse, JoinResponse, ListMeetingsResponse,
+        MeetingOrderHttp, ParticipantRoleHttp, UpdateMeetingRequest, UpdateMeetingResponse,
+        UpdateMeetingSeriesRequest, UpdateMeetingSeriesResponse,
+    },
+    adapters::input::http::models::participant::{
+        ListModerationActionsResponse, ListParticipantsResponse, ModerationActionHttp,
+        MuteParticipantRequest, UpdatePermissionsRequest,
+    },
+    adapters::input::http::models::waiting_room::{
+        AdmissionResponse, ListWaitingGuestsResponse, WaitingTicketHttp,
     },
-    domain::{meeting::MeetingId, studio::StudioId},
-    ports::output::{meeting_repository::MeetingRepository, room_manager::RoomManager},
-    services::{create_meeting, join_meeting, list_meeting},
+    domain::{
+        calendar_feed::CalendarFeedToken,
+        invitation::{InvitationId, InvitationKey, InvitationToken},
+        meeting::{JoinWindow, Meeting, MeetingId, MeetingSeriesId},
+        member::Permission,
+        passcode::MeetingPasscode,
+        room::{Participant, ParticipantRole},
+        studio::StudioId,
+        user::Identity,
+        waiting_room::WaitingTicketId,
+    },
+    ports::output::{
+        calendar_feed_repository::CalendarFeedRepository,
+        invitation_repository::InvitationRepository,
+        meeting_repository::{MeetingCursor, MeetingRepository},
+        member_repository::MemberRepository,
+        moderation_repository::ModerationRepository,
+        room_manager::RoomManager,
+        studio_repository::StudioRepository,
+        waiting_room_repository::WaitingRoomRepository,
+    },
+    services::{
+        admit_guest, authorize, calendar_feed, cancel_meeting, cancel_meeting_series,
+        check_admission, create_calendar_feed, create_meeting, create_meeting_series, deny_guest,
+        end_meeting, get_meeting, import_meetings, invite_guest, join_meeting, list_meeting,
+        list_moderation_actions, list_participants, list_waiting_guests, mute_participant,
+        redeem_invitation, remove_participant, reschedule_meeting, revoke_calendar_feed,
+        revoke_invitation, update_meeting_series, update_participant_permissions,
+        ListMeetingFilter, MeetingChanges, MeetingSeriesChanges,
+    },
+};
+use chrono::{DateTime, Utc};
+use poem::{error::BadRequest, http::StatusCode, Result};
+use poem_openapi::{
+    param::{Header, Path, Query},
+    payload::{Json, PlainText},
+    types::MaybeUndefined,
+    OpenApi,
 };
-use chrono::Utc;
-use poem::Result;
-use poem_openapi::{param::Path, payload::Json, OpenApi};
+use std::sync::Arc;
 
 pub struct MeetingRouter<R, M> {
-    pub repository: R,
+    pub repository: Arc<R>,
     pub room_manager: M,
+    /// Public address of the API, to build the links handed out of it
+    pub api_url: url::Url,
+    pub invitation_key: InvitationKey,
+}
+
+impl<R, M> MeetingRouter<R, M> {
+    fn api_link(&self, path: &str) -> String {
+        format!("{}{path}", self.api_url.as_str().trim_end_matches('/'))
+    }
+
+    fn join_link(&self, meeting: &Meeting) -> String {
+        self.api_link(&format!("/meetings/{}/join", meeting.id.as_ref()))
+    }
 }
 
 #[OpenApi]
 impl<R, M> MeetingRouter<R, M>
 where
-    R: MeetingRepository + Send + Sync + 'static,
+    R: MeetingRepository
+        + CalendarFeedRepository
+        + StudioRepository
+        + MemberRepository
+        + InvitationRepository
+        + WaitingRoomRepository
+        + ModerationRepository
+        + Send
+        + Sync
+        + 'static,
     M: RoomManager + Send + Sync + 'static,
 {
     #[oai(path = "/meetings", method = "post", tag = "ApiTags::Meeting")]
     pub async fn create_meeting(
         &self,
-        #[oai(name = "studio")] studio_id: StudioId,
+        identity: Identity,
         Json(body): Json<CreateMeetingRequest>,
     ) -> Result<Json<CreateMeetingResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
         let today = Utc::now();
-        let created_meeting =
-            create_meeting(&self.repository, body.name, body.date, studio_id, today).await?;
+        let join_window = body
+            .join_window
+            .map(JoinWindow::try_from)
+            .transpose()
+            .map_err(BadRequest)?
+            .unwrap_or_default();
+        let passcode = body
+            .passcode
+            .map(MeetingPasscode::try_from)
+            .transpose()
+            .map_err(BadRequest)?;
+        let created_meeting = create_meeting(
+            self.repository.as_ref(),
+            body.name,
+            body.date,
+            body.duration,
+            join_window,
+            passcode,
+            body.waiting_room.unwrap_or(false),
+            body.max_participants,
+            studio_id,
+            today,
+        )
+        .await?;
         Ok(Json(created_meeting.into()))
     }
 
     #[oai(path = "/meetings", method = "get", tag = "ApiTags::Meeting")]
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_meetings(
         &self,
-        #[oai(name = "studio")] studio_id: StudioId,
+        identity: Identity,
+        Query(include_cancelled): Query<Option<bool>>,
+        /// Meetings starting at or after this date, now when neither `from` nor `to` is given
+        Query(from): Query<Option<DateTime<Utc>>>,
+        /// Meetings starting before this date
+        Query(to): Query<Option<DateTime<Utc>>>,
+        Query(order): Query<Option<MeetingOrderHttp>>,
+        #[oai(validator(minimum(value = "1"), maximum(value = "100")))] Query(limit): Query<
+            Option<u32>,
+        >,
+        /// The `next_cursor` of the previous page
+        Query(cursor): Query<Option<MeetingCursor>>,
     ) -> Result<Json<ListMeetingsResponse>> {
+        let studio_id = authorize(self.repository.as_ref(), &identity, Permission::View)
+            .await?
+            .studio_id;
         let today = Utc::now();
-        let meetings = list_meeting(&self.repository, studio_id, today).await?;
-        Ok(Json(ListMeetingsResponse::from(meetings)))
+        let filter = ListMeetingFilter {
+            from,
+            to,
+            include_cancelled: include_cancelled.unwrap_or(false),
+            order: order.map(Into::into).unwrap_or_default(),
+            limit,
+            cursor,
+            ..ListMeetingFilter::new(studio_id)
+        };
+        let page = list_meeting(self.repository.as_ref(), filter, today).await?;
+        Ok(Json(ListMeetingsResponse::from(page)))
+    }
+
+    /// Creates the meetings of an iCalendar file, with the same rules as one by one, and reports
+    /// what happened to each event
+    #[oai(path = "/meetings/import", method = "post", tag = "ApiTags::Meeting")]
+    pub async fn import_meetings(
+        &self,
+        identity: Identity,
+        /// Runs the checks without creating anything
+        Query(dry_run): Query<Option<bool>>,
+        /// Participants the room of each meeting holds at once, up to 10000, without limit when
+        /// omitted
+        #[oai(validator(minimum(value = "1"), maximum(value = "10000")))]
+        Query(max_participants): Query<Option<u32>>,
+        body: ImportCalendarRequest,
+    ) -> Result<Json<ImportMeetingsResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let dry_run = dry_run.unwrap_or(false);
+        let calendar = body.file.into_string().await.map_err(BadRequest)?;
+        let events = parse_calendar(&calendar)
+            .map_err(|message| poem::Error::from_string(message, StatusCode::BAD_REQUEST))?;
+        let report = import_meetings(
+            self.repository.as_ref(),
+            studio_id,
+            events,
+            max_participants,
+            dry_run,
+            today,
+        )
+        .await?;
+        Ok(Json(ImportMeetingsResponse::new(dry_run, report)))
+    }
+
+    #[oai(
+        path = "/meetings/:meeting-id",
+        method = "get",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn get_meeting(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+    ) -> Result<Json<GetMeetingResponse>> {
+        let studio_id = authorize(self.repository.as_ref(), &identity, Permission::View)
+            .await?
+            .studio_id;
+        let meeting = get_meeting(self.repository.as_ref(), meeting_id, studio_id).await?;
+        Ok(Json(meeting.into()))
+    }
+
+    #[oai(
+        path = "/meetings/:meeting-id",
+        method = "patch",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn update_meeting(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        Json(body): Json<UpdateMeetingRequest>,
+    ) -> Result<Json<UpdateMeetingResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let changes = MeetingChanges {
+            name: body.name,
+            date: body.date,
+            duration: body.duration,
+            join_window: body
+                .join_window
+                .map(JoinWindow::try_from)
+                .transpose()
+                .map_err(BadRequest)?,
+            passcode: match body.passcode {
+                MaybeUndefined::Undefined => None,
+                MaybeUndefined::Null => Some(None),
+                MaybeUndefined::Value(passcode) => Some(Some(
+                    MeetingPasscode::try_from(passcode).map_err(BadRequest)?,
+                )),
+            },
+            waiting_room: body.waiting_room,
+        };
+        let meeting = reschedule_meeting(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+            changes,
+            today,
+        )
+        .await?;
+        Ok(Json(meeting.into()))
+    }
+
+    #[oai(
+        path = "/meetings/:meeting-id/cancel",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn cancel_meeting(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+    ) -> Result<Json<CancelMeetingResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let meeting = cancel_meeting(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+        )
+        .await?;
+        Ok(Json(meeting.into()))
+    }
+
+    /// Ends the meeting for everyone, closing its room and refusing anyone joining afterwards
+    #[oai(
+        path = "/meetings/:meeting-id/end",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn end_meeting(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+    ) -> Result<Json<EndMeetingResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let meeting = end_meeting(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+        )
+        .await?;
+        Ok(Json(meeting.into()))
+    }
+
+    #[oai(path = "/meeting-series", method = "post", tag = "ApiTags::Meeting")]
+    pub async fn create_meeting_series(
+        &self,
+        identity: Identity,
+        Json(body): Json<CreateMeetingSeriesRequest>,
+    ) -> Result<Json<CreateMeetingSeriesResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let join_window = body
+            .join_window
+            .map(JoinWindow::try_from)
+            .transpose()
+            .map_err(BadRequest)?
+            .unwrap_or_default();
+        let series = create_meeting_series(
+            self.repository.as_ref(),
+            body.name,
+            body.date,
+            body.duration,
+            join_window,
+            body.max_participants,
+            body.rrule,
+            studio_id,
+            today,
+        )
+        .await?;
+        Ok(Json(series.into()))
+    }
+
+    #[oai(
+        path = "/meeting-series/:series-id",
+        method = "patch",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn update_meeting_series(
+        &self,
+        identity: Identity,
+        Path(series_id): Path<MeetingSeriesId>,
+        Json(body): Json<UpdateMeetingSeriesRequest>,
+    ) -> Result<Json<UpdateMeetingSeriesResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let changes = MeetingSeriesChanges {
+            name: body.name,
+            duration: body.duration,
+            join_window: body
+                .join_window
+                .map(JoinWindow::try_from)
+                .transpose()
+                .map_err(BadRequest)?,
+        };
+        let series = update_meeting_series(
+            self.repository.as_ref(),
+            &self.room_manager,
+            series_id,
+            studio_id,
+            changes,
+            today,
+        )
+        .await?;
+        Ok(Json(series.into()))
+    }
+
+    #[oai(
+        path = "/meeting-series/:series-id/cancel",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn cancel_meeting_series(
+        &self,
+        identity: Identity,
+        Path(series_id): Path<MeetingSeriesId>,
+    ) -> Result<Json<CancelMeetingSeriesResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let series = cancel_meeting_series(
+            self.repository.as_ref(),
+            &self.room_manager,
+            series_id,
+            studio_id,
+            today,
+        )
+        .await?;
+        Ok(Json(series.into()))
+    }
+
+    /// Issues a new token for the calendar feed of the studio, revoking the previous one
+    #[oai(path = "/calendar-feed", method = "post", tag = "ApiTags::Meeting")]
+    pub async fn create_calendar_feed(
+        &self,
+        identity: Identity,
+    ) -> Result<Json<CalendarFeedResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let studio = *studio_id.as_ref();
+        let token = create_calendar_feed(self.repository.as_ref(), studio_id, today).await?;
+        let url = self.api_link(&format!(
+            "/studios/{studio}/calendar.ics?token={}",
+            token.as_ref()
+        ));
+        Ok(Json(CalendarFeedResponse::new(token, url)))
+    }
+
+    #[oai(path = "/calendar-feed", method = "delete", tag = "ApiTags::Meeting")]
+    pub async fn revoke_calendar_feed(&self, identity: Identity) -> Result<()> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?
+        .studio_id;
+        revoke_calendar_feed(self.repository.as_ref(), studio_id).await?;
+        Ok(())
+    }
+
+    /// Meetings of the studio as an iCalendar, authenticated by the feed token since calendar
+    /// applications cannot send headers
+    #[oai(
+        path = "/studios/:studio-id/calendar.ics",
+        method = "get",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn calendar(
+        &self,
+        Path(studio_id): Path<uuid::Uuid>,
+        Query(token): Query<String>,
+    ) -> Result<CalendarResponse> {
+        let today = Utc::now();
+        let meetings = calendar_feed(
+            self.repository.as_ref(),
+            StudioId::from(studio_id),
+            CalendarFeedToken::from(token),
+            today,
+        )
+        .await?;
+        let calendar = render_calendar(&meetings, |meeting| self.join_link(meeting), today);
+        Ok(CalendarResponse::Ok(PlainText(calendar)))
     }
 
     #[oai(
@@ -51,26 +500,397 @@ where
     )]
     pub async fn join_meeting(
         &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        /// Stable id of the participant, required when the token names no user, e.g. for a
+        /// backend joining on behalf of its customers
+        Query(participant_id): Query<Option<String>>,
+        /// Name shown to the others, the participant id when omitted
+        Query(display_name): Query<Option<String>>,
+        /// Role of the participant named by `participant_id`, a speaker when omitted
+        Query(role): Query<Option<ParticipantRoleHttp>>,
+        /// Passcode of the meeting, the members of the studio do not need it
+        #[oai(name = "X-Meeting-Passcode")]
+        Header(passcode): Header<Option<String>>,
+    ) -> Result<JoinResponse> {
+        let today = Utc::now();
+        let on_behalf_of = participant_id.map(|identity| Participant {
+            name: display_name.unwrap_or_else(|| identity.clone()),
+            identity,
+        });
+        let admission = join_meeting(
+            self.repository.as_ref(),
+            &self.room_manager,
+            &identity,
+            on_behalf_of,
+            role.map(Into::into),
+            passcode,
+            meeting_id,
+            today,
+        )
+        .await?;
+        Ok(admission.into())
+    }
+
+    /// Invites a guest without an account, the returned link lets them join on their own
+    #[oai(
+        path = "/meetings/:meeting-id/invitations",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn create_invitation(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        Json(body): Json<CreateInvitationRequest>,
+    ) -> Result<Json<CreateInvitationResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let (invitation, token) = invite_guest(
+            self.repository.as_ref(),
+            &self.invitation_key,
+            meeting_id,
+            studio_id,
+            body.display_name,
+            body.role
+                .map(Into::into)
+                .unwrap_or(ParticipantRole::Speaker),
+            body.max_uses,
+            body.expires_at,
+            today,
+        )
+        .await?;
+        let url = self.api_link(&format!("/invitations/join?token={}", token.as_ref()));
+        Ok(Json(CreateInvitationResponse::new(invitation, token, url)))
+    }
+
+    #[oai(
+        path = "/invitations/:invitation-id",
+        method = "delete",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn revoke_invitation(
+        &self,
+        identity: Identity,
+        Path(invitation_id): Path<InvitationId>,
+    ) -> Result<()> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        revoke_invitation(self.repository.as_ref(), studio_id, invitation_id, today).await?;
+        Ok(())
+    }
+
+    /// Enters the room with an invitation link, authenticated by its signed token alone
+    #[oai(path = "/invitations/join", method = "get", tag = "ApiTags::Meeting")]
+    pub async fn redeem_invitation(&self, Query(token): Query<String>) -> Result<JoinResponse> {
+        let today = Utc::now();
+        let admission = redeem_invitation(
+            self.repository.as_ref(),
+            &self.room_manager,
+            &self.invitation_key,
+            InvitationToken::from(token),
+            today,
+        )
+        .await?;
+        Ok(admission.into())
+    }
+
+    /// Participants waiting for a host to let them in, in their order of arrival
+    #[oai(
+        path = "/meetings/:meeting-id/waiting-room",
+        method = "get",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn list_waiting_guests(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+    ) -> Result<Json<ListWaitingGuestsResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let tickets = list_waiting_guests(self.repository.as_ref(), meeting_id, studio_id).await?;
+        Ok(Json(tickets.into()))
+    }
+
+    #[oai(
+        path = "/meetings/:meeting-id/waiting-room/:ticket-id/admit",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn admit_guest(
+        &self,
+        identity: Identity,
         Path(meeting_id): Path<MeetingId>,
-    ) -> Result<Json<JoinMeetingResponse>> {
+        Path(ticket_id): Path<WaitingTicketId>,
+    ) -> Result<Json<WaitingTicketHttp>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
         let today = Utc::now();
-        let token = join_meeting(&self.repository, &self.room_manager, meeting_id, today).await?;
-        Ok(Json(JoinMeetingResponse::from(token)))
+        let ticket = admit_guest(
+            self.repository.as_ref(),
+            meeting_id,
+            ticket_id,
+            studio_id,
+            today,
+        )
+        .await?;
+        Ok(Json(ticket.into()))
+    }
+
+    #[oai(
+        path = "/meetings/:meeting-id/waiting-room/:ticket-id/deny",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn deny_guest(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        Path(ticket_id): Path<WaitingTicketId>,
+    ) -> Result<Json<WaitingTicketHttp>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let ticket = deny_guest(
+            self.repository.as_ref(),
+            meeting_id,
+            ticket_id,
+            studio_id,
+            today,
+        )
+        .await?;
+        Ok(Json(ticket.into()))
+    }
+
+    /// Who is connected to the room of the meeting right now
+    #[oai(
+        path = "/meetings/:meeting-id/participants",
+        method = "get",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn list_participants(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+    ) -> Result<Json<ListParticipantsResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let participants = list_participants(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+        )
+        .await?;
+        Ok(Json(participants.into()))
+    }
+
+    /// Disconnects the participant from the room of the meeting
+    #[oai(
+        path = "/meetings/:meeting-id/participants/:participant-id",
+        method = "delete",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn remove_participant(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        Path(participant_id): Path<String>,
+    ) -> Result<()> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        remove_participant(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+            participant_id,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Mutes what the participant publishes from the source, kept in the audit trail of the
+    /// meeting
+    #[oai(
+        path = "/meetings/:meeting-id/participants/:participant-id/mute",
+        method = "post",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn mute_participant(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        Path(participant_id): Path<String>,
+        Json(body): Json<MuteParticipantRequest>,
+    ) -> Result<Json<ModerationActionHttp>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let action = mute_participant(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+            identity.user_id,
+            participant_id,
+            body.source.into(),
+            today,
+        )
+        .await?;
+        Ok(Json(action.into()))
+    }
+
+    /// Grants or revokes the permissions of the participant until they leave, kept in the audit
+    /// trail of the meeting
+    #[oai(
+        path = "/meetings/:meeting-id/participants/:participant-id/permissions",
+        method = "patch",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn update_participant_permissions(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+        Path(participant_id): Path<String>,
+        Json(body): Json<UpdatePermissionsRequest>,
+    ) -> Result<Json<ModerationActionHttp>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let today = Utc::now();
+        let action = update_participant_permissions(
+            self.repository.as_ref(),
+            &self.room_manager,
+            meeting_id,
+            studio_id,
+            identity.user_id,
+            participant_id,
+            body.into(),
+            today,
+        )
+        .await?;
+        Ok(Json(action.into()))
+    }
+
+    /// Audit trail of what the hosts did to the participants of the meeting, the oldest first
+    #[oai(
+        path = "/meetings/:meeting-id/moderation",
+        method = "get",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn list_moderation_actions(
+        &self,
+        identity: Identity,
+        Path(meeting_id): Path<MeetingId>,
+    ) -> Result<Json<ListModerationActionsResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMeetings,
+        )
+        .await?
+        .studio_id;
+        let actions =
+            list_moderation_actions(self.repository.as_ref(), meeting_id, studio_id).await?;
+        Ok(Json(actions.into()))
+    }
+
+    /// Whether a host let the participant holding the ticket in, to poll while waiting, with the
+    /// token to enter the room once admitted
+    #[oai(
+        path = "/waiting-room/:ticket-id",
+        method = "get",
+        tag = "ApiTags::Meeting"
+    )]
+    pub async fn check_admission(
+        &self,
+        Path(ticket_id): Path<WaitingTicketId>,
+    ) -> Result<Json<AdmissionResponse>> {
+        let today = Utc::now();
+        let admission = check_admission(
+            self.repository.as_ref(),
+            &self.room_manager,
+            ticket_id,
+            today,
+        )
+        .await?;
+        Ok(Json(admission.into()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        domain::studio::StudioId,
+        adapters::input::http::auth::tests::{studio_token, user_token, TEST_SECRET},
+        domain::{
+            calendar_feed::{CalendarFeed, CalendarFeedToken},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            member::{Member, MemberRole},
+            room::{ParticipantRole, RoomToken},
+            studio::{Studio, StudioId, StudioName},
+        },
         ports::output::{
-            config::MockConfig, meeting_repository::MockMeetingRepository,
+            config::{JwtKey, MockConfig},
+            meeting_repository::MeetingQuery,
+            repository::MockRepository,
             room_manager::MockRoomManager,
         },
     };
-    use chrono::{Days, Utc};
+    use chrono::{Days, TimeDelta, Utc};
     use mockall::predicate::eq;
-    use poem::{http::StatusCode, test::TestClient};
+    use poem::{
+        http::StatusCode,
+        test::{TestClient, TestForm, TestFormField},
+    };
     use serde::Serialize;
 
     #[derive(Serialize)]
@@ -79,15 +899,58 @@ mod tests {
         date: &'a str,
     }
 
+    #[derive(Serialize)]
+    struct UpdateBody<'a> {
+        name: &'a str,
+    }
+
     fn token(studio_id: StudioId) -> String {
-        studio_id.as_ref().to_string()
+        format!("Bearer {}", studio_token(&studio_id, TimeDelta::hours(1)))
+    }
+
+    fn expect_studio(mock_repo: &mut MockRepository, studio_id: &StudioId) {
+        mock_repo
+            .expect_find_studio()
+            .with(eq(studio_id.clone()))
+            .returning(|studio_id| {
+                let studio = Studio {
+                    id: studio_id.clone(),
+                    name: StudioName::try_from("Hello studio".to_string()).unwrap(),
+                    timezone: chrono_tz::UTC,
+                    default_duration: MeetingDuration::default(),
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(Some(studio)) })
+            });
+    }
+
+    fn config() -> MockConfig {
+        let mut config = MockConfig::new();
+        config
+            .expect_swagger_uri()
+            .returning(|| url::Url::parse("http://localhost:8000").unwrap());
+        config
+            .expect_jwt_key()
+            .returning(|| Some(JwtKey::Hs256(TEST_SECRET.to_string())));
+        config.expect_oidc().returning(|| None);
+        config
+            .expect_invitation_secret()
+            .returning(|| "an invitation secret".to_string());
+        config
+            .expect_livekit_api_key()
+            .returning(|| "a livekit key".to_string());
+        config
+            .expect_livekit_secret()
+            .returning(|| "a livekit secret".to_string());
+        config
     }
 
     #[tokio::test]
     pub async fn test_payload_parsing_ok() {
         let studio_id = StudioId::from(uuid::Uuid::new_v4());
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
+        expect_studio(&mut mock_repo, &studio_id);
         mock_repo
             .expect_create_meeting()
             .once()
@@ -96,10 +959,10 @@ mod tests {
         mock_repo
             .expect_list_meetings()
             .once()
-            .with(eq(studio_id.clone()))
+            .withf(MeetingQuery::on_studio(&studio_id))
             .return_once(|_| Box::pin(async { Ok(vec![]) }));
 
-        let app = crate::app::app(MockConfig::new(), mock_repo, MockRoomManager::new())
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
             .await
             .unwrap();
 
@@ -116,7 +979,7 @@ mod tests {
                 name: "Meeting name",
                 date: date.as_str(),
             })
-            .header("studio", token(studio_id))
+            .header("authorization", token(studio_id))
             .send()
             .await;
         res.assert_status_is_ok();
@@ -124,13 +987,9 @@ mod tests {
 
     #[tokio::test]
     pub async fn test_payload_parsing_fail_name_is_empty() {
-        let app = crate::app::app(
-            MockConfig::new(),
-            MockMeetingRepository::new(),
-            MockRoomManager::new(),
-        )
-        .await
-        .unwrap();
+        let app = crate::app::app(config(), MockRepository::new(), MockRoomManager::new())
+            .await
+            .unwrap();
 
         let cli = TestClient::new(app);
         let res = cli
@@ -139,7 +998,27 @@ mod tests {
                 name: "",
                 date: "2025-02-17T17:50:41.777Z",
             })
-            .header("studio", token(StudioId::from(uuid::Uuid::new_v4())))
+            .header("authorization", token(StudioId::from(uuid::Uuid::new_v4())))
+            .send()
+            .await;
+        res.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    pub async fn test_payload_parsing_fail_too_many_participants() {
+        let app = crate::app::app(config(), MockRepository::new(), MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .post("/api/meetings")
+            .body_json(&serde_json::json!({
+                "name": "Meeting name",
+                "date": "2025-02-17T17:50:41.777Z",
+                "max_participants": 4_294_967_295_u32,
+            }))
+            .header("authorization", token(StudioId::from(uuid::Uuid::new_v4())))
             .send()
             .await;
         res.assert_status(StatusCode::BAD_REQUEST);
@@ -147,13 +1026,9 @@ mod tests {
 
     #[tokio::test]
     pub async fn test_authorization_is_needed() {
-        let app = crate::app::app(
-            MockConfig::new(),
-            MockMeetingRepository::new(),
-            MockRoomManager::new(),
-        )
-        .await
-        .unwrap();
+        let app = crate::app::app(config(), MockRepository::new(), MockRoomManager::new())
+            .await
+            .unwrap();
 
         let cli = TestClient::new(app);
         let res = cli
@@ -166,4 +1041,610 @@ mod tests {
             .await;
         res.assert_status(StatusCode::UNAUTHORIZED);
     }
+
+    #[tokio::test]
+    pub async fn test_viewer_cannot_create_meeting() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = Member {
+                    studio_id: studio_id.clone(),
+                    user_id: user_id.clone(),
+                    display_name: None,
+                    role: MemberRole::Viewer,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .return_once(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo.expect_create_meeting().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let authorization = format!("Bearer {}", user_token(&studio_id, "bob"));
+
+        let res = cli
+            .get("/api/meetings")
+            .header("authorization", &authorization)
+            .send()
+            .await;
+        res.assert_status_is_ok();
+
+        let date = (Utc::now() + TimeDelta::days(2)).to_rfc3339();
+        let res = cli
+            .post("/api/meetings")
+            .body_json(&Body {
+                name: "Meeting name",
+                date: date.as_str(),
+            })
+            .header("authorization", &authorization)
+            .send()
+            .await;
+        res.assert_status(StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    pub async fn test_viewer_cannot_moderate_participants() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = Member {
+                    studio_id: studio_id.clone(),
+                    user_id: user_id.clone(),
+                    display_name: None,
+                    role: MemberRole::Viewer,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_list_participants().never();
+        mock_room_manager.expect_remove_participant().never();
+        mock_room_manager.expect_mute_track().never();
+        mock_repo.expect_record_action().never();
+
+        let app = crate::app::app(config(), mock_repo, mock_room_manager)
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let authorization = format!("Bearer {}", user_token(&studio_id, "bob"));
+        let meeting_id = MeetingId::new();
+
+        let res = cli
+            .get(format!(
+                "/api/meetings/{}/participants",
+                meeting_id.as_ref()
+            ))
+            .header("authorization", &authorization)
+            .send()
+            .await;
+        res.assert_status(StatusCode::FORBIDDEN);
+
+        let res = cli
+            .delete(format!(
+                "/api/meetings/{}/participants/customer-42",
+                meeting_id.as_ref()
+            ))
+            .header("authorization", &authorization)
+            .send()
+            .await;
+        res.assert_status(StatusCode::FORBIDDEN);
+
+        let res = cli
+            .post(format!(
+                "/api/meetings/{}/participants/customer-42/mute",
+                meeting_id.as_ref()
+            ))
+            .body_json(&serde_json::json!({ "source": "microphone" }))
+            .header("authorization", &authorization)
+            .send()
+            .await;
+        res.assert_status(StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    pub async fn test_join_needs_a_member() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        let meeting_studio = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: meeting_studio.clone(),
+                    name: MeetingName::try_from("Morning yoga".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+        mock_repo
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = Member {
+                    studio_id: studio_id.clone(),
+                    user_id: user_id.clone(),
+                    display_name: Some("Bob Smith".to_string()),
+                    role: MemberRole::Member,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .withf(|_, participant, role| {
+                participant.identity == "bob"
+                    && participant.name == "Bob Smith"
+                    && *role == ParticipantRole::Host
+            })
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let app = crate::app::app(config(), mock_repo, mock_room_manager)
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let path = format!("/api/meetings/{}/join", meeting_id.as_ref());
+
+        let res = cli.get(&path).send().await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+
+        let res = cli
+            .get(&path)
+            .header(
+                "authorization",
+                format!("Bearer {}", user_token(&studio_id, "bob")),
+            )
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        res.assert_json(serde_json::json!({ "token": "mytoken" }))
+            .await;
+    }
+
+    #[tokio::test]
+    pub async fn test_join_passcode_in_header() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        let meeting_studio = studio_id.clone();
+        let passcode = crate::domain::passcode::MeetingPasscode::try_from("sunrise-42".to_string())
+            .unwrap()
+            .hash();
+        mock_repo
+            .expect_find_meeting()
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: meeting_studio.clone(),
+                    name: MeetingName::try_from("Open class".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: Some(passcode.clone()),
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+        mock_repo
+            .expect_count_passcode_failures()
+            .returning(|_, _, _| Box::pin(async { Ok(0) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let app = crate::app::app(config(), mock_repo, mock_room_manager)
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let path = format!("/api/meetings/{}/join", meeting_id.as_ref());
+
+        // The passcode is not read from the query string, where it would be logged
+        let res = cli
+            .get(&path)
+            .query("participant_id", &"customer-42")
+            .query("passcode", &"sunrise-42")
+            .header("authorization", token(studio_id.clone()))
+            .send()
+            .await;
+        res.assert_status(StatusCode::FORBIDDEN);
+
+        let res = cli
+            .get(&path)
+            .query("participant_id", &"customer-42")
+            .header("authorization", token(studio_id))
+            .header("x-meeting-passcode", "sunrise-42")
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        res.assert_json(serde_json::json!({ "token": "mytoken" }))
+            .await;
+    }
+
+    #[tokio::test]
+    pub async fn test_list_meetings_limit_out_of_range() {
+        let mut mock_repo = MockRepository::new();
+        mock_repo.expect_list_meetings().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .get("/api/meetings")
+            .query("limit", &101)
+            .header("authorization", token(StudioId::from(uuid::Uuid::new_v4())))
+            .send()
+            .await;
+        res.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    pub async fn test_get_meeting_of_another_studio() {
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(|meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id: StudioId::from(uuid::Uuid::new_v4()),
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .get(format!("/api/meetings/{}", meeting_id.as_ref()))
+            .header("authorization", token(StudioId::from(uuid::Uuid::new_v4())))
+            .send()
+            .await;
+        res.assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    pub async fn test_create_meeting_series_bad_rrule() {
+        let mut mock_repo = MockRepository::new();
+        mock_repo.expect_create_series().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let date = Utc::now()
+            .checked_add_days(Days::new(2))
+            .unwrap()
+            .to_rfc3339();
+        let res = cli
+            .post("/api/meeting-series")
+            .body_json(&serde_json::json!({
+                "name": "Stand-up",
+                "date": date,
+                "rrule": "FREQ=MONTHLY;COUNT=3",
+            }))
+            .header("authorization", token(StudioId::from(uuid::Uuid::new_v4())))
+            .send()
+            .await;
+        res.assert_status(StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    pub async fn test_calendar_feed() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let token = CalendarFeedToken::generate();
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        let token_hash = token.hash();
+        mock_repo
+            .expect_find_calendar_feed()
+            .once()
+            .with(eq(studio_id.clone()))
+            .return_once(move |studio_id| {
+                let studio_id = studio_id.clone();
+                Box::pin(async move {
+                    Ok(Some(CalendarFeed {
+                        studio_id,
+                        token_hash,
+                        created_at: Utc::now(),
+                    }))
+                })
+            });
+        let listed_id = meeting_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .return_once(move |query| {
+                let studio_id = query.studio_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id: listed_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .get(format!("/api/studios/{}/calendar.ics", studio_id.as_ref()))
+            .query("token", &token.as_ref())
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        res.assert_content_type("text/calendar; charset=utf-8");
+        // Long lines are folded on a space
+        let calendar = res
+            .0
+            .into_body()
+            .into_string()
+            .await
+            .unwrap()
+            .replace("\r\n ", "");
+        assert!(calendar.contains(&format!("UID:{}", meeting_id.as_ref())));
+        assert!(calendar.contains(&format!(
+            "URL:http://localhost:8000/meetings/{}/join",
+            meeting_id.as_ref()
+        )));
+    }
+
+    #[tokio::test]
+    pub async fn test_calendar_feed_revoked() {
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_calendar_feed()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo.expect_list_meetings().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .get(format!(
+                "/api/studios/{}/calendar.ics",
+                uuid::Uuid::new_v4()
+            ))
+            .query("token", &"revoked")
+            .send()
+            .await;
+        res.assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    pub async fn test_import_dry_run() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let mut mock_repo = MockRepository::new();
+        expect_studio(&mut mock_repo, &studio_id);
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo.expect_create_meeting().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let calendar = format!(
+            "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:kick-off\r\nSUMMARY:Kick-off\r\nDTSTART:{}\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n",
+            date.format("%Y%m%dT%H%M%SZ")
+        );
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .post("/api/meetings/import")
+            .query("dry_run", &true)
+            .multipart(
+                TestForm::new().field(
+                    TestFormField::text(calendar)
+                        .name("file")
+                        .filename("calendar.ics"),
+                ),
+            )
+            .header("authorization", token(studio_id))
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        let json = res.json().await;
+        let report = json.value().object();
+        report.get("dry_run").assert_bool(true);
+        let entry = report.get("events").array().get(0).object();
+        entry.get("uid").assert_string("kick-off");
+        entry.get("outcome").assert_string("created");
+    }
+
+    #[tokio::test]
+    pub async fn test_update_meeting_ok() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        let owner = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(move |meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id: owner,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .return_once(|_| Box::pin(async { Ok(()) }));
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let res = cli
+            .patch(format!("/api/meetings/{}", meeting_id.as_ref()))
+            .body_json(&UpdateBody {
+                name: "Renamed meeting",
+            })
+            .header("authorization", token(studio_id))
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        res.json()
+            .await
+            .value()
+            .object()
+            .get("name")
+            .assert_string("Renamed meeting");
+    }
+
+    #[tokio::test]
+    pub async fn test_create_meeting_conflict_body() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let existing_id = MeetingId::new();
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        expect_studio(&mut mock_repo, &studio_id);
+        mock_repo.expect_create_meeting().never();
+        let listed_id = existing_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .return_once(move |query| {
+                let studio_id = query.studio_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id: listed_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date,
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+
+        let cli = TestClient::new(app);
+        let date = (date + TimeDelta::minutes(1)).to_rfc3339();
+        let res = cli
+            .post("/api/meetings")
+            .body_json(&Body {
+                name: "Overlapping meeting",
+                date: date.as_str(),
+            })
+            .header("authorization", token(studio_id))
+            .send()
+            .await;
+        res.assert_status(StatusCode::BAD_REQUEST);
+        res.json()
+            .await
+            .value()
+            .object()
+            .get("conflicting_meeting_id")
+            .assert_string(&existing_id.as_ref().to_string());
+    }
 }
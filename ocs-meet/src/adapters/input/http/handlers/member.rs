@@ -0,0 +1,108 @@
+use super::super::tags::ApiTags;
+use crate::{
+    adapters::input::http::models::member::{
+        AddMemberRequest, AddMemberResponse, ChangeMemberRoleRequest, ChangeMemberRoleResponse,
+        ListMembersResponse,
+    },
+    domain::{
+        member::Permission,
+        user::{Identity, UserId},
+    },
+    ports::output::{member_repository::MemberRepository, studio_repository::StudioRepository},
+    services::{add_member, authorize, change_member_role, list_members, remove_member},
+};
+use chrono::Utc;
+use poem::Result;
+use poem_openapi::{param::Path, payload::Json, OpenApi};
+use std::sync::Arc;
+
+pub struct MemberRouter<R> {
+    pub repository: Arc<R>,
+}
+
+/// The users working in the studio, owners and admins manage them
+#[OpenApi]
+impl<R> MemberRouter<R>
+where
+    R: StudioRepository + MemberRepository + Send + Sync + 'static,
+{
+    #[oai(path = "/studio/members", method = "get", tag = "ApiTags::Member")]
+    pub async fn list_members(&self, identity: Identity) -> Result<Json<ListMembersResponse>> {
+        let access = authorize(self.repository.as_ref(), &identity, Permission::View).await?;
+        let members = list_members(self.repository.as_ref(), access.studio_id).await?;
+        Ok(Json(members.into()))
+    }
+
+    /// Adds a user to the studio, they get access with their next token
+    #[oai(path = "/studio/members", method = "post", tag = "ApiTags::Member")]
+    pub async fn add_member(
+        &self,
+        identity: Identity,
+        Json(body): Json<AddMemberRequest>,
+    ) -> Result<Json<AddMemberResponse>> {
+        let today = Utc::now();
+        let access = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMembers,
+        )
+        .await?;
+        let member = add_member(
+            self.repository.as_ref(),
+            &access,
+            UserId::from(body.user_id),
+            body.display_name,
+            body.role.into(),
+            today,
+        )
+        .await?;
+        Ok(Json(member.into()))
+    }
+
+    #[oai(
+        path = "/studio/members/:user-id",
+        method = "patch",
+        tag = "ApiTags::Member"
+    )]
+    pub async fn change_member_role(
+        &self,
+        identity: Identity,
+        Path(user_id): Path<String>,
+        Json(body): Json<ChangeMemberRoleRequest>,
+    ) -> Result<Json<ChangeMemberRoleResponse>> {
+        let access = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMembers,
+        )
+        .await?;
+        let member = change_member_role(
+            self.repository.as_ref(),
+            &access,
+            UserId::from(user_id),
+            body.role.into(),
+        )
+        .await?;
+        Ok(Json(member.into()))
+    }
+
+    #[oai(
+        path = "/studio/members/:user-id",
+        method = "delete",
+        tag = "ApiTags::Member"
+    )]
+    pub async fn remove_member(
+        &self,
+        identity: Identity,
+        Path(user_id): Path<String>,
+    ) -> Result<()> {
+        let access = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageMembers,
+        )
+        .await?;
+        remove_member(self.repository.as_ref(), &access, UserId::from(user_id)).await?;
+        Ok(())
+    }
+}
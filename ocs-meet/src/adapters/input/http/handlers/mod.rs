@@ -1 +1,5 @@
+pub mod api_key;
 pub mod meeting;
+pub mod member;
+pub mod studio;
+pub mod webhook;
@@ -0,0 +1,184 @@
+use super::super::tags::ApiTags;
+use crate::{
+    adapters::input::http::models::studio::{
+        CreateStudioRequest, CreateStudioResponse, GetStudioResponse, UpdateStudioRequest,
+        UpdateStudioResponse,
+    },
+    domain::{member::Permission, user::Identity},
+    ports::output::{member_repository::MemberRepository, studio_repository::StudioRepository},
+    services::{authorize, create_studio, delete_studio, get_studio, update_studio, StudioChanges},
+};
+use chrono::Utc;
+use chrono_tz::Tz;
+use poem::{error::BadRequest, Result};
+use poem_openapi::{payload::Json, OpenApi};
+use std::{str::FromStr, sync::Arc};
+
+pub struct StudioRouter<R> {
+    pub repository: Arc<R>,
+}
+
+fn parse_timezone(timezone: Option<String>) -> Result<Option<Tz>> {
+    timezone
+        .map(|timezone| Tz::from_str(&timezone))
+        .transpose()
+        .map_err(BadRequest)
+}
+
+/// The studio the token grants access to, it has to be created before scheduling meetings
+#[OpenApi]
+impl<R> StudioRouter<R>
+where
+    R: StudioRepository + MemberRepository + Send + Sync + 'static,
+{
+    #[oai(path = "/studio", method = "post", tag = "ApiTags::Studio")]
+    pub async fn create_studio(
+        &self,
+        identity: Identity,
+        Json(body): Json<CreateStudioRequest>,
+    ) -> Result<Json<CreateStudioResponse>> {
+        let today = Utc::now();
+        let access = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?;
+        let studio = create_studio(
+            self.repository.as_ref(),
+            access.studio_id,
+            identity.user_id,
+            body.name,
+            parse_timezone(body.timezone)?,
+            body.default_duration,
+            today,
+        )
+        .await?;
+        Ok(Json(studio.into()))
+    }
+
+    #[oai(path = "/studio", method = "get", tag = "ApiTags::Studio")]
+    pub async fn get_studio(&self, identity: Identity) -> Result<Json<GetStudioResponse>> {
+        let studio_id = authorize(self.repository.as_ref(), &identity, Permission::View)
+            .await?
+            .studio_id;
+        let studio = get_studio(self.repository.as_ref(), studio_id).await?;
+        Ok(Json(studio.into()))
+    }
+
+    #[oai(path = "/studio", method = "patch", tag = "ApiTags::Studio")]
+    pub async fn update_studio(
+        &self,
+        identity: Identity,
+        Json(body): Json<UpdateStudioRequest>,
+    ) -> Result<Json<UpdateStudioResponse>> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::ManageStudio,
+        )
+        .await?
+        .studio_id;
+        let changes = StudioChanges {
+            name: body.name,
+            timezone: parse_timezone(body.timezone)?,
+            default_duration: body.default_duration,
+        };
+        let studio = update_studio(self.repository.as_ref(), studio_id, changes).await?;
+        Ok(Json(studio.into()))
+    }
+
+    #[oai(path = "/studio", method = "delete", tag = "ApiTags::Studio")]
+    pub async fn delete_studio(&self, identity: Identity) -> Result<()> {
+        let studio_id = authorize(
+            self.repository.as_ref(),
+            &identity,
+            Permission::DeleteStudio,
+        )
+        .await?
+        .studio_id;
+        delete_studio(self.repository.as_ref(), studio_id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::input::http::auth::tests::{studio_token, TEST_SECRET},
+        domain::studio::StudioId,
+        ports::output::{
+            config::{JwtKey, MockConfig},
+            repository::MockRepository,
+            room_manager::MockRoomManager,
+        },
+    };
+    use chrono::TimeDelta;
+    use poem::{http::StatusCode, test::TestClient};
+    use serde_json::json;
+
+    fn config() -> MockConfig {
+        let mut config = MockConfig::new();
+        config
+            .expect_swagger_uri()
+            .returning(|| url::Url::parse("http://localhost:8000").unwrap());
+        config
+            .expect_jwt_key()
+            .returning(|| Some(JwtKey::Hs256(TEST_SECRET.to_string())));
+        config.expect_oidc().returning(|| None);
+        config
+            .expect_invitation_secret()
+            .returning(|| "an invitation secret".to_string());
+        config
+            .expect_livekit_api_key()
+            .returning(|| "a livekit key".to_string());
+        config
+            .expect_livekit_secret()
+            .returning(|| "a livekit secret".to_string());
+        config
+    }
+
+    #[tokio::test]
+    async fn test_create_studio() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo
+            .expect_create_studio()
+            .once()
+            .withf(|studio| studio.timezone == chrono_tz::Europe::Paris)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let authorization = format!("Bearer {}", studio_token(&studio_id, TimeDelta::hours(1)));
+
+        let res = cli
+            .post("/api/studio")
+            .header("authorization", &authorization)
+            .body_json(&json!({ "name": "Yoga studio", "timezone": "Mars/Olympus_Mons" }))
+            .send()
+            .await;
+        res.assert_status(StatusCode::BAD_REQUEST);
+
+        let res = cli
+            .post("/api/studio")
+            .header("authorization", &authorization)
+            .body_json(&json!({ "name": "Yoga studio", "timezone": "Europe/Paris" }))
+            .send()
+            .await;
+        res.assert_status_is_ok();
+        let body = res.json().await;
+        let studio = body.value().object();
+        studio
+            .get("id")
+            .assert_string(&studio_id.as_ref().to_string());
+        studio.get("default_duration").assert_i64(60);
+    }
+}
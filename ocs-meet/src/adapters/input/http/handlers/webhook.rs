@@ -0,0 +1,189 @@
+use crate::{
+    adapters::input::http::models::webhook::room_event,
+    ports::output::{
+        meeting_repository::MeetingRepository, room_event_repository::RoomEventRepository,
+    },
+    services::handle_room_event,
+};
+use chrono::Utc;
+use livekit_api::webhooks::{WebhookError, WebhookReceiver};
+use poem::{
+    http::{header::AUTHORIZATION, StatusCode},
+    Endpoint, Error, Request, Response, Result,
+};
+use std::sync::Arc;
+
+/// Receives the events LiveKit posts about the rooms, signed with the API secret
+///
+/// Kept out of the OpenAPI routers since LiveKit posts them as `application/webhook+json`.
+pub struct LivekitWebhook<R> {
+    pub repository: Arc<R>,
+    pub receiver: WebhookReceiver,
+}
+
+impl<R> Endpoint for LivekitWebhook<R>
+where
+    R: MeetingRepository + RoomEventRepository + Send + Sync,
+{
+    type Output = Response;
+
+    async fn call(&self, mut req: Request) -> Result<Self::Output> {
+        // LiveKit sends the token as is, some proxies add the scheme
+        let token = req
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim_start_matches("Bearer ").to_string())
+            .ok_or_else(|| Error::from_string("Missing signature", StatusCode::UNAUTHORIZED))?;
+        let body = req.take_body().into_string().await?;
+
+        let event = self
+            .receiver
+            .receive(&body, &token)
+            .map_err(|err| match err {
+                WebhookError::InvalidData(_) => {
+                    Error::from_string(err.to_string(), StatusCode::BAD_REQUEST)
+                }
+                _ => Error::from_string("Invalid signature", StatusCode::UNAUTHORIZED),
+            })?;
+
+        // Acknowledged all the same, so that LiveKit does not retry what we do not follow
+        if let Some(event) = room_event(event) {
+            handle_room_event(self.repository.as_ref(), event, Utc::now()).await?;
+        }
+
+        Ok(Response::builder().status(StatusCode::OK).finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::input::http::auth::tests::TEST_SECRET,
+        domain::meeting::MeetingId,
+        ports::output::{
+            config::{JwtKey, MockConfig},
+            repository::MockRepository,
+            room_manager::MockRoomManager,
+        },
+    };
+    use base64::Engine;
+    use livekit_api::access_token::AccessToken;
+    use poem::{http::StatusCode, test::TestClient};
+    use serde_json::json;
+    use sha2::{Digest, Sha256};
+
+    const LIVEKIT_KEY: &str = "a livekit key";
+    const LIVEKIT_SECRET: &str = "a livekit secret";
+
+    fn config() -> MockConfig {
+        let mut config = MockConfig::new();
+        config
+            .expect_swagger_uri()
+            .returning(|| url::Url::parse("http://localhost:8000").unwrap());
+        config
+            .expect_jwt_key()
+            .returning(|| Some(JwtKey::Hs256(TEST_SECRET.to_string())));
+        config.expect_oidc().returning(|| None);
+        config
+            .expect_invitation_secret()
+            .returning(|| "an invitation secret".to_string());
+        config
+            .expect_livekit_api_key()
+            .returning(|| LIVEKIT_KEY.to_string());
+        config
+            .expect_livekit_secret()
+            .returning(|| LIVEKIT_SECRET.to_string());
+        config
+    }
+
+    /// Signs the body the way LiveKit does, with the hash of the body in the token
+    fn signature(body: &str, secret: &str) -> String {
+        let hash = Sha256::digest(body.as_bytes());
+        AccessToken::with_api_key(LIVEKIT_KEY, secret)
+            .with_sha256(&base64::engine::general_purpose::STANDARD.encode(hash))
+            .to_jwt()
+            .unwrap()
+    }
+
+    fn body(meeting_id: &MeetingId) -> String {
+        json!({
+            "event": "room_finished",
+            "room": { "name": meeting_id.as_ref().to_string() },
+            "id": "EV_4Xk9wTz",
+            "createdAt": 1_746_608_400,
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_invalid_signature() {
+        let mut mock_repo = MockRepository::new();
+        mock_repo.expect_has_event().never();
+        mock_repo.expect_update_meeting().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let body = body(&MeetingId::new());
+
+        let res = cli
+            .post("/webhooks/livekit")
+            .header("authorization", signature(&body, "another secret"))
+            .content_type("application/webhook+json")
+            .body(body.clone())
+            .send()
+            .await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+
+        // Signed for another body
+        let res = cli
+            .post("/webhooks/livekit")
+            .header("authorization", signature("{}", LIVEKIT_SECRET))
+            .content_type("application/webhook+json")
+            .body(body.clone())
+            .send()
+            .await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+
+        let res = cli
+            .post("/webhooks/livekit")
+            .content_type("application/webhook+json")
+            .body(body)
+            .send()
+            .await;
+        res.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_signed_event() {
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_has_event()
+            .withf(|event_id| event_id == "EV_4Xk9wTz")
+            .returning(|_| Box::pin(async { Ok(false) }));
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo.expect_record_event().never();
+
+        let app = crate::app::app(config(), mock_repo, MockRoomManager::new())
+            .await
+            .unwrap();
+        let cli = TestClient::new(app);
+        let body = body(&meeting_id);
+
+        let res = cli
+            .post("/webhooks/livekit")
+            .header("authorization", signature(&body, LIVEKIT_SECRET))
+            .content_type("application/webhook+json")
+            .body(body)
+            .send()
+            .await;
+        res.assert_status_is_ok();
+    }
+}
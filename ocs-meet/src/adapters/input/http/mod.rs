@@ -1,3 +1,4 @@
+pub mod auth;
 pub mod extractors;
 pub mod handlers;
 pub mod models;
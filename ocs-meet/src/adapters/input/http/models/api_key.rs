@@ -0,0 +1,99 @@
+use crate::domain::api_key::{ApiKey, ApiKeyScope, ApiKeyToken};
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object};
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "lowercase")]
+pub enum ApiKeyScopeHttp {
+    /// Reading the meetings
+    Read,
+    /// Creating and changing the meetings
+    Write,
+    /// Getting tokens to enter the rooms
+    Join,
+}
+
+impl From<ApiKeyScopeHttp> for ApiKeyScope {
+    fn from(value: ApiKeyScopeHttp) -> Self {
+        match value {
+            ApiKeyScopeHttp::Read => ApiKeyScope::Read,
+            ApiKeyScopeHttp::Write => ApiKeyScope::Write,
+            ApiKeyScopeHttp::Join => ApiKeyScope::Join,
+        }
+    }
+}
+
+impl From<ApiKeyScope> for ApiKeyScopeHttp {
+    fn from(value: ApiKeyScope) -> Self {
+        match value {
+            ApiKeyScope::Read => ApiKeyScopeHttp::Read,
+            ApiKeyScope::Write => ApiKeyScopeHttp::Write,
+            ApiKeyScope::Join => ApiKeyScopeHttp::Join,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct CreateApiKeyRequest {
+    /// Label to recognize the key, e.g. the integration using it
+    #[oai(validator(min_length = "1", max_length = "100"))]
+    pub name: String,
+    pub scopes: Vec<ApiKeyScopeHttp>,
+    /// The key never expires when omitted
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Object)]
+pub struct ApiKeyHttp {
+    id: uuid::Uuid,
+    name: String,
+    scopes: Vec<ApiKeyScopeHttp>,
+    expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ApiKey> for ApiKeyHttp {
+    fn from(value: ApiKey) -> Self {
+        ApiKeyHttp {
+            id: *value.id.as_ref(),
+            name: value.name,
+            scopes: value
+                .scopes
+                .into_iter()
+                .map(ApiKeyScopeHttp::from)
+                .collect(),
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct CreateApiKeyResponse {
+    #[oai(flatten)]
+    api_key: ApiKeyHttp,
+    /// Secret to send as a bearer token, it is not stored and cannot be shown again
+    key: String,
+}
+
+impl From<(ApiKey, ApiKeyToken)> for CreateApiKeyResponse {
+    fn from((api_key, token): (ApiKey, ApiKeyToken)) -> Self {
+        CreateApiKeyResponse {
+            api_key: api_key.into(),
+            key: token.as_ref().clone(),
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct ListApiKeysResponse {
+    api_keys: Vec<ApiKeyHttp>,
+}
+
+impl From<Vec<ApiKey>> for ListApiKeysResponse {
+    fn from(value: Vec<ApiKey>) -> Self {
+        ListApiKeysResponse {
+            api_keys: value.into_iter().map(ApiKeyHttp::from).collect(),
+        }
+    }
+}
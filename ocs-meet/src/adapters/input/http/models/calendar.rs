@@ -0,0 +1,501 @@
+use super::meeting::MeetingHttp;
+use crate::{
+    domain::{
+        calendar_feed::CalendarFeedToken,
+        meeting::{Meeting, MeetingStatus},
+    },
+    services::{ImportOutcome, ImportReportEntry, ImportedEvent, ImportedEventFields},
+};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone, Utc};
+use poem_openapi::{
+    payload::PlainText, types::multipart::Upload, ApiResponse, Enum, Multipart, Object,
+};
+use std::str::FromStr;
+
+#[derive(Object)]
+pub struct CalendarFeedResponse {
+    /// Secret of the feed, it cannot be retrieved afterwards
+    token: String,
+    /// Address to subscribe to from a calendar application
+    url: String,
+}
+
+impl CalendarFeedResponse {
+    pub fn new(token: CalendarFeedToken, url: String) -> Self {
+        CalendarFeedResponse {
+            token: token.as_ref().to_string(),
+            url,
+        }
+    }
+}
+
+#[derive(ApiResponse)]
+pub enum CalendarResponse {
+    #[oai(status = 200, content_type = "text/calendar; charset=utf-8")]
+    Ok(PlainText<String>),
+}
+
+fn format_date(date: DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes the characters RFC 5545 reserves in TEXT values
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+        .replace('\r', "")
+}
+
+/// Writes a content line, folded so that no line exceeds 75 octets
+fn push_line(calendar: &mut String, line: &str) {
+    let mut width = 0;
+    for character in line.chars() {
+        if width + character.len_utf8() > 75 {
+            calendar.push_str("\r\n ");
+            width = 1;
+        }
+        calendar.push(character);
+        width += character.len_utf8();
+    }
+    calendar.push_str("\r\n");
+}
+
+/// Renders the meetings as an iCalendar, `join_url` giving the address to join each of them
+pub fn render_calendar(
+    meetings: &[Meeting],
+    join_url: impl Fn(&Meeting) -> String,
+    now: DateTime<Utc>,
+) -> String {
+    let mut calendar = String::new();
+    push_line(&mut calendar, "BEGIN:VCALENDAR");
+    push_line(&mut calendar, "VERSION:2.0");
+    push_line(&mut calendar, "PRODID:-//OCS//Meet//EN");
+    push_line(&mut calendar, "CALSCALE:GREGORIAN");
+    push_line(&mut calendar, "METHOD:PUBLISH");
+    for meeting in meetings {
+        let url = join_url(meeting);
+        let status = match meeting.status {
+            MeetingStatus::Cancelled => "CANCELLED",
+            MeetingStatus::Scheduled | MeetingStatus::Live | MeetingStatus::Ended => "CONFIRMED",
+        };
+        push_line(&mut calendar, "BEGIN:VEVENT");
+        push_line(&mut calendar, &format!("UID:{}", meeting.id.as_ref()));
+        push_line(&mut calendar, &format!("DTSTAMP:{}", format_date(now)));
+        push_line(
+            &mut calendar,
+            &format!("DTSTART:{}", format_date(meeting.date)),
+        );
+        push_line(
+            &mut calendar,
+            &format!("DTEND:{}", format_date(meeting.end_date())),
+        );
+        push_line(
+            &mut calendar,
+            &format!("SUMMARY:{}", escape_text(meeting.name.as_ref())),
+        );
+        push_line(
+            &mut calendar,
+            &format!(
+                "DESCRIPTION:{}",
+                escape_text(&format!("Join the meeting: {url}"))
+            ),
+        );
+        push_line(&mut calendar, &format!("URL:{url}"));
+        push_line(&mut calendar, &format!("STATUS:{status}"));
+        push_line(&mut calendar, "END:VEVENT");
+    }
+    push_line(&mut calendar, "END:VCALENDAR");
+    calendar
+}
+
+#[derive(Multipart)]
+pub struct ImportCalendarRequest {
+    /// The iCalendar file to import
+    pub file: Upload,
+}
+
+#[derive(Enum)]
+#[oai(rename_all = "snake_case")]
+pub enum ImportOutcomeHttp {
+    Created,
+    SkippedPast,
+    Conflict,
+    Invalid,
+    Failed,
+}
+
+#[derive(Object)]
+pub struct ImportReportEntryHttp {
+    uid: Option<String>,
+    outcome: ImportOutcomeHttp,
+    /// The meeting created from the event, or that would be on a dry run
+    meeting: Option<MeetingHttp>,
+    conflicting_meeting_id: Option<uuid::Uuid>,
+    /// Why the event could not be imported
+    reason: Option<String>,
+}
+
+impl From<ImportReportEntry> for ImportReportEntryHttp {
+    fn from(entry: ImportReportEntry) -> Self {
+        let mut http = ImportReportEntryHttp {
+            uid: entry.uid,
+            outcome: ImportOutcomeHttp::Created,
+            meeting: None,
+            conflicting_meeting_id: None,
+            reason: None,
+        };
+        match entry.outcome {
+            ImportOutcome::Created(meeting) => http.meeting = Some(meeting.into()),
+            ImportOutcome::SkippedPast => http.outcome = ImportOutcomeHttp::SkippedPast,
+            ImportOutcome::Conflict(meeting_id) => {
+                http.outcome = ImportOutcomeHttp::Conflict;
+                http.conflicting_meeting_id = Some(*meeting_id.as_ref());
+            }
+            ImportOutcome::Invalid(reason) => {
+                http.outcome = ImportOutcomeHttp::Invalid;
+                http.reason = Some(reason);
+            }
+            ImportOutcome::Failed(reason) => {
+                http.outcome = ImportOutcomeHttp::Failed;
+                http.reason = Some(reason);
+            }
+        }
+        http
+    }
+}
+
+#[derive(Object)]
+pub struct ImportMeetingsResponse {
+    /// Whether the import was a dry run, in which case nothing has been created
+    dry_run: bool,
+    /// One entry per event of the calendar, in the order of the file
+    events: Vec<ImportReportEntryHttp>,
+}
+
+impl ImportMeetingsResponse {
+    pub fn new(dry_run: bool, report: Vec<ImportReportEntry>) -> Self {
+        ImportMeetingsResponse {
+            dry_run,
+            events: report
+                .into_iter()
+                .map(ImportReportEntryHttp::from)
+                .collect(),
+        }
+    }
+}
+
+/// Property of a calendar component, as in `DTSTART;TZID=Europe/Paris:20250303T093000`
+struct ContentLine<'a> {
+    name: String,
+    params: Vec<(String, &'a str)>,
+    value: &'a str,
+}
+
+impl ContentLine<'_> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.trim_matches('"'))
+    }
+}
+
+/// Joins the lines folded on a space or a tab back together
+fn unfold(calendar: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in calendar.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn parse_content_line(line: &str) -> Option<ContentLine<'_>> {
+    // The value starts at the first colon outside of a quoted parameter value
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(index, character)| {
+        match character {
+            '"' => quoted = !quoted,
+            ':' if !quoted => return Some(index),
+            _ => {}
+        }
+        None
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(param, value)| (param.to_ascii_uppercase(), value))
+        .collect();
+    Some(ContentLine {
+        name,
+        params,
+        value,
+    })
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut characters = text.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(escaped) => unescaped.push(escaped),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+/// Date of a DATE-TIME property, in UTC, local time with a TZID, or floating taken as UTC
+fn parse_date(line: &ContentLine) -> Result<DateTime<Utc>, String> {
+    if line.param("VALUE") == Some("DATE") || line.value.len() == 8 {
+        return Err("All-day events are not supported".to_string());
+    }
+    let malformed = || format!("Malformed date {}", line.value);
+    if let Some(value) = line.value.strip_suffix('Z') {
+        let date =
+            NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| malformed())?;
+        return Ok(date.and_utc());
+    }
+    let date =
+        NaiveDateTime::parse_from_str(line.value, "%Y%m%dT%H%M%S").map_err(|_| malformed())?;
+    let Some(tzid) = line.param("TZID") else {
+        return Ok(date.and_utc());
+    };
+    let timezone =
+        chrono_tz::Tz::from_str(tzid).map_err(|_| format!("Unknown time zone {tzid}"))?;
+    timezone
+        .from_local_datetime(&date)
+        .earliest()
+        .map(|date| date.with_timezone(&Utc))
+        .ok_or_else(malformed)
+}
+
+/// Duration of the form `PT1H30M`, `P1D` or `P2W`
+fn parse_duration(value: &str) -> Result<TimeDelta, String> {
+    let malformed = || format!("Malformed duration {value}");
+    let rest = value.strip_prefix('+').unwrap_or(value);
+    let rest = rest.strip_prefix('P').ok_or_else(malformed)?;
+    let mut duration = TimeDelta::zero();
+    let mut number = String::new();
+    for character in rest.chars() {
+        match character {
+            '0'..='9' => number.push(character),
+            'T' if number.is_empty() => {}
+            unit => {
+                let amount = i64::from_str(&number).map_err(|_| malformed())?;
+                number.clear();
+                let part = match unit {
+                    'W' => TimeDelta::try_weeks(amount),
+                    'D' => TimeDelta::try_days(amount),
+                    'H' => TimeDelta::try_hours(amount),
+                    'M' => TimeDelta::try_minutes(amount),
+                    'S' => TimeDelta::try_seconds(amount),
+                    _ => return Err(malformed()),
+                };
+                duration = part
+                    .and_then(|part| duration.checked_add(&part))
+                    .ok_or_else(malformed)?;
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(malformed());
+    }
+    Ok(duration)
+}
+
+fn parse_event(lines: &[ContentLine]) -> ImportedEvent {
+    let find = |name: &str| lines.iter().find(|line| line.name == name);
+    let uid = find("UID").map(|line| line.value.to_string());
+
+    let fields = (|| {
+        if find("RRULE").is_some() {
+            return Err("Recurring events are not supported".to_string());
+        }
+        let start = find("DTSTART").ok_or("The event has no DTSTART")?;
+        let date = parse_date(start)?;
+        let duration = match (find("DTEND"), find("DURATION")) {
+            (Some(end), _) => Some(parse_date(end)? - date),
+            (None, Some(duration)) => Some(parse_duration(duration.value)?),
+            (None, None) => None,
+        };
+        let duration_minutes = duration
+            .map(|duration| u32::try_from(duration.num_minutes()))
+            .transpose()
+            .map_err(|_| "The event ends before it starts".to_string())?;
+        Ok(ImportedEventFields {
+            summary: find("SUMMARY")
+                .map(|line| unescape_text(line.value))
+                .unwrap_or_default(),
+            date,
+            duration_minutes,
+        })
+    })();
+
+    ImportedEvent { uid, fields }
+}
+
+/// Reads the events of an iCalendar, the ones nested in other components being ignored
+pub fn parse_calendar(calendar: &str) -> Result<Vec<ImportedEvent>, String> {
+    let lines = unfold(calendar);
+    let mut components: Vec<String> = Vec::new();
+    let mut event: Vec<ContentLine> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in &lines {
+        let line = parse_content_line(line).ok_or_else(|| format!("Malformed line {line}"))?;
+        match line.name.as_str() {
+            "BEGIN" => {
+                components.push(line.value.to_ascii_uppercase());
+                if components.last().map(String::as_str) == Some("VEVENT") {
+                    event.clear();
+                }
+            }
+            "END" => {
+                let component = components.pop();
+                if component.as_deref() == Some("VEVENT") {
+                    events.push(parse_event(&event));
+                }
+            }
+            _ if components.last().map(String::as_str) == Some("VEVENT") => event.push(line),
+            _ => {}
+        }
+    }
+
+    if events.is_empty() && !lines.iter().any(|line| line == "BEGIN:VCALENDAR") {
+        return Err("The file is not an iCalendar".to_string());
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_text, parse_calendar, parse_duration, push_line, render_calendar};
+    use crate::domain::{
+        meeting::{JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus},
+        studio::StudioId,
+    };
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a; b, c\\d\ne"), "a\\; b\\, c\\\\d\\ne");
+    }
+
+    #[test]
+    fn test_line_folding() {
+        let mut calendar = String::new();
+        push_line(&mut calendar, &"é".repeat(50));
+        for line in calendar.split("\r\n") {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(
+            calendar.replace("\r\n ", ""),
+            format!("{}\r\n", "é".repeat(50))
+        );
+    }
+
+    #[test]
+    fn test_render_event() {
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Weekly, review".to_string()).unwrap(),
+            date: Utc.with_ymd_and_hms(2025, 3, 3, 9, 30, 0).unwrap(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Cancelled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+
+        let calendar = render_calendar(
+            &[meeting],
+            |meeting| format!("https://meet.example/{}", meeting.id.as_ref()),
+            Utc::now(),
+        );
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(calendar.contains("DTSTART:20250303T093000Z\r\n"));
+        assert!(calendar.contains("DTEND:20250303T103000Z\r\n"));
+        assert!(calendar.contains("SUMMARY:Weekly\\, review\r\n"));
+        assert!(calendar.contains("STATUS:CANCELLED\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse_duration("P1DT2H"), Ok(TimeDelta::hours(26)));
+        assert_eq!(parse_duration("P2W"), Ok(TimeDelta::weeks(2)));
+        assert!(parse_duration("1H").is_err());
+        assert!(parse_duration("PT1").is_err());
+        assert!(parse_duration("P99999999999W").is_err());
+        assert!(parse_duration("P106751991167DT23H").is_err());
+    }
+
+    #[test]
+    fn test_parse_calendar() {
+        let calendar = "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:first\r\n\
+            SUMMARY:Weekly\\, review of the\r\n  week\r\n\
+            DTSTART;TZID=Europe/Paris:20250303T093000\r\n\
+            DTEND;TZID=Europe/Paris:20250303T103000\r\n\
+            BEGIN:VALARM\r\n\
+            DURATION:PT15M\r\n\
+            END:VALARM\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:second\r\n\
+            SUMMARY:Offsite\r\n\
+            DTSTART;VALUE=DATE:20250304\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            UID:third\r\n\
+            DTSTART:20250305T080000Z\r\n\
+            DURATION:PT45M\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+
+        let events = parse_calendar(calendar).unwrap();
+        assert_eq!(events.len(), 3);
+
+        let first = events[0].fields.as_ref().unwrap();
+        assert_eq!(events[0].uid.as_deref(), Some("first"));
+        assert_eq!(first.summary, "Weekly, review of the week");
+        assert_eq!(
+            first.date,
+            Utc.with_ymd_and_hms(2025, 3, 3, 8, 30, 0).unwrap()
+        );
+        assert_eq!(first.duration_minutes, Some(60));
+
+        assert!(events[1].fields.is_err());
+
+        let third = events[2].fields.as_ref().unwrap();
+        assert_eq!(third.summary, "");
+        assert_eq!(third.duration_minutes, Some(45));
+    }
+
+    #[test]
+    fn test_parse_not_a_calendar() {
+        assert!(parse_calendar("name,date\nreview,2025-03-03").is_err());
+    }
+}
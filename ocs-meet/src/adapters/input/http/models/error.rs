@@ -1,12 +1,50 @@
-use crate::services::{CreateMeetingError, JoinMeetingError, ListMeetingError};
-use poem::{error::ResponseError, http::StatusCode};
+use crate::domain::meeting::MeetingId;
+use crate::services::{
+    AddMemberError, AuthorizeError, CalendarFeedError, CancelMeetingError,
+    CancelMeetingSeriesError, ChangeMemberRoleError, CreateApiKeyError, CreateCalendarFeedError,
+    CreateMeetingError, CreateMeetingSeriesError, CreateStudioError, DeleteStudioError,
+    EndMeetingError, GetMeetingError, GetStudioError, HandleRoomEventError, ImportMeetingsError,
+    InviteGuestError, JoinMeetingError, ListApiKeysError, ListMeetingError, ListMembersError,
+    RedeemInvitationError, RemoveMemberError, RescheduleMeetingError, RevokeApiKeyError,
+    RevokeCalendarFeedError, RevokeInvitationError, RoomParticipantsError,
+    UpdateMeetingSeriesError, UpdateStudioError, WaitingRoomError,
+};
+use poem::{error::ResponseError, http::StatusCode, web::Json, IntoResponse, Response};
+use serde::Serialize;
+
+/// Body of the errors caused by another meeting, so clients can point at it
+#[derive(Serialize)]
+struct ConflictBody {
+    message: String,
+    conflicting_meeting_id: uuid::Uuid,
+}
+
+fn conflict_response(status: StatusCode, message: String, meeting_id: &MeetingId) -> Response {
+    Json(ConflictBody {
+        message,
+        conflicting_meeting_id: *meeting_id.as_ref(),
+    })
+    .with_status(status)
+    .into_response()
+}
 
 impl ResponseError for CreateMeetingError {
     fn status(&self) -> StatusCode {
         match self {
             CreateMeetingError::DateInThePast => StatusCode::BAD_REQUEST,
-            CreateMeetingError::DateAlreadyTaken => StatusCode::BAD_REQUEST,
+            CreateMeetingError::DateAlreadyTaken(_) => StatusCode::BAD_REQUEST,
+            CreateMeetingError::StudioNotFound => StatusCode::NOT_FOUND,
             CreateMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            CreateMeetingError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        match self {
+            CreateMeetingError::DateAlreadyTaken(meeting_id) => {
+                conflict_response(self.status(), self.to_string(), meeting_id)
+            }
+            _ => (self.status(), self.to_string()).into_response(),
         }
     }
 }
@@ -14,18 +52,369 @@ impl ResponseError for CreateMeetingError {
 impl ResponseError for ListMeetingError {
     fn status(&self) -> StatusCode {
         match self {
+            ListMeetingError::InvalidLimit => StatusCode::BAD_REQUEST,
             ListMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
 
+impl ResponseError for GetMeetingError {
+    fn status(&self) -> StatusCode {
+        match self {
+            GetMeetingError::NotFound => StatusCode::NOT_FOUND,
+            GetMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 impl ResponseError for JoinMeetingError {
     fn status(&self) -> StatusCode {
         match self {
             JoinMeetingError::NotFound => StatusCode::NOT_FOUND,
+            JoinMeetingError::TooEarly => StatusCode::BAD_REQUEST,
             JoinMeetingError::TooLate => StatusCode::BAD_REQUEST,
+            JoinMeetingError::Cancelled => StatusCode::GONE,
+            JoinMeetingError::Ended => StatusCode::GONE,
+            JoinMeetingError::Full => StatusCode::CONFLICT,
+            JoinMeetingError::ParticipantMissing => StatusCode::BAD_REQUEST,
+            JoinMeetingError::RoleNotAllowed => StatusCode::FORBIDDEN,
+            JoinMeetingError::PasscodeRequired => StatusCode::FORBIDDEN,
+            JoinMeetingError::WrongPasscode => StatusCode::FORBIDDEN,
+            JoinMeetingError::TooManyAttempts => StatusCode::TOO_MANY_REQUESTS,
+            JoinMeetingError::Authorize(err) => err.status(),
             JoinMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            JoinMeetingError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            JoinMeetingError::WaitingRoomRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
             JoinMeetingError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
+
+impl ResponseError for RescheduleMeetingError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RescheduleMeetingError::NotFound => StatusCode::NOT_FOUND,
+            RescheduleMeetingError::Cancelled => StatusCode::GONE,
+            RescheduleMeetingError::Ended => StatusCode::GONE,
+            RescheduleMeetingError::DateInThePast => StatusCode::BAD_REQUEST,
+            RescheduleMeetingError::DateAlreadyTaken(_) => StatusCode::BAD_REQUEST,
+            RescheduleMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RescheduleMeetingError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        match self {
+            RescheduleMeetingError::DateAlreadyTaken(meeting_id) => {
+                conflict_response(self.status(), self.to_string(), meeting_id)
+            }
+            _ => (self.status(), self.to_string()).into_response(),
+        }
+    }
+}
+
+impl ResponseError for CancelMeetingError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CancelMeetingError::NotFound => StatusCode::NOT_FOUND,
+            CancelMeetingError::AlreadyStarted | CancelMeetingError::AlreadyEnded => {
+                StatusCode::CONFLICT
+            }
+            CancelMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            CancelMeetingError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for EndMeetingError {
+    fn status(&self) -> StatusCode {
+        match self {
+            EndMeetingError::NotFound => StatusCode::NOT_FOUND,
+            EndMeetingError::Cancelled => StatusCode::CONFLICT,
+            EndMeetingError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            EndMeetingError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for CreateMeetingSeriesError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CreateMeetingSeriesError::DateInThePast => StatusCode::BAD_REQUEST,
+            CreateMeetingSeriesError::Recurrence(_) => StatusCode::BAD_REQUEST,
+            CreateMeetingSeriesError::DateAlreadyTaken(_, _) => StatusCode::BAD_REQUEST,
+            CreateMeetingSeriesError::StudioNotFound => StatusCode::NOT_FOUND,
+            CreateMeetingSeriesError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            CreateMeetingSeriesError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        match self {
+            CreateMeetingSeriesError::DateAlreadyTaken(_, meeting_id) => {
+                conflict_response(self.status(), self.to_string(), meeting_id)
+            }
+            _ => (self.status(), self.to_string()).into_response(),
+        }
+    }
+}
+
+impl ResponseError for UpdateMeetingSeriesError {
+    fn status(&self) -> StatusCode {
+        match self {
+            UpdateMeetingSeriesError::NotFound => StatusCode::NOT_FOUND,
+            UpdateMeetingSeriesError::DateAlreadyTaken(_, _) => StatusCode::BAD_REQUEST,
+            UpdateMeetingSeriesError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            UpdateMeetingSeriesError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        match self {
+            UpdateMeetingSeriesError::DateAlreadyTaken(_, meeting_id) => {
+                conflict_response(self.status(), self.to_string(), meeting_id)
+            }
+            _ => (self.status(), self.to_string()).into_response(),
+        }
+    }
+}
+
+impl ResponseError for CancelMeetingSeriesError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CancelMeetingSeriesError::NotFound => StatusCode::NOT_FOUND,
+            CancelMeetingSeriesError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            CancelMeetingSeriesError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for CreateCalendarFeedError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CreateCalendarFeedError::CalendarFeedRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for RevokeCalendarFeedError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RevokeCalendarFeedError::CalendarFeedRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for CalendarFeedError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CalendarFeedError::NotFound => StatusCode::NOT_FOUND,
+            CalendarFeedError::CalendarFeedRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            CalendarFeedError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for ImportMeetingsError {
+    fn status(&self) -> StatusCode {
+        match self {
+            ImportMeetingsError::StudioNotFound => StatusCode::NOT_FOUND,
+            ImportMeetingsError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for CreateApiKeyError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CreateApiKeyError::NoScope => StatusCode::BAD_REQUEST,
+            CreateApiKeyError::ExpiryInThePast => StatusCode::BAD_REQUEST,
+            CreateApiKeyError::ApiKeyRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for ListApiKeysError {
+    fn status(&self) -> StatusCode {
+        match self {
+            ListApiKeysError::ApiKeyRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for RevokeApiKeyError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RevokeApiKeyError::NotFound => StatusCode::NOT_FOUND,
+            RevokeApiKeyError::ApiKeyRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for CreateStudioError {
+    fn status(&self) -> StatusCode {
+        match self {
+            CreateStudioError::AlreadyExists => StatusCode::CONFLICT,
+            CreateStudioError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            CreateStudioError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for GetStudioError {
+    fn status(&self) -> StatusCode {
+        match self {
+            GetStudioError::NotFound => StatusCode::NOT_FOUND,
+            GetStudioError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for UpdateStudioError {
+    fn status(&self) -> StatusCode {
+        match self {
+            UpdateStudioError::NotFound => StatusCode::NOT_FOUND,
+            UpdateStudioError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for DeleteStudioError {
+    fn status(&self) -> StatusCode {
+        match self {
+            DeleteStudioError::NotFound => StatusCode::NOT_FOUND,
+            DeleteStudioError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for AuthorizeError {
+    fn status(&self) -> StatusCode {
+        match self {
+            AuthorizeError::Forbidden => StatusCode::FORBIDDEN,
+            AuthorizeError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for AddMemberError {
+    fn status(&self) -> StatusCode {
+        match self {
+            AddMemberError::StudioNotFound => StatusCode::NOT_FOUND,
+            AddMemberError::AlreadyMember => StatusCode::CONFLICT,
+            AddMemberError::Forbidden => StatusCode::FORBIDDEN,
+            AddMemberError::StudioRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AddMemberError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for ListMembersError {
+    fn status(&self) -> StatusCode {
+        match self {
+            ListMembersError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for ChangeMemberRoleError {
+    fn status(&self) -> StatusCode {
+        match self {
+            ChangeMemberRoleError::NotFound => StatusCode::NOT_FOUND,
+            ChangeMemberRoleError::Forbidden => StatusCode::FORBIDDEN,
+            ChangeMemberRoleError::LastOwner => StatusCode::CONFLICT,
+            ChangeMemberRoleError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for RemoveMemberError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RemoveMemberError::NotFound => StatusCode::NOT_FOUND,
+            RemoveMemberError::Forbidden => StatusCode::FORBIDDEN,
+            RemoveMemberError::LastOwner => StatusCode::CONFLICT,
+            RemoveMemberError::MemberRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for InviteGuestError {
+    fn status(&self) -> StatusCode {
+        match self {
+            InviteGuestError::NotFound => StatusCode::NOT_FOUND,
+            InviteGuestError::Cancelled => StatusCode::GONE,
+            InviteGuestError::ExpiryInThePast => StatusCode::BAD_REQUEST,
+            InviteGuestError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            InviteGuestError::InvitationRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for RedeemInvitationError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RedeemInvitationError::Invalid => StatusCode::NOT_FOUND,
+            RedeemInvitationError::Revoked => StatusCode::GONE,
+            RedeemInvitationError::Expired => StatusCode::GONE,
+            RedeemInvitationError::Exhausted => StatusCode::GONE,
+            RedeemInvitationError::TooEarly => StatusCode::BAD_REQUEST,
+            RedeemInvitationError::TooLate => StatusCode::BAD_REQUEST,
+            RedeemInvitationError::Cancelled => StatusCode::GONE,
+            RedeemInvitationError::Ended => StatusCode::GONE,
+            RedeemInvitationError::Full => StatusCode::CONFLICT,
+            RedeemInvitationError::InvitationRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RedeemInvitationError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RedeemInvitationError::WaitingRoomRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RedeemInvitationError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for RevokeInvitationError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RevokeInvitationError::NotFound => StatusCode::NOT_FOUND,
+            RevokeInvitationError::InvitationRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for WaitingRoomError {
+    fn status(&self) -> StatusCode {
+        match self {
+            WaitingRoomError::MeetingNotFound => StatusCode::NOT_FOUND,
+            WaitingRoomError::TicketNotFound => StatusCode::NOT_FOUND,
+            WaitingRoomError::AlreadyDecided => StatusCode::CONFLICT,
+            WaitingRoomError::TooLate => StatusCode::BAD_REQUEST,
+            WaitingRoomError::Cancelled => StatusCode::GONE,
+            WaitingRoomError::Ended => StatusCode::GONE,
+            WaitingRoomError::Full => StatusCode::CONFLICT,
+            WaitingRoomError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            WaitingRoomError::WaitingRoomRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            WaitingRoomError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for RoomParticipantsError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RoomParticipantsError::MeetingNotFound => StatusCode::NOT_FOUND,
+            RoomParticipantsError::ParticipantNotFound => StatusCode::NOT_FOUND,
+            RoomParticipantsError::TrackNotFound => StatusCode::NOT_FOUND,
+            RoomParticipantsError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RoomParticipantsError::ModerationRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            RoomParticipantsError::RoomManager(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ResponseError for HandleRoomEventError {
+    fn status(&self) -> StatusCode {
+        match self {
+            HandleRoomEventError::MeetingRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            HandleRoomEventError::RoomEventRepository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use super::meeting::ParticipantRoleHttp;
+use crate::domain::invitation::{Invitation, InvitationToken};
+use chrono::{DateTime, Utc};
+use poem_openapi::Object;
+
+#[derive(Object)]
+pub struct CreateInvitationRequest {
+    /// Name shown to the others once the guest is in the room
+    #[oai(validator(min_length = "1", max_length = "100"))]
+    pub display_name: String,
+    /// A speaker when omitted
+    pub role: Option<ParticipantRoleHttp>,
+    /// The link can be used any number of times when omitted
+    #[oai(validator(minimum(value = "1"), maximum(value = "1000")))]
+    pub max_uses: Option<u32>,
+    /// The link works until the meeting cannot be joined anymore when omitted
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Object)]
+pub struct InvitationHttp {
+    id: uuid::Uuid,
+    meeting_id: uuid::Uuid,
+    display_name: String,
+    role: ParticipantRoleHttp,
+    max_uses: Option<u32>,
+    uses: u32,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<Invitation> for InvitationHttp {
+    fn from(value: Invitation) -> Self {
+        InvitationHttp {
+            id: *value.id.as_ref(),
+            meeting_id: *value.meeting_id.as_ref(),
+            display_name: value.display_name,
+            role: value.role.into(),
+            max_uses: value.max_uses,
+            uses: value.uses,
+            expires_at: value.expires_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct CreateInvitationResponse {
+    #[oai(flatten)]
+    invitation: InvitationHttp,
+    /// Token of the link, signed by the server
+    token: String,
+    /// Link to hand out to the guest, anyone holding it can join the meeting
+    url: String,
+}
+
+impl CreateInvitationResponse {
+    pub fn new(invitation: Invitation, token: InvitationToken, url: String) -> Self {
+        CreateInvitationResponse {
+            invitation: invitation.into(),
+            token: token.as_ref().clone(),
+            url,
+        }
+    }
+}
@@ -1,19 +1,97 @@
-use crate::domain::{
-    meeting::{Meeting, MeetingName},
-    room::RoomToken,
+use super::waiting_room::WaitingTicketHttp;
+use crate::{
+    domain::{
+        meeting::{
+            JoinWindow, Meeting, MeetingDuration, MeetingName, MeetingSeries, MeetingStatus,
+        },
+        recurrence::RecurrenceRule,
+        room::{ParticipantRole, RoomToken},
+        waiting_room::Admission,
+    },
+    ports::output::meeting_repository::{MeetingCursor, MeetingOrder},
+    services::MeetingPage,
 };
 use chrono::{DateTime, Utc};
 use poem_openapi::{
+    payload::Json,
     registry::{MetaSchema, MetaSchemaRef},
-    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
-    Object,
+    types::{MaybeUndefined, ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+    ApiResponse, Enum, Object,
 };
 use serde_json::Value;
+use std::str::FromStr;
+use validator::ValidationErrors;
 
 #[derive(Object)]
 pub struct CreateMeetingRequest {
     pub name: MeetingName,
     pub date: DateTime<Utc>,
+    /// Duration of the meeting in minutes, the default duration of the studio when omitted
+    pub duration: Option<MeetingDuration>,
+    /// Opens 15 minutes before the start and closes at the end when omitted
+    pub join_window: Option<JoinWindowRequest>,
+    /// Asked to the participants who are not members of the studio
+    pub passcode: Option<String>,
+    /// Makes the participants who are not members of the studio wait for a host to let them in,
+    /// disabled when omitted
+    pub waiting_room: Option<bool>,
+    /// Participants the room holds at once, up to 10000, without limit when omitted
+    #[oai(validator(minimum(value = "1"), maximum(value = "10000")))]
+    pub max_participants: Option<u32>,
+}
+
+#[derive(Object)]
+pub struct UpdateMeetingRequest {
+    pub name: Option<MeetingName>,
+    pub date: Option<DateTime<Utc>>,
+    /// Duration of the meeting in minutes
+    pub duration: Option<MeetingDuration>,
+    pub join_window: Option<JoinWindowRequest>,
+    /// `null` removes the passcode
+    pub passcode: MaybeUndefined<String>,
+    pub waiting_room: Option<bool>,
+}
+
+#[derive(Object)]
+pub struct CreateMeetingSeriesRequest {
+    pub name: MeetingName,
+    /// Start of the first occurrence, the following ones keep its time of day in the timezone of
+    /// the studio
+    pub date: DateTime<Utc>,
+    /// Duration of each occurrence in minutes, the default duration of the studio when omitted
+    pub duration: Option<MeetingDuration>,
+    pub join_window: Option<JoinWindowRequest>,
+    /// Participants the room of each occurrence holds at once, up to 10000, without limit when
+    /// omitted
+    #[oai(validator(minimum(value = "1"), maximum(value = "10000")))]
+    pub max_participants: Option<u32>,
+    pub rrule: RecurrenceRule,
+}
+
+#[derive(Object)]
+pub struct UpdateMeetingSeriesRequest {
+    pub name: Option<MeetingName>,
+    /// Duration of each occurrence in minutes
+    pub duration: Option<MeetingDuration>,
+    pub join_window: Option<JoinWindowRequest>,
+}
+
+#[derive(Object)]
+pub struct JoinWindowRequest {
+    /// Minutes before the start during which participants can already enter
+    #[oai(validator(maximum(value = "240")))]
+    pub early_entry: u32,
+    /// Minutes after the end during which participants can still join
+    #[oai(validator(maximum(value = "240")))]
+    pub late_join: u32,
+}
+
+impl TryFrom<JoinWindowRequest> for JoinWindow {
+    type Error = ValidationErrors;
+
+    fn try_from(value: JoinWindowRequest) -> Result<Self, Self::Error> {
+        JoinWindow::new(value.early_entry, value.late_join)
+    }
 }
 
 impl Type for MeetingName {
@@ -60,23 +138,235 @@ impl ToJSON for MeetingName {
     }
 }
 
+impl Type for RecurrenceRule {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("RecurrenceRule")
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            description: Some(
+                "RFC 5545 rule, FREQ=DAILY or WEEKLY with INTERVAL, BYDAY and COUNT or UNTIL",
+            ),
+            example: Some(Value::String(
+                "FREQ=WEEKLY;BYDAY=MO,TH;COUNT=10".to_string(),
+            )),
+            ..MetaSchema::new("string")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for RecurrenceRule {
+    fn parse_from_json(value: Option<Value>) -> poem_openapi::types::ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(string) = value {
+            let rule = RecurrenceRule::from_str(&string).map_err(ParseError::custom)?;
+            ParseResult::Ok(rule)
+        } else {
+            ParseResult::Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ToJSON for RecurrenceRule {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+impl Type for MeetingDuration {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = u32;
+
+    type RawElementValueType = u32;
+
+    fn name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("MeetingDuration")
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("integer", "uint32")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self.as_ref())
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for MeetingDuration {
+    fn parse_from_json(value: Option<Value>) -> poem_openapi::types::ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let Some(minutes) = value
+            .as_u64()
+            .and_then(|minutes| u32::try_from(minutes).ok())
+        else {
+            return ParseResult::Err(ParseError::expected_type(value));
+        };
+        let duration = MeetingDuration::try_from(minutes).map_err(ParseError::from)?;
+        ParseResult::Ok(duration)
+    }
+}
+
+impl ToJSON for MeetingDuration {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::from(self.minutes()))
+    }
+}
+
+#[derive(Enum)]
+#[oai(rename_all = "lowercase")]
+pub enum MeetingStatusHttp {
+    Scheduled,
+    Live,
+    Cancelled,
+    Ended,
+}
+
+impl From<MeetingStatus> for MeetingStatusHttp {
+    fn from(value: MeetingStatus) -> Self {
+        match value {
+            MeetingStatus::Scheduled => MeetingStatusHttp::Scheduled,
+            MeetingStatus::Live => MeetingStatusHttp::Live,
+            MeetingStatus::Cancelled => MeetingStatusHttp::Cancelled,
+            MeetingStatus::Ended => MeetingStatusHttp::Ended,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "lowercase")]
+pub enum MeetingOrderHttp {
+    Asc,
+    Desc,
+}
+
+impl From<MeetingOrderHttp> for MeetingOrder {
+    fn from(value: MeetingOrderHttp) -> Self {
+        match value {
+            MeetingOrderHttp::Asc => MeetingOrder::Ascending,
+            MeetingOrderHttp::Desc => MeetingOrder::Descending,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct JoinWindowHttp {
+    early_entry: u32,
+    late_join: u32,
+    opens_at: DateTime<Utc>,
+    closes_at: DateTime<Utc>,
+}
+
+impl From<&Meeting> for JoinWindowHttp {
+    fn from(meeting: &Meeting) -> Self {
+        JoinWindowHttp {
+            early_entry: meeting.join_window.early_entry_minutes(),
+            late_join: meeting.join_window.late_join_minutes(),
+            opens_at: meeting.join_opens_at(),
+            closes_at: meeting.join_closes_at(),
+        }
+    }
+}
+
 #[derive(Object)]
 pub struct MeetingHttp {
     id: uuid::Uuid,
     studio_id: uuid::Uuid,
     name: MeetingName,
     date: DateTime<Utc>,
+    /// Duration of the meeting in minutes
+    duration: MeetingDuration,
+    end_date: DateTime<Utc>,
+    join_window: JoinWindowHttp,
+    status: MeetingStatusHttp,
+    /// The series the meeting is an occurrence of
+    series_id: Option<uuid::Uuid>,
+    /// Whether the participants who are not members of the studio need a passcode
+    has_passcode: bool,
+    /// Whether the participants who are not members of the studio wait for a host to let them in
+    waiting_room: bool,
+    max_participants: Option<u32>,
+    /// When the first participant entered the room
+    started_at: Option<DateTime<Utc>>,
+    /// When the room was closed
+    ended_at: Option<DateTime<Utc>>,
 }
 
 pub type CreateMeetingResponse = MeetingHttp;
+pub type UpdateMeetingResponse = MeetingHttp;
+pub type CancelMeetingResponse = MeetingHttp;
+pub type EndMeetingResponse = MeetingHttp;
+pub type GetMeetingResponse = MeetingHttp;
 
 impl From<Meeting> for CreateMeetingResponse {
     fn from(value: Meeting) -> Self {
+        let end_date = value.end_date();
+        let join_window = JoinWindowHttp::from(&value);
         CreateMeetingResponse {
             id: *value.id.as_ref(),
             studio_id: *value.studio_id.as_ref(),
             name: value.name,
             date: value.date,
+            duration: value.duration,
+            end_date,
+            join_window,
+            status: value.status.into(),
+            series_id: value.series_id.map(|series_id| *series_id.as_ref()),
+            has_passcode: value.passcode.is_some(),
+            waiting_room: value.waiting_room,
+            max_participants: value.max_participants,
+            started_at: value.started_at,
+            ended_at: value.ended_at,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct MeetingSeriesHttp {
+    id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    rrule: RecurrenceRule,
+    starts_at: DateTime<Utc>,
+    /// The occurrences affected by the operation, all of them on creation and the upcoming ones
+    /// afterwards
+    occurrences: Vec<MeetingHttp>,
+}
+
+pub type CreateMeetingSeriesResponse = MeetingSeriesHttp;
+pub type UpdateMeetingSeriesResponse = MeetingSeriesHttp;
+pub type CancelMeetingSeriesResponse = MeetingSeriesHttp;
+
+impl From<(MeetingSeries, Vec<Meeting>)> for MeetingSeriesHttp {
+    fn from((series, meetings): (MeetingSeries, Vec<Meeting>)) -> Self {
+        MeetingSeriesHttp {
+            id: *series.id.as_ref(),
+            studio_id: *series.studio_id.as_ref(),
+            rrule: series.rule,
+            starts_at: series.starts_at,
+            occurrences: meetings.into_iter().map(MeetingHttp::from).collect(),
         }
     }
 }
@@ -84,12 +374,50 @@ impl From<Meeting> for CreateMeetingResponse {
 #[derive(Object)]
 pub struct ListMeetingsResponse {
     meetings: Vec<MeetingHttp>,
+    /// To pass as `cursor` to fetch the next page, absent on the last page
+    next_cursor: Option<MeetingCursor>,
 }
 
-impl From<Vec<Meeting>> for ListMeetingsResponse {
-    fn from(meetings: Vec<Meeting>) -> Self {
+impl From<MeetingPage> for ListMeetingsResponse {
+    fn from(page: MeetingPage) -> Self {
         ListMeetingsResponse {
-            meetings: meetings.into_iter().map(MeetingHttp::from).collect(),
+            meetings: page.meetings.into_iter().map(MeetingHttp::from).collect(),
+            next_cursor: page.next_cursor,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "snake_case")]
+pub enum ParticipantRoleHttp {
+    /// Runs the meeting, including the other participants
+    Host,
+    /// Takes part with their camera and microphone
+    Speaker,
+    /// Only watches and chats
+    Viewer,
+    /// Records the meeting without showing up in it
+    RecorderBot,
+}
+
+impl From<ParticipantRoleHttp> for ParticipantRole {
+    fn from(value: ParticipantRoleHttp) -> Self {
+        match value {
+            ParticipantRoleHttp::Host => ParticipantRole::Host,
+            ParticipantRoleHttp::Speaker => ParticipantRole::Speaker,
+            ParticipantRoleHttp::Viewer => ParticipantRole::Viewer,
+            ParticipantRoleHttp::RecorderBot => ParticipantRole::RecorderBot,
+        }
+    }
+}
+
+impl From<ParticipantRole> for ParticipantRoleHttp {
+    fn from(value: ParticipantRole) -> Self {
+        match value {
+            ParticipantRole::Host => ParticipantRoleHttp::Host,
+            ParticipantRole::Speaker => ParticipantRoleHttp::Speaker,
+            ParticipantRole::Viewer => ParticipantRoleHttp::Viewer,
+            ParticipantRole::RecorderBot => ParticipantRoleHttp::RecorderBot,
         }
     }
 }
@@ -106,3 +434,22 @@ impl From<RoomToken> for JoinMeetingResponse {
         }
     }
 }
+
+#[derive(ApiResponse)]
+pub enum JoinResponse {
+    /// The participant can enter the room with the token
+    #[oai(status = 200)]
+    Ok(Json<JoinMeetingResponse>),
+    /// The participant waits for a host, checking the ticket until they are let in
+    #[oai(status = 202)]
+    Waiting(Json<WaitingTicketHttp>),
+}
+
+impl From<Admission> for JoinResponse {
+    fn from(value: Admission) -> Self {
+        match value {
+            Admission::Granted(token) => JoinResponse::Ok(Json(token.into())),
+            Admission::Waiting(ticket) => JoinResponse::Waiting(Json(ticket.into())),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::domain::member::{Member, MemberRole};
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object};
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "lowercase")]
+pub enum MemberRoleHttp {
+    /// Everything, including deleting the studio
+    Owner,
+    /// Manages the settings, the integrations and the members of the studio
+    Admin,
+    /// Schedules and runs the meetings
+    Member,
+    /// Only sees the meetings
+    Viewer,
+}
+
+impl From<MemberRoleHttp> for MemberRole {
+    fn from(value: MemberRoleHttp) -> Self {
+        match value {
+            MemberRoleHttp::Owner => MemberRole::Owner,
+            MemberRoleHttp::Admin => MemberRole::Admin,
+            MemberRoleHttp::Member => MemberRole::Member,
+            MemberRoleHttp::Viewer => MemberRole::Viewer,
+        }
+    }
+}
+
+impl From<MemberRole> for MemberRoleHttp {
+    fn from(value: MemberRole) -> Self {
+        match value {
+            MemberRole::Owner => MemberRoleHttp::Owner,
+            MemberRole::Admin => MemberRoleHttp::Admin,
+            MemberRole::Member => MemberRoleHttp::Member,
+            MemberRole::Viewer => MemberRoleHttp::Viewer,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct AddMemberRequest {
+    /// Identifier of the user at the identity provider, the `sub` claim of their tokens
+    #[oai(validator(min_length = "1", max_length = "255"))]
+    pub user_id: String,
+    #[oai(validator(min_length = "1", max_length = "100"))]
+    pub display_name: Option<String>,
+    pub role: MemberRoleHttp,
+}
+
+#[derive(Object)]
+pub struct ChangeMemberRoleRequest {
+    pub role: MemberRoleHttp,
+}
+
+#[derive(Object)]
+pub struct MemberHttp {
+    user_id: String,
+    display_name: Option<String>,
+    role: MemberRoleHttp,
+    created_at: DateTime<Utc>,
+}
+
+pub type AddMemberResponse = MemberHttp;
+pub type ChangeMemberRoleResponse = MemberHttp;
+
+impl From<Member> for MemberHttp {
+    fn from(value: Member) -> Self {
+        MemberHttp {
+            user_id: value.user_id.as_ref().clone(),
+            display_name: value.display_name,
+            role: value.role.into(),
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct ListMembersResponse {
+    members: Vec<MemberHttp>,
+}
+
+impl From<Vec<Member>> for ListMembersResponse {
+    fn from(value: Vec<Member>) -> Self {
+        ListMembersResponse {
+            members: value.into_iter().map(MemberHttp::from).collect(),
+        }
+    }
+}
@@ -1,2 +1,10 @@
+pub mod api_key;
+pub mod calendar;
 pub mod error;
+pub mod invitation;
 pub mod meeting;
+pub mod member;
+pub mod participant;
+pub mod studio;
+pub mod waiting_room;
+pub mod webhook;
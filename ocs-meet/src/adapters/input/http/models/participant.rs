@@ -0,0 +1,151 @@
+use crate::domain::{
+    moderation::{ModerationAction, ModerationKind},
+    room::{PermissionChanges, RoomParticipant, TrackSource},
+};
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object};
+
+#[derive(Object)]
+pub struct RoomParticipantHttp {
+    /// Id to remove the participant with
+    participant_id: String,
+    display_name: String,
+    joined_at: DateTime<Utc>,
+    /// Whether they are sharing their camera, microphone or screen
+    is_publishing: bool,
+}
+
+impl From<RoomParticipant> for RoomParticipantHttp {
+    fn from(value: RoomParticipant) -> Self {
+        RoomParticipantHttp {
+            participant_id: value.identity,
+            display_name: value.name,
+            joined_at: value.joined_at,
+            is_publishing: value.is_publishing,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct ListParticipantsResponse {
+    participants: Vec<RoomParticipantHttp>,
+}
+
+impl From<Vec<RoomParticipant>> for ListParticipantsResponse {
+    fn from(value: Vec<RoomParticipant>) -> Self {
+        ListParticipantsResponse {
+            participants: value.into_iter().map(RoomParticipantHttp::from).collect(),
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "snake_case")]
+pub enum TrackSourceHttp {
+    Microphone,
+    Camera,
+    ScreenShare,
+}
+
+impl From<TrackSourceHttp> for TrackSource {
+    fn from(value: TrackSourceHttp) -> Self {
+        match value {
+            TrackSourceHttp::Microphone => TrackSource::Microphone,
+            TrackSourceHttp::Camera => TrackSource::Camera,
+            TrackSourceHttp::ScreenShare => TrackSource::ScreenShare,
+        }
+    }
+}
+
+impl From<TrackSource> for TrackSourceHttp {
+    fn from(value: TrackSource) -> Self {
+        match value {
+            TrackSource::Microphone => TrackSourceHttp::Microphone,
+            TrackSource::Camera => TrackSourceHttp::Camera,
+            TrackSource::ScreenShare => TrackSourceHttp::ScreenShare,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct MuteParticipantRequest {
+    pub source: TrackSourceHttp,
+}
+
+/// Permissions to change, the ones left out are kept
+#[derive(Object)]
+pub struct UpdatePermissionsRequest {
+    /// Whether the participant can share their camera, microphone or screen
+    pub can_publish: Option<bool>,
+    /// Whether the participant can chat
+    pub can_publish_data: Option<bool>,
+}
+
+impl From<UpdatePermissionsRequest> for PermissionChanges {
+    fn from(value: UpdatePermissionsRequest) -> Self {
+        PermissionChanges {
+            can_publish: value.can_publish,
+            can_publish_data: value.can_publish_data,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "snake_case")]
+pub enum ModerationKindHttp {
+    Mute,
+    UpdatePermissions,
+}
+
+#[derive(Object)]
+pub struct ModerationActionHttp {
+    id: uuid::Uuid,
+    /// User who acted, absent for the backend of the studio
+    performed_by: Option<String>,
+    participant_id: String,
+    action: ModerationKindHttp,
+    /// Source muted
+    source: Option<TrackSourceHttp>,
+    can_publish: Option<bool>,
+    can_publish_data: Option<bool>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ModerationAction> for ModerationActionHttp {
+    fn from(value: ModerationAction) -> Self {
+        let (action, source, changes) = match value.kind {
+            ModerationKind::Mute(source) => (
+                ModerationKindHttp::Mute,
+                Some(source.into()),
+                PermissionChanges::default(),
+            ),
+            ModerationKind::UpdatePermissions(changes) => {
+                (ModerationKindHttp::UpdatePermissions, None, changes)
+            }
+        };
+
+        ModerationActionHttp {
+            id: *value.id.as_ref(),
+            performed_by: value.performed_by.map(|user_id| user_id.as_ref().clone()),
+            participant_id: value.participant_identity,
+            action,
+            source,
+            can_publish: changes.can_publish,
+            can_publish_data: changes.can_publish_data,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct ListModerationActionsResponse {
+    actions: Vec<ModerationActionHttp>,
+}
+
+impl From<Vec<ModerationAction>> for ListModerationActionsResponse {
+    fn from(value: Vec<ModerationAction>) -> Self {
+        ListModerationActionsResponse {
+            actions: value.into_iter().map(ModerationActionHttp::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::domain::{
+    meeting::MeetingDuration,
+    studio::{Studio, StudioName},
+};
+use chrono::{DateTime, Utc};
+use poem_openapi::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+    Object,
+};
+use serde_json::Value;
+
+#[derive(Object)]
+pub struct CreateStudioRequest {
+    pub name: StudioName,
+    /// IANA name of the timezone of the studio, e.g. `Europe/Paris`, UTC when omitted
+    pub timezone: Option<String>,
+    /// Duration in minutes of the meetings created without one, one hour when omitted
+    pub default_duration: Option<MeetingDuration>,
+}
+
+#[derive(Object)]
+pub struct UpdateStudioRequest {
+    pub name: Option<StudioName>,
+    /// IANA name of the timezone of the studio, e.g. `Europe/Paris`
+    pub timezone: Option<String>,
+    /// Duration in minutes of the meetings created without one
+    pub default_duration: Option<MeetingDuration>,
+}
+
+impl Type for StudioName {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = String;
+
+    type RawElementValueType = String;
+
+    fn name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("StudioName")
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new("string")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self.as_ref())
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for StudioName {
+    fn parse_from_json(value: Option<Value>) -> poem_openapi::types::ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(string) = value {
+            let studio_name = StudioName::try_from(string).map_err(ParseError::from)?;
+            ParseResult::Ok(studio_name)
+        } else {
+            ParseResult::Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ToJSON for StudioName {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.as_ref().to_string()))
+    }
+}
+
+#[derive(Object)]
+pub struct StudioHttp {
+    id: uuid::Uuid,
+    name: StudioName,
+    timezone: String,
+    /// Duration in minutes of the meetings created without one
+    default_duration: MeetingDuration,
+    created_at: DateTime<Utc>,
+}
+
+pub type CreateStudioResponse = StudioHttp;
+pub type GetStudioResponse = StudioHttp;
+pub type UpdateStudioResponse = StudioHttp;
+
+impl From<Studio> for StudioHttp {
+    fn from(value: Studio) -> Self {
+        StudioHttp {
+            id: *value.id.as_ref(),
+            name: value.name,
+            timezone: value.timezone.name().to_string(),
+            default_duration: value.default_duration,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::meeting::ParticipantRoleHttp;
+use crate::domain::waiting_room::{Admission, AdmissionStatus, WaitingTicket};
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object};
+
+#[derive(Enum, Clone, Copy)]
+#[oai(rename_all = "lowercase")]
+pub enum AdmissionStatusHttp {
+    /// No host has answered yet
+    Pending,
+    /// A host let the participant in
+    Admitted,
+    /// A host turned the participant away
+    Denied,
+}
+
+impl From<AdmissionStatus> for AdmissionStatusHttp {
+    fn from(value: AdmissionStatus) -> Self {
+        match value {
+            AdmissionStatus::Pending => AdmissionStatusHttp::Pending,
+            AdmissionStatus::Admitted => AdmissionStatusHttp::Admitted,
+            AdmissionStatus::Denied => AdmissionStatusHttp::Denied,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct WaitingTicketHttp {
+    /// Id to check the ticket with, and for the hosts to answer it
+    id: uuid::Uuid,
+    meeting_id: uuid::Uuid,
+    participant_id: String,
+    display_name: String,
+    role: ParticipantRoleHttp,
+    status: AdmissionStatusHttp,
+    created_at: DateTime<Utc>,
+}
+
+impl From<WaitingTicket> for WaitingTicketHttp {
+    fn from(value: WaitingTicket) -> Self {
+        WaitingTicketHttp {
+            id: *value.id.as_ref(),
+            meeting_id: *value.meeting_id.as_ref(),
+            participant_id: value.participant.identity,
+            display_name: value.participant.name,
+            role: value.role.into(),
+            status: value.status.into(),
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct ListWaitingGuestsResponse {
+    guests: Vec<WaitingTicketHttp>,
+}
+
+impl From<Vec<WaitingTicket>> for ListWaitingGuestsResponse {
+    fn from(value: Vec<WaitingTicket>) -> Self {
+        ListWaitingGuestsResponse {
+            guests: value.into_iter().map(WaitingTicketHttp::from).collect(),
+        }
+    }
+}
+
+#[derive(Object)]
+pub struct AdmissionResponse {
+    status: AdmissionStatusHttp,
+    /// Token to enter the room, once admitted
+    token: Option<String>,
+}
+
+impl From<Admission> for AdmissionResponse {
+    fn from(value: Admission) -> Self {
+        match value {
+            Admission::Granted(token) => AdmissionResponse {
+                status: AdmissionStatusHttp::Admitted,
+                token: Some(token.as_ref().clone()),
+            },
+            Admission::Waiting(ticket) => AdmissionResponse {
+                status: ticket.status.into(),
+                token: None,
+            },
+        }
+    }
+}
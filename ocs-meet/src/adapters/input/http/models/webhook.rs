@@ -0,0 +1,65 @@
+use crate::domain::{
+    meeting::MeetingId,
+    room::{RoomEvent, RoomEventKind},
+};
+use chrono::{DateTime, Utc};
+use livekit_protocol as proto;
+use std::str::FromStr;
+
+/// The event when it is one we follow and its room is named after a meeting, as the rooms we
+/// create are
+pub fn room_event(event: proto::WebhookEvent) -> Option<RoomEvent> {
+    let kind = RoomEventKind::from_str(&event.event).ok()?;
+    let room = event.room?;
+    let meeting_id = uuid::Uuid::parse_str(&room.name).ok()?;
+
+    Some(RoomEvent {
+        id: event.id,
+        meeting_id: MeetingId::from(meeting_id),
+        kind,
+        occurred_at: DateTime::from_timestamp(event.created_at, 0).unwrap_or_else(Utc::now),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::room_event;
+    use crate::domain::room::RoomEventKind;
+    use livekit_protocol as proto;
+
+    #[test]
+    fn test_room_event() {
+        let meeting_id = uuid::Uuid::new_v4();
+        let event = proto::WebhookEvent {
+            event: "participant_joined".to_string(),
+            room: Some(proto::Room {
+                name: meeting_id.to_string(),
+                ..Default::default()
+            }),
+            id: "EV_4Xk9wTz".to_string(),
+            created_at: 1_746_608_400,
+            ..Default::default()
+        };
+
+        let Some(joined) = room_event(event.clone()) else {
+            panic!("The event should concern the meeting");
+        };
+        assert_eq!(joined.meeting_id.as_ref(), &meeting_id);
+        assert_eq!(joined.kind, RoomEventKind::ParticipantJoined);
+        assert_eq!(joined.occurred_at.timestamp(), 1_746_608_400);
+
+        let track_published = proto::WebhookEvent {
+            event: "track_published".to_string(),
+            ..event.clone()
+        };
+        assert!(room_event(track_published).is_none());
+        let other_room = proto::WebhookEvent {
+            room: Some(proto::Room {
+                name: "lobby".to_string(),
+                ..Default::default()
+            }),
+            ..event
+        };
+        assert!(room_event(other_room).is_none());
+    }
+}
@@ -3,4 +3,7 @@ use poem_openapi::Tags;
 #[derive(Tags)]
 pub enum ApiTags {
     Meeting,
+    ApiKey,
+    Studio,
+    Member,
 }
@@ -1,38 +1,575 @@
 use crate::{
-    domain::{meeting::MeetingId, room::RoomToken},
+    domain::{
+        meeting::MeetingId,
+        room::{
+            Participant, ParticipantRole, PermissionChanges, RoomParticipant, RoomSettings,
+            RoomToken, TrackSource,
+        },
+    },
     ports::output::room_manager::{RoomManager, RoomManagerError},
 };
-use livekit_api::access_token;
+use chrono::DateTime;
+use livekit_api::{
+    access_token,
+    services::{
+        room::{CreateRoomOptions, RoomClient, UpdateParticipantOptions},
+        ServiceError, TwirpError, TwirpErrorCode,
+    },
+};
+use livekit_protocol as proto;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct Livekit {
     api_key: Arc<String>,
     api_secret: Arc<String>,
+    /// Client of the RoomService API of the server, to act on the rooms once open
+    rooms: Arc<RoomClient>,
 }
 
 impl Livekit {
-    pub fn new(api_key: String, api_secret: String) -> Self {
+    pub fn new(url: String, api_key: String, api_secret: String) -> Self {
         Self {
+            rooms: Arc::new(RoomClient::with_api_key(&url, &api_key, &api_secret)),
             api_key: Arc::new(api_key),
             api_secret: Arc::new(api_secret),
         }
     }
 }
 
+/// Whether the server answered that the room or the participant does not exist
+fn is_not_found(err: &ServiceError) -> bool {
+    matches!(
+        err,
+        ServiceError::Twirp(TwirpError::Twirp(code)) if code.code == TwirpErrorCode::NOT_FOUND
+    )
+}
+
+/// Sources of the tracks LiveKit publishes for the source
+fn track_sources(source: TrackSource) -> &'static [proto::TrackSource] {
+    match source {
+        TrackSource::Microphone => &[proto::TrackSource::Microphone],
+        TrackSource::Camera => &[proto::TrackSource::Camera],
+        TrackSource::ScreenShare => &[
+            proto::TrackSource::ScreenShare,
+            proto::TrackSource::ScreenShareAudio,
+        ],
+    }
+}
+
+impl Livekit {
+    async fn participant(
+        &self,
+        room: &str,
+        identity: &str,
+    ) -> Result<proto::ParticipantInfo, RoomManagerError> {
+        self.rooms
+            .get_participant(room, identity)
+            .await
+            .map_err(|err| match err {
+                err if is_not_found(&err) => RoomManagerError::ParticipantNotFound,
+                err => RoomManagerError::LiveKitService(err),
+            })
+    }
+}
+
+impl From<proto::ParticipantInfo> for RoomParticipant {
+    fn from(info: proto::ParticipantInfo) -> Self {
+        RoomParticipant {
+            joined_at: DateTime::from_timestamp(info.joined_at, 0).unwrap_or_default(),
+            is_publishing: info.is_publisher,
+            identity: info.identity,
+            name: info.name,
+        }
+    }
+}
+
+/// The permissions in the room of the meeting matching the role of the participant
+fn grants(meeting_id: &MeetingId, role: ParticipantRole) -> access_token::VideoGrants {
+    let grants = access_token::VideoGrants {
+        room_join: true,
+        room: meeting_id.as_ref().to_string(),
+        ..Default::default()
+    };
+    match role {
+        ParticipantRole::Host => access_token::VideoGrants {
+            room_admin: true,
+            ..grants
+        },
+        ParticipantRole::Speaker => grants,
+        ParticipantRole::Viewer => access_token::VideoGrants {
+            can_publish: false,
+            ..grants
+        },
+        ParticipantRole::RecorderBot => access_token::VideoGrants {
+            can_publish: false,
+            can_publish_data: false,
+            hidden: true,
+            recorder: true,
+            ..grants
+        },
+    }
+}
+
 impl RoomManager for Livekit {
-    async fn create_token(&self, meeting_id: MeetingId) -> Result<RoomToken, RoomManagerError> {
-        let identity = uuid::Uuid::new_v4();
+    async fn create_room(
+        &self,
+        meeting_id: &MeetingId,
+        settings: RoomSettings,
+    ) -> Result<(), RoomManagerError> {
+        // Read by the clients, to show what the room is about
+        let metadata = serde_json::json!({
+            "meeting_name": settings.meeting_name,
+            "studio_id": settings.studio_id.as_ref(),
+        });
+
+        self.rooms
+            .create_room(
+                &meeting_id.as_ref().to_string(),
+                CreateRoomOptions {
+                    empty_timeout: u32::try_from(settings.empty_timeout.num_seconds())
+                        .unwrap_or(u32::MAX),
+                    // LiveKit takes 0 as no limit
+                    max_participants: settings.max_participants.unwrap_or(0),
+                    metadata: metadata.to_string(),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_token(
+        &self,
+        meeting_id: MeetingId,
+        participant: Participant,
+        role: ParticipantRole,
+    ) -> Result<RoomToken, RoomManagerError> {
         let token = access_token::AccessToken::with_api_key(&self.api_key, &self.api_secret)
-            .with_identity(&identity.to_string())
-            .with_grants(access_token::VideoGrants {
-                room_join: true,
-                room: meeting_id.as_ref().to_string(),
-                ..Default::default()
-            });
+            .with_identity(&participant.identity)
+            .with_name(&participant.name)
+            .with_grants(grants(&meeting_id, role));
         token
             .to_jwt()
             .map_err(RoomManagerError::LiveKitAccessToken)
             .map(RoomToken::from)
     }
+
+    async fn list_participants(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> Result<Vec<RoomParticipant>, RoomManagerError> {
+        match self
+            .rooms
+            .list_participants(&meeting_id.as_ref().to_string())
+            .await
+        {
+            Ok(participants) => Ok(participants.into_iter().map(Into::into).collect()),
+            // The room only exists while someone is in it
+            Err(err) if is_not_found(&err) => Ok(vec![]),
+            Err(err) => Err(RoomManagerError::LiveKitService(err)),
+        }
+    }
+
+    async fn remove_participant(
+        &self,
+        meeting_id: &MeetingId,
+        identity: &str,
+    ) -> Result<(), RoomManagerError> {
+        self.rooms
+            .remove_participant(&meeting_id.as_ref().to_string(), identity)
+            .await
+            .map_err(|err| match err {
+                err if is_not_found(&err) => RoomManagerError::ParticipantNotFound,
+                err => RoomManagerError::LiveKitService(err),
+            })
+    }
+
+    async fn mute_track(
+        &self,
+        meeting_id: &MeetingId,
+        identity: &str,
+        source: TrackSource,
+    ) -> Result<(), RoomManagerError> {
+        let room = meeting_id.as_ref().to_string();
+        let participant = self.participant(&room, identity).await?;
+
+        let sources = track_sources(source);
+        let tracks = participant
+            .tracks
+            .iter()
+            .filter(|track| sources.contains(&track.source()))
+            .collect::<Vec<_>>();
+        if tracks.is_empty() {
+            return Err(RoomManagerError::TrackNotFound);
+        }
+
+        for track in tracks {
+            self.rooms
+                .mute_published_track(&room, identity, &track.sid, true)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn update_permissions(
+        &self,
+        meeting_id: &MeetingId,
+        identity: &str,
+        changes: PermissionChanges,
+    ) -> Result<(), RoomManagerError> {
+        let room = meeting_id.as_ref().to_string();
+        let participant = self.participant(&room, identity).await?;
+
+        // LiveKit replaces the whole permission, the ones left out are kept as they are
+        let mut permission = participant.permission.unwrap_or_default();
+        if let Some(can_publish) = changes.can_publish {
+            permission.can_publish = can_publish;
+        }
+        if let Some(can_publish_data) = changes.can_publish_data {
+            permission.can_publish_data = can_publish_data;
+        }
+
+        self.rooms
+            .update_participant(
+                &room,
+                identity,
+                UpdateParticipantOptions {
+                    permission: Some(permission),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_room(&self, meeting_id: &MeetingId) -> Result<(), RoomManagerError> {
+        match self
+            .rooms
+            .delete_room(&meeting_id.as_ref().to_string())
+            .await
+        {
+            // The room has never been opened or is already closed
+            Err(err) if is_not_found(&err) => Ok(()),
+            result => Ok(result?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{grants, Livekit};
+    use crate::{
+        domain::{
+            meeting::MeetingId,
+            room::{self, ParticipantRole, PermissionChanges, RoomSettings},
+            studio::StudioId,
+        },
+        ports::output::room_manager::{RoomManager, RoomManagerError},
+    };
+    use chrono::TimeDelta;
+    use livekit_protocol::{
+        CreateRoomRequest, DeleteRoomRequest, DeleteRoomResponse, ListParticipantsRequest,
+        ListParticipantsResponse, MuteRoomTrackRequest, MuteRoomTrackResponse, ParticipantInfo,
+        ParticipantPermission, RemoveParticipantResponse, RoomParticipantIdentity, TrackInfo,
+        TrackSource, UpdateParticipantRequest,
+    };
+    use poem::{
+        endpoint::make,
+        listener::{Acceptor, Listener, TcpListener},
+        Request, Response, Server,
+    };
+    use prost::Message;
+    use std::sync::{Arc, Mutex};
+
+    /// `customer-42`, connected with their microphone on
+    fn customer() -> ParticipantInfo {
+        ParticipantInfo {
+            identity: "customer-42".to_string(),
+            name: "Jane Doe".to_string(),
+            joined_at: 1_740_000_000,
+            is_publisher: true,
+            tracks: vec![TrackInfo {
+                sid: "TR_microphone".to_string(),
+                source: TrackSource::Microphone.into(),
+                ..Default::default()
+            }],
+            permission: Some(ParticipantPermission {
+                can_subscribe: true,
+                can_publish: true,
+                can_publish_data: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// LiveKit server answering the RoomService calls for a single room, where `customer-42` is
+    /// connected
+    struct StubServer {
+        livekit: Livekit,
+        muted_tracks: Arc<Mutex<Vec<String>>>,
+        permission: Arc<Mutex<Option<ParticipantPermission>>>,
+        created_room: Arc<Mutex<Option<CreateRoomRequest>>>,
+    }
+
+    impl StubServer {
+        async fn start(room: &MeetingId) -> StubServer {
+            let acceptor = TcpListener::bind("127.0.0.1:0")
+                .into_acceptor()
+                .await
+                .unwrap();
+            let address = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+
+            let stub = StubServer {
+                livekit: Livekit::new(
+                    format!("http://{address}"),
+                    "api-key".to_string(),
+                    "a secret long enough for the tests".to_string(),
+                ),
+                muted_tracks: Arc::default(),
+                permission: Arc::default(),
+                created_room: Arc::default(),
+            };
+
+            let room = room.as_ref().to_string();
+            let (muted_tracks, permission, created_room) = (
+                stub.muted_tracks.clone(),
+                stub.permission.clone(),
+                stub.created_room.clone(),
+            );
+            let endpoint = make(move |req: Request| {
+                let (room, muted_tracks, permission, created_room) = (
+                    room.clone(),
+                    muted_tracks.clone(),
+                    permission.clone(),
+                    created_room.clone(),
+                );
+                async move {
+                    let path = req.original_uri().path().to_string();
+                    let body = req.into_body().into_bytes().await.unwrap();
+                    let not_found = Response::builder()
+                        .status(poem::http::StatusCode::NOT_FOUND)
+                        .content_type("application/json")
+                        .body(r#"{"code":"not_found","msg":"not found"}"#);
+
+                    if path.ends_with("CreateRoom") {
+                        let request = CreateRoomRequest::decode(body).unwrap();
+                        let response = livekit_protocol::Room {
+                            name: request.name.clone(),
+                            ..Default::default()
+                        };
+                        *created_room.lock().unwrap() = Some(request);
+                        return Response::builder().body(response.encode_to_vec());
+                    }
+
+                    if path.ends_with("DeleteRoom") {
+                        let request = DeleteRoomRequest::decode(body).unwrap();
+                        if request.room != room {
+                            return not_found;
+                        }
+                        return Response::builder().body(DeleteRoomResponse {}.encode_to_vec());
+                    }
+
+                    if path.ends_with("ListParticipants") {
+                        let request = ListParticipantsRequest::decode(body).unwrap();
+                        if request.room != room {
+                            return not_found;
+                        }
+                        let response = ListParticipantsResponse {
+                            participants: vec![customer()],
+                        };
+                        return Response::builder().body(response.encode_to_vec());
+                    }
+
+                    let (request_room, identity) = if path.ends_with("MutePublishedTrack") {
+                        let request = MuteRoomTrackRequest::decode(body.clone()).unwrap();
+                        (request.room, request.identity)
+                    } else if path.ends_with("UpdateParticipant") {
+                        let request = UpdateParticipantRequest::decode(body.clone()).unwrap();
+                        (request.room, request.identity)
+                    } else {
+                        let request = RoomParticipantIdentity::decode(body.clone()).unwrap();
+                        (request.room, request.identity)
+                    };
+                    if request_room != room || identity != "customer-42" {
+                        return not_found;
+                    }
+
+                    if path.ends_with("GetParticipant") {
+                        Response::builder().body(customer().encode_to_vec())
+                    } else if path.ends_with("RemoveParticipant") {
+                        Response::builder().body(RemoveParticipantResponse {}.encode_to_vec())
+                    } else if path.ends_with("MutePublishedTrack") {
+                        let request = MuteRoomTrackRequest::decode(body).unwrap();
+                        assert!(request.muted);
+                        muted_tracks.lock().unwrap().push(request.track_sid.clone());
+                        let response = MuteRoomTrackResponse {
+                            track: Some(TrackInfo {
+                                sid: request.track_sid,
+                                muted: true,
+                                ..Default::default()
+                            }),
+                        };
+                        Response::builder().body(response.encode_to_vec())
+                    } else if path.ends_with("UpdateParticipant") {
+                        let request = UpdateParticipantRequest::decode(body).unwrap();
+                        *permission.lock().unwrap() = request.permission;
+                        Response::builder().body(customer().encode_to_vec())
+                    } else {
+                        not_found
+                    }
+                }
+            });
+            tokio::spawn(Server::new_with_acceptor(acceptor).run(endpoint));
+
+            stub
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_participants() {
+        let meeting_id = MeetingId::new();
+        let livekit = StubServer::start(&meeting_id).await.livekit;
+
+        let participants = livekit.list_participants(&meeting_id).await.unwrap();
+        assert_eq!(participants.len(), 1);
+        assert_eq!(participants[0].identity, "customer-42");
+        assert_eq!(participants[0].name, "Jane Doe");
+        assert_eq!(participants[0].joined_at.timestamp(), 1_740_000_000);
+        assert!(participants[0].is_publishing);
+
+        // Nobody is in a room that is not open
+        let participants = livekit.list_participants(&MeetingId::new()).await.unwrap();
+        assert!(participants.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remove_participant() {
+        let meeting_id = MeetingId::new();
+        let livekit = StubServer::start(&meeting_id).await.livekit;
+
+        livekit
+            .remove_participant(&meeting_id, "customer-42")
+            .await
+            .unwrap();
+
+        let Err(RoomManagerError::ParticipantNotFound) =
+            livekit.remove_participant(&meeting_id, "customer-43").await
+        else {
+            panic!("Removing someone who is not in the room should be reported");
+        };
+    }
+
+    #[test]
+    fn test_role_grants() {
+        let meeting_id = MeetingId::new();
+
+        let host = grants(&meeting_id, ParticipantRole::Host);
+        assert!(host.room_join && host.room_admin && host.can_publish);
+        assert_eq!(host.room, meeting_id.as_ref().to_string());
+
+        let speaker = grants(&meeting_id, ParticipantRole::Speaker);
+        assert!(speaker.can_publish && speaker.can_subscribe && !speaker.room_admin);
+
+        let viewer = grants(&meeting_id, ParticipantRole::Viewer);
+        assert!(!viewer.can_publish && viewer.can_subscribe && viewer.can_publish_data);
+
+        let recorder = grants(&meeting_id, ParticipantRole::RecorderBot);
+        assert!(recorder.hidden && recorder.recorder && recorder.can_subscribe);
+        assert!(!recorder.can_publish && !recorder.can_publish_data);
+    }
+
+    #[tokio::test]
+    async fn test_mute_track() {
+        let meeting_id = MeetingId::new();
+        let stub = StubServer::start(&meeting_id).await;
+
+        stub.livekit
+            .mute_track(&meeting_id, "customer-42", room::TrackSource::Microphone)
+            .await
+            .unwrap();
+        assert_eq!(*stub.muted_tracks.lock().unwrap(), vec!["TR_microphone"]);
+
+        let Err(RoomManagerError::TrackNotFound) = stub
+            .livekit
+            .mute_track(&meeting_id, "customer-42", room::TrackSource::Camera)
+            .await
+        else {
+            panic!("Muting a track which is not published should be reported");
+        };
+
+        let Err(RoomManagerError::ParticipantNotFound) = stub
+            .livekit
+            .mute_track(&meeting_id, "customer-43", room::TrackSource::Microphone)
+            .await
+        else {
+            panic!("Muting someone who is not in the room should be reported");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_update_permissions() {
+        let meeting_id = MeetingId::new();
+        let stub = StubServer::start(&meeting_id).await;
+
+        stub.livekit
+            .update_permissions(
+                &meeting_id,
+                "customer-42",
+                PermissionChanges {
+                    can_publish: Some(false),
+                    can_publish_data: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let permission = stub.permission.lock().unwrap().clone().unwrap();
+        assert!(!permission.can_publish);
+        // The permissions left out are kept
+        assert!(permission.can_publish_data && permission.can_subscribe);
+    }
+
+    #[tokio::test]
+    async fn test_create_room() {
+        let meeting_id = MeetingId::new();
+        let stub = StubServer::start(&meeting_id).await;
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        stub.livekit
+            .create_room(
+                &meeting_id,
+                RoomSettings {
+                    max_participants: Some(25),
+                    empty_timeout: TimeDelta::hours(2),
+                    meeting_name: "Morning yoga".to_string(),
+                    studio_id: studio_id.clone(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let request = stub.created_room.lock().unwrap().clone().unwrap();
+        assert_eq!(request.name, meeting_id.as_ref().to_string());
+        assert_eq!(request.max_participants, 25);
+        assert_eq!(request.empty_timeout, 7200);
+        let metadata: serde_json::Value = serde_json::from_str(&request.metadata).unwrap();
+        assert_eq!(metadata["meeting_name"], "Morning yoga");
+        assert_eq!(metadata["studio_id"], studio_id.as_ref().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_delete_room() {
+        let meeting_id = MeetingId::new();
+        let livekit = StubServer::start(&meeting_id).await.livekit;
+
+        livekit.delete_room(&meeting_id).await.unwrap();
+
+        // A room which is not open is as good as deleted
+        livekit.delete_room(&MeetingId::new()).await.unwrap();
+    }
 }
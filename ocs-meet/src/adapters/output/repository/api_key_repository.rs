@@ -0,0 +1,182 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        api_key::{ApiKey, ApiKeyId, ApiKeyScope, ApiKeyTokenHash},
+        studio::StudioId,
+    },
+    ports::output::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as};
+use std::str::FromStr;
+
+struct ApiKeyRecord {
+    id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    name: String,
+    token_hash: Vec<u8>,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ApiKeyRecord> for ApiKey {
+    type Error = ApiKeyRepositoryError;
+
+    fn try_from(record: ApiKeyRecord) -> Result<Self, Self::Error> {
+        Ok(ApiKey {
+            id: ApiKeyId::from(record.id),
+            studio_id: StudioId::from(record.studio_id),
+            name: record.name,
+            token_hash: ApiKeyTokenHash::from(record.token_hash),
+            scopes: record
+                .scopes
+                .iter()
+                .map(|scope| ApiKeyScope::from_str(scope))
+                .collect::<Result<_, _>>()?,
+            expires_at: record.expires_at,
+            created_at: record.created_at,
+        })
+    }
+}
+
+impl ApiKeyRepository for Repository {
+    async fn create_api_key(&self, api_key: &ApiKey) -> Result<(), ApiKeyRepositoryError> {
+        let id = api_key.id.as_ref();
+        let studio_id = api_key.studio_id.as_ref();
+        let token_hash: &Vec<u8> = api_key.token_hash.as_ref();
+        let scopes = api_key
+            .scopes
+            .iter()
+            .map(ApiKeyScope::to_string)
+            .collect::<Vec<_>>();
+
+        query!(
+            "INSERT INTO api_keys (id, studio_id, name, token_hash, scopes, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            id,
+            studio_id,
+            api_key.name,
+            token_hash,
+            &scopes,
+            api_key.expires_at,
+            api_key.created_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_api_keys(
+        &self,
+        studio_id: &StudioId,
+    ) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let records = query_as!(
+            ApiKeyRecord,
+            "SELECT id, studio_id, name, token_hash, scopes, expires_at, created_at FROM api_keys
+            WHERE studio_id = $1 ORDER BY created_at DESC, id",
+            studio_id
+        )
+        .fetch_all(&self.pg_pool)
+        .await?;
+
+        records.into_iter().map(ApiKey::try_from).collect()
+    }
+
+    async fn find_api_key_by_hash(
+        &self,
+        token_hash: &ApiKeyTokenHash,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let token_hash: &Vec<u8> = token_hash.as_ref();
+        let record = query_as!(
+            ApiKeyRecord,
+            "SELECT id, studio_id, name, token_hash, scopes, expires_at, created_at FROM api_keys
+            WHERE token_hash = $1",
+            token_hash
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        record.map(ApiKey::try_from).transpose()
+    }
+
+    async fn delete_api_key(
+        &self,
+        studio_id: &StudioId,
+        api_key_id: &ApiKeyId,
+    ) -> Result<bool, ApiKeyRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let api_key_id = api_key_id.as_ref();
+        let result = query!(
+            "DELETE FROM api_keys WHERE id = $1 AND studio_id = $2",
+            api_key_id,
+            studio_id
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            api_key::{ApiKey, ApiKeyId, ApiKeyScope, ApiKeyToken},
+            studio::StudioId,
+        },
+        ports::output::api_key_repository::ApiKeyRepository,
+    };
+    use chrono::{TimeDelta, Utc};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_create_find_and_delete(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let token = ApiKeyToken::generate();
+        let api_key = ApiKey {
+            id: ApiKeyId::new(),
+            studio_id: studio_id.clone(),
+            name: "Booking backend".to_string(),
+            token_hash: token.hash(),
+            scopes: vec![ApiKeyScope::Read, ApiKeyScope::Write],
+            expires_at: Some(Utc::now() + TimeDelta::days(30)),
+            created_at: Utc::now(),
+        };
+
+        repository.create_api_key(&api_key).await.unwrap();
+
+        let found = repository
+            .find_api_key_by_hash(&token.hash())
+            .await
+            .unwrap()
+            .expect("The key should be found by its hash");
+        assert_eq!(found.id, api_key.id);
+        assert_eq!(found.scopes, api_key.scopes);
+
+        let listed = repository.list_api_keys(&studio_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+
+        // Another studio cannot revoke the key
+        let other_studio = StudioId::from(uuid::Uuid::new_v4());
+        assert!(!repository
+            .delete_api_key(&other_studio, &api_key.id)
+            .await
+            .unwrap());
+
+        assert!(repository
+            .delete_api_key(&studio_id, &api_key.id)
+            .await
+            .unwrap());
+        assert!(repository
+            .find_api_key_by_hash(&token.hash())
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
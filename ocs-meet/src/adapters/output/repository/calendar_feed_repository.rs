@@ -0,0 +1,129 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        calendar_feed::{CalendarFeed, CalendarFeedTokenHash},
+        studio::StudioId,
+    },
+    ports::output::calendar_feed_repository::{
+        CalendarFeedRepository, CalendarFeedRepositoryError,
+    },
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as};
+
+struct CalendarFeedRecord {
+    studio_id: uuid::Uuid,
+    token_hash: Vec<u8>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<CalendarFeedRecord> for CalendarFeed {
+    fn from(record: CalendarFeedRecord) -> Self {
+        CalendarFeed {
+            studio_id: StudioId::from(record.studio_id),
+            token_hash: CalendarFeedTokenHash::from(record.token_hash),
+            created_at: record.created_at,
+        }
+    }
+}
+
+impl CalendarFeedRepository for Repository {
+    async fn save_calendar_feed(
+        &self,
+        feed: &CalendarFeed,
+    ) -> Result<(), CalendarFeedRepositoryError> {
+        let studio_id = feed.studio_id.as_ref();
+        let token_hash: &Vec<u8> = feed.token_hash.as_ref();
+
+        query!(
+            "INSERT INTO calendar_feeds (studio_id, token_hash, created_at) VALUES ($1, $2, $3)
+            ON CONFLICT (studio_id) DO UPDATE SET token_hash = EXCLUDED.token_hash, created_at = EXCLUDED.created_at",
+            studio_id,
+            token_hash,
+            feed.created_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_calendar_feed(
+        &self,
+        studio_id: &StudioId,
+    ) -> Result<Option<CalendarFeed>, CalendarFeedRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let record = query_as!(
+            CalendarFeedRecord,
+            "SELECT studio_id, token_hash, created_at FROM calendar_feeds WHERE studio_id = $1",
+            studio_id
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        Ok(record.map(CalendarFeed::from))
+    }
+
+    async fn delete_calendar_feed(
+        &self,
+        studio_id: &StudioId,
+    ) -> Result<(), CalendarFeedRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        query!("DELETE FROM calendar_feeds WHERE studio_id = $1", studio_id)
+            .execute(&self.pg_pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            calendar_feed::{CalendarFeed, CalendarFeedToken},
+            studio::StudioId,
+        },
+        ports::output::calendar_feed_repository::CalendarFeedRepository,
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_rotate_and_revoke(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let feed = |token: &CalendarFeedToken| CalendarFeed {
+            studio_id: studio_id.clone(),
+            token_hash: token.hash(),
+            created_at: Utc::now(),
+        };
+
+        let first_token = CalendarFeedToken::generate();
+        repository
+            .save_calendar_feed(&feed(&first_token))
+            .await
+            .unwrap();
+        let second_token = CalendarFeedToken::generate();
+        repository
+            .save_calendar_feed(&feed(&second_token))
+            .await
+            .unwrap();
+
+        let saved = repository
+            .find_calendar_feed(&studio_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(saved.accepts(&second_token));
+        assert!(!saved.accepts(&first_token));
+
+        repository.delete_calendar_feed(&studio_id).await.unwrap();
+        assert!(repository
+            .find_calendar_feed(&studio_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,246 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        invitation::{Invitation, InvitationId},
+        meeting::MeetingId,
+        room::ParticipantRole,
+        studio::StudioId,
+    },
+    ports::output::invitation_repository::{InvitationRepository, InvitationRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as};
+use std::str::FromStr;
+
+struct InvitationRecord {
+    id: uuid::Uuid,
+    meeting_id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    display_name: String,
+    role: String,
+    max_uses: Option<i32>,
+    uses: i32,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<InvitationRecord> for Invitation {
+    type Error = InvitationRepositoryError;
+
+    fn try_from(record: InvitationRecord) -> Result<Self, Self::Error> {
+        Ok(Invitation {
+            id: InvitationId::from(record.id),
+            meeting_id: MeetingId::from(record.meeting_id),
+            studio_id: StudioId::from(record.studio_id),
+            display_name: record.display_name,
+            role: ParticipantRole::from_str(&record.role)?,
+            max_uses: record
+                .max_uses
+                .map(|max_uses| u32::try_from(max_uses).unwrap_or_default()),
+            uses: u32::try_from(record.uses).unwrap_or_default(),
+            expires_at: record.expires_at,
+            revoked_at: record.revoked_at,
+            created_at: record.created_at,
+        })
+    }
+}
+
+impl InvitationRepository for Repository {
+    async fn create_invitation(
+        &self,
+        invitation: &Invitation,
+    ) -> Result<(), InvitationRepositoryError> {
+        let id = invitation.id.as_ref();
+        let meeting_id = invitation.meeting_id.as_ref();
+        let studio_id = invitation.studio_id.as_ref();
+        let max_uses = invitation.max_uses.map(|max_uses| max_uses as i32);
+
+        query!(
+            "INSERT INTO meeting_invitations (id, meeting_id, studio_id, display_name, role, max_uses, uses, expires_at, revoked_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            id,
+            meeting_id,
+            studio_id,
+            invitation.display_name,
+            invitation.role.to_string(),
+            max_uses,
+            invitation.uses as i32,
+            invitation.expires_at,
+            invitation.revoked_at,
+            invitation.created_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_invitation(
+        &self,
+        invitation_id: &InvitationId,
+    ) -> Result<Option<Invitation>, InvitationRepositoryError> {
+        let invitation_id = invitation_id.as_ref();
+        let record = query_as!(
+            InvitationRecord,
+            "SELECT id, meeting_id, studio_id, display_name, role, max_uses, uses, expires_at, revoked_at, created_at
+            FROM meeting_invitations WHERE id = $1",
+            invitation_id
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        record.map(Invitation::try_from).transpose()
+    }
+
+    async fn revoke_invitation(
+        &self,
+        studio_id: &StudioId,
+        invitation_id: &InvitationId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<bool, InvitationRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let invitation_id = invitation_id.as_ref();
+        let result = query!(
+            "UPDATE meeting_invitations SET revoked_at = COALESCE(revoked_at, $3)
+            WHERE id = $1 AND studio_id = $2",
+            invitation_id,
+            studio_id,
+            revoked_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn use_invitation(
+        &self,
+        invitation_id: &InvitationId,
+        used_at: DateTime<Utc>,
+    ) -> Result<Option<u32>, InvitationRepositoryError> {
+        let invitation_id = invitation_id.as_ref();
+        // Checked within the update so two guests cannot take the last use together
+        let record = query!(
+            "UPDATE meeting_invitations SET uses = uses + 1
+            WHERE id = $1 AND revoked_at IS NULL AND expires_at > $2
+            AND (max_uses IS NULL OR uses < max_uses)
+            RETURNING uses",
+            invitation_id,
+            used_at
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        Ok(record.map(|record| u32::try_from(record.uses).unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            invitation::{Invitation, InvitationId},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::ParticipantRole,
+            studio::StudioId,
+        },
+        ports::output::{
+            invitation_repository::InvitationRepository, meeting_repository::MeetingRepository,
+        },
+    };
+    use chrono::{TimeDelta, Utc};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_uses_and_revocation(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: studio_id.clone(),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        repository.create_meeting(&meeting).await.unwrap();
+
+        let now = Utc::now();
+        let invitation = Invitation {
+            id: InvitationId::new(),
+            meeting_id: meeting.id.clone(),
+            studio_id: studio_id.clone(),
+            display_name: "Jane Doe".to_string(),
+            role: ParticipantRole::Viewer,
+            max_uses: Some(2),
+            uses: 0,
+            expires_at: now + TimeDelta::days(1),
+            revoked_at: None,
+            created_at: now,
+        };
+        repository.create_invitation(&invitation).await.unwrap();
+
+        let found = repository
+            .find_invitation(&invitation.id)
+            .await
+            .unwrap()
+            .expect("The invitation should be found");
+        assert_eq!(found.role, ParticipantRole::Viewer);
+        assert_eq!(found.max_uses, Some(2));
+
+        for uses in [Some(1), Some(2), None] {
+            assert_eq!(
+                repository
+                    .use_invitation(&invitation.id, now)
+                    .await
+                    .unwrap(),
+                uses
+            );
+        }
+        let found = repository.find_invitation(&invitation.id).await.unwrap();
+        assert_eq!(found.map(|invitation| invitation.uses), Some(2));
+
+        let unlimited = Invitation {
+            id: InvitationId::new(),
+            max_uses: None,
+            ..invitation
+        };
+        repository.create_invitation(&unlimited).await.unwrap();
+        assert_eq!(
+            repository
+                .use_invitation(&unlimited.id, now + TimeDelta::days(2))
+                .await
+                .unwrap(),
+            None
+        );
+
+        // Another studio cannot revoke the invitation
+        let other_studio = StudioId::from(uuid::Uuid::new_v4());
+        assert!(!repository
+            .revoke_invitation(&other_studio, &unlimited.id, now)
+            .await
+            .unwrap());
+
+        assert!(repository
+            .revoke_invitation(&studio_id, &unlimited.id, now)
+            .await
+            .unwrap());
+        assert_eq!(
+            repository.use_invitation(&unlimited.id, now).await.unwrap(),
+            None
+        );
+        let found = repository.find_invitation(&unlimited.id).await.unwrap();
+        assert!(found.is_some_and(|invitation| invitation.revoked_at.is_some()));
+    }
+}
@@ -1,60 +1,257 @@
 use super::db::Repository;
 use crate::{
     domain::{
-        meeting::{Meeting, MeetingId, MeetingName},
+        meeting::{
+            JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingSeries,
+            MeetingSeriesId, MeetingStatus,
+        },
+        passcode::PasscodeHash,
+        recurrence::RecurrenceRule,
         studio::StudioId,
     },
-    ports::output::meeting_repository::{MeetingRepository, MeetingRepositoryError},
+    ports::output::meeting_repository::{
+        MeetingOrder, MeetingQuery, MeetingRepository, MeetingRepositoryError,
+    },
 };
-use sqlx::query;
-use validator::ValidationErrors;
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, PgExecutor};
+use std::str::FromStr;
+
+struct MeetingRecord {
+    id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    name: String,
+    date: DateTime<Utc>,
+    duration_minutes: i32,
+    early_entry_minutes: i32,
+    late_join_minutes: i32,
+    status: String,
+    series_id: Option<uuid::Uuid>,
+    passcode_hash: Option<String>,
+    waiting_room: bool,
+    max_participants: Option<i32>,
+    started_at: Option<DateTime<Utc>>,
+    ended_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<MeetingRecord> for Meeting {
+    type Error = MeetingRepositoryError;
+
+    fn try_from(record: MeetingRecord) -> Result<Self, Self::Error> {
+        Ok(Meeting {
+            id: MeetingId::from(record.id),
+            studio_id: StudioId::from(record.studio_id),
+            name: MeetingName::try_from(record.name)?,
+            date: record.date,
+            // Out of range values are turned into values the validation rejects
+            duration: MeetingDuration::try_from(
+                u32::try_from(record.duration_minutes).unwrap_or_default(),
+            )?,
+            join_window: JoinWindow::new(
+                u32::try_from(record.early_entry_minutes).unwrap_or(u32::MAX),
+                u32::try_from(record.late_join_minutes).unwrap_or(u32::MAX),
+            )?,
+            status: MeetingStatus::from_str(&record.status)?,
+            series_id: record.series_id.map(MeetingSeriesId::from),
+            passcode: record.passcode_hash.map(PasscodeHash::from),
+            waiting_room: record.waiting_room,
+            max_participants: record
+                .max_participants
+                .and_then(|max_participants| u32::try_from(max_participants).ok()),
+            started_at: record.started_at,
+            ended_at: record.ended_at,
+        })
+    }
+}
+
+struct MeetingSeriesRecord {
+    id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    rrule: String,
+    starts_at: DateTime<Utc>,
+}
+
+impl TryFrom<MeetingSeriesRecord> for MeetingSeries {
+    type Error = MeetingRepositoryError;
+
+    fn try_from(record: MeetingSeriesRecord) -> Result<Self, Self::Error> {
+        Ok(MeetingSeries {
+            id: MeetingSeriesId::from(record.id),
+            studio_id: StudioId::from(record.studio_id),
+            rule: RecurrenceRule::from_str(&record.rrule)?,
+            starts_at: record.starts_at,
+        })
+    }
+}
+
+async fn insert_meeting(
+    executor: impl PgExecutor<'_>,
+    meeting: &Meeting,
+) -> Result<(), MeetingRepositoryError> {
+    let meeting_id = meeting.id.as_ref();
+    let studio_id = meeting.studio_id.as_ref();
+    let meeting_name = meeting.name.as_ref();
+    let meeting_date = meeting.date;
+    let meeting_duration = meeting.duration.minutes() as i32;
+    let early_entry = meeting.join_window.early_entry_minutes() as i32;
+    let late_join = meeting.join_window.late_join_minutes() as i32;
+    let meeting_status = meeting.status.as_str();
+    let series_id = meeting
+        .series_id
+        .as_ref()
+        .map(|series_id| *series_id.as_ref());
+    let passcode_hash = meeting.passcode.as_ref().map(AsRef::<String>::as_ref);
+    let max_participants = meeting
+        .max_participants
+        .map(|max_participants| i32::try_from(max_participants).unwrap_or(i32::MAX));
+
+    query!(
+        "INSERT INTO meetings (id, studio_id, name, date, duration_minutes, early_entry_minutes, late_join_minutes, status, series_id, passcode_hash, waiting_room, max_participants, started_at, ended_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+        meeting_id,
+        studio_id,
+        meeting_name,
+        meeting_date,
+        meeting_duration,
+        early_entry,
+        late_join,
+        meeting_status,
+        series_id,
+        passcode_hash,
+        meeting.waiting_room,
+        max_participants,
+        meeting.started_at,
+        meeting.ended_at
+    )
+    .execute(executor)
+    .await
+    .map_err(MeetingRepositoryError::from)?;
+
+    Ok(())
+}
+
+async fn save_meeting(
+    executor: impl PgExecutor<'_>,
+    meeting: &Meeting,
+) -> Result<(), MeetingRepositoryError> {
+    let meeting_id = meeting.id.as_ref();
+    let meeting_name = meeting.name.as_ref();
+    let meeting_date = meeting.date;
+    let meeting_duration = meeting.duration.minutes() as i32;
+    let early_entry = meeting.join_window.early_entry_minutes() as i32;
+    let late_join = meeting.join_window.late_join_minutes() as i32;
+    let meeting_status = meeting.status.as_str();
+    let passcode_hash = meeting.passcode.as_ref().map(AsRef::<String>::as_ref);
+    let max_participants = meeting
+        .max_participants
+        .map(|max_participants| i32::try_from(max_participants).unwrap_or(i32::MAX));
+
+    query!(
+        "UPDATE meetings SET name = $2, date = $3, duration_minutes = $4, early_entry_minutes = $5, late_join_minutes = $6, status = $7, passcode_hash = $8, waiting_room = $9, max_participants = $10, started_at = $11, ended_at = $12 WHERE id = $1",
+        meeting_id,
+        meeting_name,
+        meeting_date,
+        meeting_duration,
+        early_entry,
+        late_join,
+        meeting_status,
+        passcode_hash,
+        meeting.waiting_room,
+        max_participants,
+        meeting.started_at,
+        meeting.ended_at
+    )
+    .execute(executor)
+    .await
+    .map_err(MeetingRepositoryError::from)?;
+
+    Ok(())
+}
 
 impl MeetingRepository for Repository {
     async fn create_meeting(&self, meeting: &Meeting) -> Result<(), MeetingRepositoryError> {
-        let meeting_id = meeting.id.as_ref();
-        let studio_id = meeting.studio_id.as_ref();
-        let meeting_name = meeting.name.as_ref();
-        let meeting_date = meeting.date;
+        insert_meeting(&self.pg_pool, meeting).await
+    }
 
-        query!(
-            "INSERT INTO meetings (id, studio_id, name, date) VALUES ($1, $2, $3, $4)",
-            meeting_id,
-            studio_id,
-            meeting_name,
-            meeting_date
-        )
-        .execute(&self.pg_pool)
-        .await
-        .map_err(MeetingRepositoryError::from)?;
+    async fn update_meeting(&self, meeting: &Meeting) -> Result<(), MeetingRepositoryError> {
+        save_meeting(&self.pg_pool, meeting).await
+    }
 
+    async fn update_meetings(&self, meetings: &[Meeting]) -> Result<(), MeetingRepositoryError> {
+        let mut transaction = self.pg_pool.begin().await?;
+        for meeting in meetings {
+            save_meeting(&mut *transaction, meeting).await?;
+        }
+        transaction.commit().await?;
         Ok(())
     }
 
     async fn list_meetings(
         &self,
-        studio_id: &StudioId,
+        query: &MeetingQuery,
     ) -> Result<Vec<Meeting>, MeetingRepositoryError> {
-        let studio_id = studio_id.as_ref();
-        let rows = query!(
-            "SELECT id, studio_id, name, date FROM meetings where studio_id = $1",
-            studio_id
-        )
-        .fetch_all(&self.pg_pool)
-        .await
+        let studio_id = query.studio_id.as_ref();
+        let limit = query.limit.map(i64::from);
+        let after_date = query.after.as_ref().map(|cursor| cursor.date);
+        let after_id = query.after.as_ref().map(|cursor| *cursor.id.as_ref());
+        let series_id = query
+            .series_id
+            .as_ref()
+            .map(|series_id| *series_id.as_ref());
+
+        // The bounds are coalesced rather than tested against NULL so the (studio_id, date) index can be used
+        let records = match query.order {
+            MeetingOrder::Ascending => {
+                query_as!(
+                    MeetingRecord,
+                    r#"SELECT id, studio_id, name, date, duration_minutes, early_entry_minutes, late_join_minutes, status, series_id, passcode_hash, waiting_room, max_participants, started_at, ended_at FROM meetings
+                    WHERE studio_id = $1
+                    AND date >= COALESCE($2, '-infinity'::timestamptz)
+                    AND date < COALESCE($3, 'infinity'::timestamptz)
+                    AND ($4 OR status <> 'cancelled')
+                    AND ($6::timestamptz IS NULL OR (date, id) > ($6, $7::uuid))
+                    AND ($8::uuid IS NULL OR series_id = $8)
+                    ORDER BY date ASC, id ASC
+                    LIMIT $5"#,
+                    studio_id,
+                    query.from,
+                    query.to,
+                    query.include_cancelled,
+                    limit,
+                    after_date,
+                    after_id,
+                    series_id
+                )
+                .fetch_all(&self.pg_pool)
+                .await
+            }
+            MeetingOrder::Descending => {
+                query_as!(
+                    MeetingRecord,
+                    r#"SELECT id, studio_id, name, date, duration_minutes, early_entry_minutes, late_join_minutes, status, series_id, passcode_hash, waiting_room, max_participants, started_at, ended_at FROM meetings
+                    WHERE studio_id = $1
+                    AND date >= COALESCE($2, '-infinity'::timestamptz)
+                    AND date < COALESCE($3, 'infinity'::timestamptz)
+                    AND ($4 OR status <> 'cancelled')
+                    AND ($6::timestamptz IS NULL OR (date, id) < ($6, $7::uuid))
+                    AND ($8::uuid IS NULL OR series_id = $8)
+                    ORDER BY date DESC, id DESC
+                    LIMIT $5"#,
+                    studio_id,
+                    query.from,
+                    query.to,
+                    query.include_cancelled,
+                    limit,
+                    after_date,
+                    after_id,
+                    series_id
+                )
+                .fetch_all(&self.pg_pool)
+                .await
+            }
+        }
         .map_err(MeetingRepositoryError::from)?;
 
-        rows.into_iter()
-            .map(|record| {
-                let name = MeetingName::try_from(record.name)?;
-                Ok(Meeting {
-                    id: MeetingId::from(record.id),
-                    studio_id: StudioId::from(record.id),
-                    name,
-                    date: record.date,
-                })
-            })
-            .collect::<Result<Vec<Meeting>, ValidationErrors>>()
-            .map_err(MeetingRepositoryError::from)
+        records.into_iter().map(Meeting::try_from).collect()
     }
 
     async fn find_meeting(
@@ -62,30 +259,100 @@ impl MeetingRepository for Repository {
         meeting_id: &MeetingId,
     ) -> Result<Option<Meeting>, MeetingRepositoryError> {
         let meeting_id = meeting_id.as_ref();
-        let rows = query!(
-            "SELECT id, studio_id, name, date FROM meetings where id = $1",
+        let record = query_as!(
+            MeetingRecord,
+            "SELECT id, studio_id, name, date, duration_minutes, early_entry_minutes, late_join_minutes, status, series_id, passcode_hash, waiting_room, max_participants, started_at, ended_at FROM meetings where id = $1",
             meeting_id
         )
         .fetch_optional(&self.pg_pool)
         .await
         .map_err(MeetingRepositoryError::from)?;
 
-        let meeting = rows.map(|record| {
-            let name = MeetingName::try_from(record.name)?;
-            Ok(Meeting {
-                id: MeetingId::from(record.id),
-                studio_id: StudioId::from(record.id),
-                name,
-                date: record.date,
-            })
-        });
-        match meeting {
-            Some(res) => match res {
-                Ok(meeting) => Ok(Some(meeting)),
-                Err(err) => Err(err),
-            },
-            None => Ok(None),
+        record.map(Meeting::try_from).transpose()
+    }
+
+    async fn create_series(
+        &self,
+        series: &MeetingSeries,
+        meetings: &[Meeting],
+    ) -> Result<(), MeetingRepositoryError> {
+        let series_id = series.id.as_ref();
+        let studio_id = series.studio_id.as_ref();
+        let rrule = series.rule.to_string();
+
+        let mut transaction = self.pg_pool.begin().await?;
+        query!(
+            "INSERT INTO meeting_series (id, studio_id, rrule, starts_at) VALUES ($1, $2, $3, $4)",
+            series_id,
+            studio_id,
+            rrule,
+            series.starts_at
+        )
+        .execute(&mut *transaction)
+        .await?;
+        for meeting in meetings {
+            insert_meeting(&mut *transaction, meeting).await?;
         }
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_series(
+        &self,
+        series_id: &MeetingSeriesId,
+    ) -> Result<Option<MeetingSeries>, MeetingRepositoryError> {
+        let series_id = series_id.as_ref();
+        let record = query_as!(
+            MeetingSeriesRecord,
+            "SELECT id, studio_id, rrule, starts_at FROM meeting_series WHERE id = $1",
+            series_id
+        )
+        .fetch_optional(&self.pg_pool)
+        .await
+        .map_err(MeetingRepositoryError::from)?;
+
+        record.map(MeetingSeries::try_from).transpose()
+    }
+
+    async fn record_passcode_failure(
+        &self,
+        meeting_id: &MeetingId,
+        caller: &str,
+        failed_at: DateTime<Utc>,
+    ) -> Result<(), MeetingRepositoryError> {
+        let meeting_id = meeting_id.as_ref();
+        query!(
+            "INSERT INTO meeting_passcode_failures (meeting_id, caller, failed_at) VALUES ($1, $2, $3)",
+            meeting_id,
+            caller,
+            failed_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn count_passcode_failures(
+        &self,
+        meeting_id: &MeetingId,
+        caller: &str,
+        since: DateTime<Utc>,
+    ) -> Result<u32, MeetingRepositoryError> {
+        let meeting_id = meeting_id.as_ref();
+        let count = query!(
+            r#"SELECT COUNT(*) AS "count!" FROM meeting_passcode_failures
+            WHERE meeting_id = $1 AND caller = $2 AND failed_at >= $3"#,
+            meeting_id,
+            caller,
+            since
+        )
+        .fetch_one(&self.pg_pool)
+        .await?
+        .count;
+
+        Ok(u32::try_from(count).unwrap_or(u32::MAX))
     }
 }
 
@@ -94,13 +361,21 @@ mod tests {
     use crate::{
         adapters::output::repository::db::Repository,
         domain::{
-            meeting::{Meeting, MeetingId, MeetingName},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingSeries,
+                MeetingSeriesId, MeetingStatus,
+            },
+            passcode::MeetingPasscode,
+            recurrence::RecurrenceRule,
             studio::StudioId,
         },
-        ports::output::meeting_repository::MeetingRepository,
+        ports::output::meeting_repository::{
+            MeetingCursor, MeetingOrder, MeetingQuery, MeetingRepository,
+        },
     };
-    use chrono::Utc;
+    use chrono::{Days, TimeDelta, Utc};
     use sqlx::PgPool;
+    use std::str::FromStr;
 
     #[sqlx::test]
     async fn test_create_meeting(pg_pool: PgPool) {
@@ -110,6 +385,15 @@ mod tests {
             studio_id: StudioId::from(uuid::Uuid::new_v4()),
             name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
             date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
         };
         repository.create_meeting(&meeting).await.unwrap();
         let Some(retrieved) = repository.find_meeting(&meeting.id).await.unwrap() else {
@@ -118,6 +402,111 @@ mod tests {
         assert_eq!(retrieved.id, meeting.id);
     }
 
+    #[sqlx::test]
+    async fn test_update_meeting(pg_pool: PgPool) {
+        let repository = Repository::new(pg_pool).await.unwrap();
+        let mut meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        repository.create_meeting(&meeting).await.unwrap();
+
+        meeting.name = MeetingName::try_from("Renamed meeting".to_string()).unwrap();
+        meeting.date = Utc::now().checked_add_days(Days::new(1)).unwrap();
+        meeting.duration = MeetingDuration::try_from(90).unwrap();
+        meeting.join_window = JoinWindow::new(5, 10).unwrap();
+        meeting.status = MeetingStatus::Cancelled;
+        meeting.passcode = Some(
+            MeetingPasscode::try_from("sunrise-42".to_string())
+                .unwrap()
+                .hash(),
+        );
+        meeting.waiting_room = true;
+        meeting.max_participants = Some(25);
+        meeting.started_at = Some(meeting.date);
+        meeting.ended_at = Some(meeting.end_date());
+        repository.update_meeting(&meeting).await.unwrap();
+
+        let Some(retrieved) = repository.find_meeting(&meeting.id).await.unwrap() else {
+            panic!("The meeting should still exist")
+        };
+        assert_eq!(retrieved.name.as_ref(), "Renamed meeting");
+        assert_eq!(retrieved.studio_id, meeting.studio_id);
+        assert_eq!(retrieved.duration.minutes(), 90);
+        assert_eq!(retrieved.join_window, meeting.join_window);
+        assert_eq!(retrieved.status, MeetingStatus::Cancelled);
+        assert!(retrieved
+            .passcode
+            .is_some_and(|hash| hash.verify("sunrise-42")));
+        assert!(retrieved.waiting_room);
+        assert_eq!(retrieved.max_participants, Some(25));
+        assert_eq!(
+            retrieved.started_at.map(|date| date.timestamp_micros()),
+            meeting.started_at.map(|date| date.timestamp_micros())
+        );
+        assert_eq!(
+            retrieved.ended_at.map(|date| date.timestamp_micros()),
+            meeting.ended_at.map(|date| date.timestamp_micros())
+        );
+        assert_eq!(
+            retrieved.date.timestamp_micros(),
+            meeting.date.timestamp_micros()
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_passcode_failures(pg_pool: PgPool) {
+        let repository = Repository::new(pg_pool).await.unwrap();
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        repository.create_meeting(&meeting).await.unwrap();
+
+        let now = Utc::now();
+        let api_key = uuid::Uuid::new_v4().to_string();
+        for (caller, failed_at) in [
+            ("backend", now - TimeDelta::hours(1)),
+            ("backend", now),
+            ("backend", now),
+            (api_key.as_str(), now),
+        ] {
+            repository
+                .record_passcode_failure(&meeting.id, caller, failed_at)
+                .await
+                .unwrap();
+        }
+
+        let since = now - TimeDelta::minutes(15);
+        let failures = repository
+            .count_passcode_failures(&meeting.id, "backend", since)
+            .await
+            .unwrap();
+        assert_eq!(failures, 2);
+    }
+
     #[sqlx::test]
     async fn test_list_meetings(pg_pool: PgPool) {
         let repository = Repository::new(pg_pool).await.unwrap();
@@ -129,20 +518,231 @@ mod tests {
             studio_id: studio_id.clone(),
             name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
             date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
         };
         let meeting_two = Meeting {
             id: MeetingId::new(),
             studio_id: studio_id.clone(),
             name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
             date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
         };
         repository.create_meeting(&meeting_one).await.unwrap();
         repository.create_meeting(&meeting_two).await.unwrap();
 
-        let list = repository.list_meetings(&studio_id).await.unwrap();
-        let list_2 = repository.list_meetings(&other_studio).await.unwrap();
+        let list = repository
+            .list_meetings(&MeetingQuery::new(studio_id))
+            .await
+            .unwrap();
+        let list_2 = repository
+            .list_meetings(&MeetingQuery::new(other_studio))
+            .await
+            .unwrap();
 
         assert!(!list.is_empty());
         assert!(list_2.is_empty());
     }
+
+    #[sqlx::test]
+    async fn test_list_meetings_query(pg_pool: PgPool) {
+        let repository = Repository::new(pg_pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let now = Utc::now();
+
+        let meeting = |name: &str, days: i64, status: MeetingStatus| Meeting {
+            id: MeetingId::new(),
+            studio_id: studio_id.clone(),
+            name: MeetingName::try_from(name.to_string()).unwrap(),
+            date: now + TimeDelta::days(days),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        let meetings = [
+            meeting("Last week", -7, MeetingStatus::Ended),
+            meeting("Tomorrow", 1, MeetingStatus::Scheduled),
+            meeting("Called off", 2, MeetingStatus::Cancelled),
+            meeting("Next week", 7, MeetingStatus::Scheduled),
+        ];
+        for meeting in &meetings {
+            repository.create_meeting(meeting).await.unwrap();
+        }
+
+        let names = |meetings: Vec<Meeting>| {
+            meetings
+                .iter()
+                .map(|meeting| meeting.name.as_ref().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let upcoming = repository
+            .list_meetings(&MeetingQuery {
+                from: Some(now),
+                include_cancelled: false,
+                ..MeetingQuery::new(studio_id.clone())
+            })
+            .await
+            .unwrap();
+        assert_eq!(names(upcoming), ["Tomorrow", "Next week"]);
+
+        let this_week = repository
+            .list_meetings(&MeetingQuery {
+                from: Some(now),
+                to: Some(now + TimeDelta::days(7)),
+                ..MeetingQuery::new(studio_id.clone())
+            })
+            .await
+            .unwrap();
+        assert_eq!(names(this_week), ["Tomorrow", "Called off"]);
+
+        let latest = repository
+            .list_meetings(&MeetingQuery {
+                order: MeetingOrder::Descending,
+                limit: Some(2),
+                ..MeetingQuery::new(studio_id.clone())
+            })
+            .await
+            .unwrap();
+        assert_eq!(names(latest), ["Next week", "Called off"]);
+    }
+
+    #[sqlx::test]
+    async fn test_list_meetings_after_cursor(pg_pool: PgPool) {
+        let repository = Repository::new(pg_pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now();
+
+        // Same date on purpose, the id has to break the tie
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let meeting = Meeting {
+                id: MeetingId::new(),
+                studio_id: studio_id.clone(),
+                name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                date,
+                duration: MeetingDuration::default(),
+                join_window: JoinWindow::default(),
+                status: MeetingStatus::Scheduled,
+                series_id: None,
+                passcode: None,
+                waiting_room: false,
+                max_participants: None,
+                started_at: None,
+                ended_at: None,
+            };
+            repository.create_meeting(&meeting).await.unwrap();
+            ids.push(meeting.id);
+        }
+        ids.sort_by_key(|id| *id.as_ref());
+
+        let first_page = repository
+            .list_meetings(&MeetingQuery {
+                limit: Some(2),
+                ..MeetingQuery::new(studio_id.clone())
+            })
+            .await
+            .unwrap();
+        let cursor = MeetingCursor::from(first_page.last().unwrap());
+        let second_page = repository
+            .list_meetings(&MeetingQuery {
+                after: Some(cursor.clone()),
+                ..MeetingQuery::new(studio_id.clone())
+            })
+            .await
+            .unwrap();
+        let backwards = repository
+            .list_meetings(&MeetingQuery {
+                after: Some(cursor),
+                order: MeetingOrder::Descending,
+                ..MeetingQuery::new(studio_id.clone())
+            })
+            .await
+            .unwrap();
+
+        let page_ids = |meetings: &[Meeting]| {
+            meetings
+                .iter()
+                .map(|meeting| meeting.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(page_ids(&first_page), ids[..2]);
+        assert_eq!(page_ids(&second_page), ids[2..]);
+        assert_eq!(page_ids(&backwards), ids[..1]);
+    }
+
+    #[sqlx::test]
+    async fn test_create_series(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let series = MeetingSeries {
+            id: MeetingSeriesId::new(),
+            studio_id: studio_id.clone(),
+            rule: RecurrenceRule::from_str("FREQ=WEEKLY;BYDAY=MO,TH;COUNT=3").unwrap(),
+            starts_at: Utc::now(),
+        };
+        let mut meetings: Vec<Meeting> = series
+            .rule
+            .occurrences(series.starts_at, chrono_tz::UTC)
+            .unwrap()
+            .into_iter()
+            .map(|date| Meeting {
+                id: MeetingId::new(),
+                studio_id: studio_id.clone(),
+                name: MeetingName::try_from("Stand-up".to_string()).unwrap(),
+                date,
+                duration: MeetingDuration::default(),
+                join_window: JoinWindow::default(),
+                status: MeetingStatus::Scheduled,
+                series_id: Some(series.id.clone()),
+                passcode: None,
+                waiting_room: false,
+                max_participants: None,
+                started_at: None,
+                ended_at: None,
+            })
+            .collect();
+        repository.create_series(&series, &meetings).await.unwrap();
+
+        let found = repository.find_series(&series.id).await.unwrap().unwrap();
+        assert_eq!(found.rule, series.rule);
+
+        for meeting in &mut meetings {
+            meeting.status = MeetingStatus::Cancelled;
+        }
+        repository.update_meetings(&meetings[1..]).await.unwrap();
+
+        let occurrences = repository
+            .list_meetings(&MeetingQuery {
+                include_cancelled: false,
+                series_id: Some(series.id.clone()),
+                ..MeetingQuery::new(studio_id)
+            })
+            .await
+            .unwrap();
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].id, meetings[0].id);
+        assert_eq!(occurrences[0].series_id, Some(series.id));
+    }
 }
@@ -0,0 +1,195 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        member::{Member, MemberRole},
+        studio::StudioId,
+        user::UserId,
+    },
+    ports::output::member_repository::{MemberRepository, MemberRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as};
+use std::str::FromStr;
+
+struct MemberRecord {
+    studio_id: uuid::Uuid,
+    user_id: String,
+    display_name: Option<String>,
+    role: String,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<MemberRecord> for Member {
+    type Error = MemberRepositoryError;
+
+    fn try_from(record: MemberRecord) -> Result<Self, Self::Error> {
+        Ok(Member {
+            studio_id: StudioId::from(record.studio_id),
+            user_id: UserId::from(record.user_id),
+            display_name: record.display_name,
+            role: MemberRole::from_str(&record.role)?,
+            created_at: record.created_at,
+        })
+    }
+}
+
+impl MemberRepository for Repository {
+    async fn add_member(&self, member: &Member) -> Result<(), MemberRepositoryError> {
+        let studio_id = member.studio_id.as_ref();
+        let user_id: &String = member.user_id.as_ref();
+
+        query!(
+            "INSERT INTO studio_members (studio_id, user_id, display_name, role, created_at)
+            VALUES ($1, $2, $3, $4, $5)",
+            studio_id,
+            user_id,
+            member.display_name,
+            member.role.to_string(),
+            member.created_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_member(
+        &self,
+        studio_id: &StudioId,
+        user_id: &UserId,
+    ) -> Result<Option<Member>, MemberRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let user_id: &String = user_id.as_ref();
+        let record = query_as!(
+            MemberRecord,
+            "SELECT studio_id, user_id, display_name, role, created_at FROM studio_members
+            WHERE studio_id = $1 AND user_id = $2",
+            studio_id,
+            user_id
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        record.map(Member::try_from).transpose()
+    }
+
+    async fn list_members(
+        &self,
+        studio_id: &StudioId,
+    ) -> Result<Vec<Member>, MemberRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let records = query_as!(
+            MemberRecord,
+            "SELECT studio_id, user_id, display_name, role, created_at FROM studio_members
+            WHERE studio_id = $1 ORDER BY created_at, user_id",
+            studio_id
+        )
+        .fetch_all(&self.pg_pool)
+        .await?;
+
+        records.into_iter().map(Member::try_from).collect()
+    }
+
+    async fn update_member(&self, member: &Member) -> Result<(), MemberRepositoryError> {
+        let studio_id = member.studio_id.as_ref();
+        let user_id: &String = member.user_id.as_ref();
+
+        query!(
+            "UPDATE studio_members SET display_name = $3, role = $4
+            WHERE studio_id = $1 AND user_id = $2",
+            studio_id,
+            user_id,
+            member.display_name,
+            member.role.to_string()
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_member(
+        &self,
+        studio_id: &StudioId,
+        user_id: &UserId,
+    ) -> Result<bool, MemberRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let user_id: &String = user_id.as_ref();
+        let result = query!(
+            "DELETE FROM studio_members WHERE studio_id = $1 AND user_id = $2",
+            studio_id,
+            user_id
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            meeting::MeetingDuration,
+            member::{Member, MemberRole},
+            studio::{Studio, StudioId, StudioName},
+            user::UserId,
+        },
+        ports::output::{member_repository::MemberRepository, studio_repository::StudioRepository},
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_member_lifecycle(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio = Studio {
+            id: StudioId::from(uuid::Uuid::new_v4()),
+            name: StudioName::try_from("Yoga studio".to_string()).unwrap(),
+            timezone: chrono_tz::UTC,
+            default_duration: MeetingDuration::default(),
+            created_at: Utc::now(),
+        };
+        repository.create_studio(&studio).await.unwrap();
+
+        let mut member = Member {
+            studio_id: studio.id.clone(),
+            user_id: UserId::from("alice".to_string()),
+            display_name: Some("Alice".to_string()),
+            role: MemberRole::Member,
+            created_at: Utc::now(),
+        };
+        repository.add_member(&member).await.unwrap();
+
+        member.role = MemberRole::Admin;
+        repository.update_member(&member).await.unwrap();
+
+        let found = repository
+            .find_member(&studio.id, &member.user_id)
+            .await
+            .unwrap()
+            .expect("The member should be saved");
+        assert_eq!(found.role, MemberRole::Admin);
+        assert_eq!(repository.list_members(&studio.id).await.unwrap().len(), 1);
+
+        assert!(repository
+            .remove_member(&studio.id, &member.user_id)
+            .await
+            .unwrap());
+        assert!(!repository
+            .remove_member(&studio.id, &member.user_id)
+            .await
+            .unwrap());
+
+        // The members go away with their studio
+        repository.add_member(&member).await.unwrap();
+        repository.delete_studio(&studio.id).await.unwrap();
+        assert!(repository
+            .list_members(&studio.id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -1,2 +1,10 @@
+pub mod api_key_repository;
+pub mod calendar_feed_repository;
 pub mod db;
+pub mod invitation_repository;
 pub mod meeting_repository;
+pub mod member_repository;
+pub mod moderation_repository;
+pub mod room_event_repository;
+pub mod studio_repository;
+pub mod waiting_room_repository;
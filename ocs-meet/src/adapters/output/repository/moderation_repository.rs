@@ -0,0 +1,187 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        meeting::MeetingId,
+        moderation::{ModerationAction, ModerationActionId, ModerationKind},
+        room::{PermissionChanges, TrackSource},
+        studio::StudioId,
+        user::UserId,
+    },
+    ports::output::moderation_repository::{ModerationRepository, ModerationRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as};
+use std::str::FromStr;
+
+struct ModerationActionRecord {
+    id: uuid::Uuid,
+    meeting_id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    performed_by: Option<String>,
+    participant_identity: String,
+    action: String,
+    track_source: Option<String>,
+    can_publish: Option<bool>,
+    can_publish_data: Option<bool>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ModerationActionRecord> for ModerationAction {
+    type Error = ModerationRepositoryError;
+
+    fn try_from(record: ModerationActionRecord) -> Result<Self, Self::Error> {
+        let kind = match (record.action.as_str(), record.track_source) {
+            ("mute", Some(source)) => ModerationKind::Mute(TrackSource::from_str(&source)?),
+            ("update_permissions", None) => ModerationKind::UpdatePermissions(PermissionChanges {
+                can_publish: record.can_publish,
+                can_publish_data: record.can_publish_data,
+            }),
+            _ => return Err(ModerationRepositoryError::UnknownAction(record.action)),
+        };
+
+        Ok(ModerationAction {
+            id: ModerationActionId::from(record.id),
+            meeting_id: MeetingId::from(record.meeting_id),
+            studio_id: StudioId::from(record.studio_id),
+            performed_by: record.performed_by.map(UserId::from),
+            participant_identity: record.participant_identity,
+            kind,
+            created_at: record.created_at,
+        })
+    }
+}
+
+impl ModerationRepository for Repository {
+    async fn record_action(
+        &self,
+        action: &ModerationAction,
+    ) -> Result<(), ModerationRepositoryError> {
+        let id = action.id.as_ref();
+        let meeting_id = action.meeting_id.as_ref();
+        let studio_id = action.studio_id.as_ref();
+        let performed_by: Option<&String> = action.performed_by.as_ref().map(AsRef::as_ref);
+        let (kind, track_source, changes) = match &action.kind {
+            ModerationKind::Mute(source) => (
+                "mute",
+                Some(source.to_string()),
+                PermissionChanges::default(),
+            ),
+            ModerationKind::UpdatePermissions(changes) => ("update_permissions", None, *changes),
+        };
+
+        query!(
+            "INSERT INTO moderation_actions (id, meeting_id, studio_id, performed_by, participant_identity, action, track_source, can_publish, can_publish_data, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            id,
+            meeting_id,
+            studio_id,
+            performed_by,
+            action.participant_identity,
+            kind,
+            track_source,
+            changes.can_publish,
+            changes.can_publish_data,
+            action.created_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_actions(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> Result<Vec<ModerationAction>, ModerationRepositoryError> {
+        let meeting_id = meeting_id.as_ref();
+        let records = query_as!(
+            ModerationActionRecord,
+            "SELECT id, meeting_id, studio_id, performed_by, participant_identity, action, track_source, can_publish, can_publish_data, created_at
+            FROM moderation_actions WHERE meeting_id = $1
+            ORDER BY created_at, id",
+            meeting_id
+        )
+        .fetch_all(&self.pg_pool)
+        .await?;
+
+        records
+            .into_iter()
+            .map(ModerationAction::try_from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            moderation::{ModerationAction, ModerationActionId, ModerationKind},
+            room::{PermissionChanges, TrackSource},
+            studio::StudioId,
+            user::UserId,
+        },
+        ports::output::{
+            meeting_repository::MeetingRepository, moderation_repository::ModerationRepository,
+        },
+    };
+    use chrono::{TimeDelta, Utc};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_audit_trail(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: studio_id.clone(),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        repository.create_meeting(&meeting).await.unwrap();
+
+        let now = Utc::now();
+        let mute = ModerationAction {
+            id: ModerationActionId::new(),
+            meeting_id: meeting.id.clone(),
+            studio_id,
+            performed_by: Some(UserId::from("bob".to_string())),
+            participant_identity: "customer-42".to_string(),
+            kind: ModerationKind::Mute(TrackSource::Microphone),
+            created_at: now - TimeDelta::minutes(2),
+        };
+        let silence = ModerationAction {
+            id: ModerationActionId::new(),
+            performed_by: None,
+            kind: ModerationKind::UpdatePermissions(PermissionChanges {
+                can_publish: Some(false),
+                can_publish_data: None,
+            }),
+            created_at: now,
+            ..mute.clone()
+        };
+        repository.record_action(&silence).await.unwrap();
+        repository.record_action(&mute).await.unwrap();
+
+        let actions = repository.list_actions(&meeting.id).await.unwrap();
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0].id, mute.id);
+        assert_eq!(actions[0].kind, mute.kind);
+        assert_eq!(actions[0].performed_by, mute.performed_by);
+        assert_eq!(actions[1].id, silence.id);
+        assert_eq!(actions[1].kind, silence.kind);
+        assert_eq!(actions[1].performed_by, None);
+    }
+}
@@ -0,0 +1,99 @@
+use super::db::Repository;
+use crate::{
+    domain::room::RoomEvent,
+    ports::output::room_event_repository::{RoomEventRepository, RoomEventRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use sqlx::query;
+
+impl RoomEventRepository for Repository {
+    async fn has_event(&self, event_id: &str) -> Result<bool, RoomEventRepositoryError> {
+        let exists = query!(
+            r#"SELECT EXISTS (SELECT 1 FROM room_events WHERE id = $1) AS "exists!""#,
+            event_id
+        )
+        .fetch_one(&self.pg_pool)
+        .await?
+        .exists;
+
+        Ok(exists)
+    }
+
+    async fn record_event(
+        &self,
+        event: &RoomEvent,
+        received_at: DateTime<Utc>,
+    ) -> Result<(), RoomEventRepositoryError> {
+        let meeting_id = event.meeting_id.as_ref();
+        let kind = event.kind.to_string();
+
+        // Two deliveries of the same event may be handled at once
+        query!(
+            "INSERT INTO room_events (id, meeting_id, kind, occurred_at, received_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (id) DO NOTHING",
+            event.id,
+            meeting_id,
+            kind,
+            event.occurred_at,
+            received_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::{RoomEvent, RoomEventKind},
+            studio::StudioId,
+        },
+        ports::output::{
+            meeting_repository::MeetingRepository, room_event_repository::RoomEventRepository,
+        },
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_record_event_twice(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        repository.create_meeting(&meeting).await.unwrap();
+
+        let event = RoomEvent {
+            id: "EV_4Xk9wTz".to_string(),
+            meeting_id: meeting.id.clone(),
+            kind: RoomEventKind::ParticipantJoined,
+            occurred_at: Utc::now(),
+        };
+        assert!(!repository.has_event(&event.id).await.unwrap());
+
+        repository.record_event(&event, Utc::now()).await.unwrap();
+        repository.record_event(&event, Utc::now()).await.unwrap();
+        assert!(repository.has_event(&event.id).await.unwrap());
+        assert!(!repository.has_event("EV_other").await.unwrap());
+    }
+}
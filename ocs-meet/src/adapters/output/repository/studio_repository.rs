@@ -0,0 +1,146 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        meeting::MeetingDuration,
+        studio::{Studio, StudioId, StudioName},
+    },
+    ports::output::studio_repository::{StudioRepository, StudioRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use sqlx::{query, query_as};
+use std::str::FromStr;
+
+struct StudioRecord {
+    id: uuid::Uuid,
+    name: String,
+    timezone: String,
+    default_duration: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StudioRecord> for Studio {
+    type Error = StudioRepositoryError;
+
+    fn try_from(record: StudioRecord) -> Result<Self, Self::Error> {
+        Ok(Studio {
+            id: StudioId::from(record.id),
+            name: StudioName::try_from(record.name)
+                .map_err(|err| StudioRepositoryError::InvalidStudio(err.to_string()))?,
+            timezone: Tz::from_str(&record.timezone)
+                .map_err(|err| StudioRepositoryError::InvalidStudio(err.to_string()))?,
+            default_duration: MeetingDuration::try_from(record.default_duration as u32)
+                .map_err(|err| StudioRepositoryError::InvalidStudio(err.to_string()))?,
+            created_at: record.created_at,
+        })
+    }
+}
+
+impl StudioRepository for Repository {
+    async fn create_studio(&self, studio: &Studio) -> Result<(), StudioRepositoryError> {
+        let id = studio.id.as_ref();
+        let name: &String = studio.name.as_ref();
+        let default_duration = studio.default_duration.minutes() as i32;
+
+        query!(
+            "INSERT INTO studios (id, name, timezone, default_duration, created_at)
+            VALUES ($1, $2, $3, $4, $5)",
+            id,
+            name,
+            studio.timezone.name(),
+            default_duration,
+            studio.created_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_studio(&self, studio: &Studio) -> Result<(), StudioRepositoryError> {
+        let id = studio.id.as_ref();
+        let name: &String = studio.name.as_ref();
+        let default_duration = studio.default_duration.minutes() as i32;
+
+        query!(
+            "UPDATE studios SET name = $2, timezone = $3, default_duration = $4 WHERE id = $1",
+            id,
+            name,
+            studio.timezone.name(),
+            default_duration
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_studio(
+        &self,
+        studio_id: &StudioId,
+    ) -> Result<Option<Studio>, StudioRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let record = query_as!(
+            StudioRecord,
+            "SELECT id, name, timezone, default_duration, created_at FROM studios WHERE id = $1",
+            studio_id
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        record.map(Studio::try_from).transpose()
+    }
+
+    async fn delete_studio(&self, studio_id: &StudioId) -> Result<bool, StudioRepositoryError> {
+        let studio_id = studio_id.as_ref();
+        let result = query!("DELETE FROM studios WHERE id = $1", studio_id)
+            .execute(&self.pg_pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            meeting::MeetingDuration,
+            studio::{Studio, StudioId, StudioName},
+        },
+        ports::output::studio_repository::StudioRepository,
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_studio_lifecycle(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let mut studio = Studio {
+            id: StudioId::from(uuid::Uuid::new_v4()),
+            name: StudioName::try_from("Yoga studio".to_string()).unwrap(),
+            timezone: chrono_tz::Europe::Paris,
+            default_duration: MeetingDuration::try_from(45).unwrap(),
+            created_at: Utc::now(),
+        };
+
+        repository.create_studio(&studio).await.unwrap();
+
+        studio.timezone = chrono_tz::America::New_York;
+        studio.default_duration = MeetingDuration::try_from(90).unwrap();
+        repository.update_studio(&studio).await.unwrap();
+
+        let found = repository
+            .find_studio(&studio.id)
+            .await
+            .unwrap()
+            .expect("The studio should be saved");
+        assert_eq!(found.timezone, chrono_tz::America::New_York);
+        assert_eq!(found.default_duration.minutes(), 90);
+
+        assert!(repository.delete_studio(&studio.id).await.unwrap());
+        assert!(repository.find_studio(&studio.id).await.unwrap().is_none());
+        assert!(!repository.delete_studio(&studio.id).await.unwrap());
+    }
+}
@@ -0,0 +1,225 @@
+use super::db::Repository;
+use crate::{
+    domain::{
+        meeting::MeetingId,
+        room::{Participant, ParticipantRole},
+        studio::StudioId,
+        waiting_room::{AdmissionStatus, WaitingTicket, WaitingTicketId},
+    },
+    ports::output::waiting_room_repository::{WaitingRoomRepository, WaitingRoomRepositoryError},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as};
+use std::str::FromStr;
+
+struct WaitingTicketRecord {
+    id: uuid::Uuid,
+    meeting_id: uuid::Uuid,
+    studio_id: uuid::Uuid,
+    participant_identity: String,
+    participant_name: String,
+    role: String,
+    status: String,
+    created_at: DateTime<Utc>,
+    decided_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<WaitingTicketRecord> for WaitingTicket {
+    type Error = WaitingRoomRepositoryError;
+
+    fn try_from(record: WaitingTicketRecord) -> Result<Self, Self::Error> {
+        Ok(WaitingTicket {
+            id: WaitingTicketId::from(record.id),
+            meeting_id: MeetingId::from(record.meeting_id),
+            studio_id: StudioId::from(record.studio_id),
+            participant: Participant {
+                identity: record.participant_identity,
+                name: record.participant_name,
+            },
+            role: ParticipantRole::from_str(&record.role)?,
+            status: AdmissionStatus::from_str(&record.status)?,
+            created_at: record.created_at,
+            decided_at: record.decided_at,
+        })
+    }
+}
+
+impl WaitingRoomRepository for Repository {
+    async fn create_ticket(
+        &self,
+        ticket: &WaitingTicket,
+    ) -> Result<(), WaitingRoomRepositoryError> {
+        let id = ticket.id.as_ref();
+        let meeting_id = ticket.meeting_id.as_ref();
+        let studio_id = ticket.studio_id.as_ref();
+
+        query!(
+            "INSERT INTO waiting_room_tickets (id, meeting_id, studio_id, participant_identity, participant_name, role, status, created_at, decided_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            id,
+            meeting_id,
+            studio_id,
+            ticket.participant.identity,
+            ticket.participant.name,
+            ticket.role.to_string(),
+            ticket.status.to_string(),
+            ticket.created_at,
+            ticket.decided_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_ticket(
+        &self,
+        ticket_id: &WaitingTicketId,
+    ) -> Result<Option<WaitingTicket>, WaitingRoomRepositoryError> {
+        let ticket_id = ticket_id.as_ref();
+        let record = query_as!(
+            WaitingTicketRecord,
+            "SELECT id, meeting_id, studio_id, participant_identity, participant_name, role, status, created_at, decided_at
+            FROM waiting_room_tickets WHERE id = $1",
+            ticket_id
+        )
+        .fetch_optional(&self.pg_pool)
+        .await?;
+
+        record.map(WaitingTicket::try_from).transpose()
+    }
+
+    async fn list_tickets(
+        &self,
+        meeting_id: &MeetingId,
+        status: AdmissionStatus,
+    ) -> Result<Vec<WaitingTicket>, WaitingRoomRepositoryError> {
+        let meeting_id = meeting_id.as_ref();
+        let records = query_as!(
+            WaitingTicketRecord,
+            "SELECT id, meeting_id, studio_id, participant_identity, participant_name, role, status, created_at, decided_at
+            FROM waiting_room_tickets WHERE meeting_id = $1 AND status = $2
+            ORDER BY created_at, id",
+            meeting_id,
+            status.to_string()
+        )
+        .fetch_all(&self.pg_pool)
+        .await?;
+
+        records.into_iter().map(WaitingTicket::try_from).collect()
+    }
+
+    async fn update_ticket(
+        &self,
+        ticket: &WaitingTicket,
+    ) -> Result<(), WaitingRoomRepositoryError> {
+        let id = ticket.id.as_ref();
+
+        query!(
+            "UPDATE waiting_room_tickets SET status = $2, decided_at = $3 WHERE id = $1",
+            id,
+            ticket.status.to_string(),
+            ticket.decided_at
+        )
+        .execute(&self.pg_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::output::repository::db::Repository,
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::{Participant, ParticipantRole},
+            studio::StudioId,
+            waiting_room::{AdmissionStatus, WaitingTicket, WaitingTicketId},
+        },
+        ports::output::{
+            meeting_repository::MeetingRepository, waiting_room_repository::WaitingRoomRepository,
+        },
+    };
+    use chrono::{TimeDelta, Utc};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_tickets(pool: PgPool) {
+        let repository = Repository::new(pool).await.unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: studio_id.clone(),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: true,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+        repository.create_meeting(&meeting).await.unwrap();
+
+        let now = Utc::now();
+        let first = WaitingTicket {
+            id: WaitingTicketId::new(),
+            meeting_id: meeting.id.clone(),
+            studio_id,
+            participant: Participant {
+                identity: "customer-42".to_string(),
+                name: "Jane Doe".to_string(),
+            },
+            role: ParticipantRole::Speaker,
+            status: AdmissionStatus::Pending,
+            created_at: now - TimeDelta::minutes(2),
+            decided_at: None,
+        };
+        let second = WaitingTicket {
+            id: WaitingTicketId::new(),
+            created_at: now,
+            ..first.clone()
+        };
+        repository.create_ticket(&second).await.unwrap();
+        repository.create_ticket(&first).await.unwrap();
+
+        let pending = repository
+            .list_tickets(&meeting.id, AdmissionStatus::Pending)
+            .await
+            .unwrap();
+        let ids = pending
+            .into_iter()
+            .map(|ticket| ticket.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![first.id.clone(), second.id.clone()]);
+
+        let admitted = WaitingTicket {
+            status: AdmissionStatus::Admitted,
+            decided_at: Some(now),
+            ..first
+        };
+        repository.update_ticket(&admitted).await.unwrap();
+
+        let found = repository
+            .find_ticket(&admitted.id)
+            .await
+            .unwrap()
+            .expect("The ticket should be found");
+        assert_eq!(found.status, AdmissionStatus::Admitted);
+        assert_eq!(found.participant, admitted.participant);
+        assert!(found.decided_at.is_some());
+
+        let pending = repository
+            .list_tickets(&meeting.id, AdmissionStatus::Pending)
+            .await
+            .unwrap();
+        assert_eq!(pending.len(), 1);
+    }
+}
@@ -1,12 +1,16 @@
 use anyhow::{Context, Error};
 use shuttle_runtime::SecretStore;
 
-use crate::ports::output::config::Config;
+use crate::ports::output::config::{Config, JwtKey, OidcSettings};
 
 pub struct ShuttleConfig {
     swagger_uri: url::Url,
+    livekit_url: String,
     livekit_api_key: String,
     livekit_secret: String,
+    jwt_key: Option<JwtKey>,
+    oidc: Option<OidcSettings>,
+    invitation_secret: String,
 }
 
 impl ShuttleConfig {
@@ -15,6 +19,10 @@ impl ShuttleConfig {
             .get("SWAGGER_URI")
             .context("SWAGGER_URI is required")?;
 
+        let livekit_url = secrets
+            .get("LIVEKIT_URL")
+            .context("LIVEKIT_URL is required")?;
+
         let livekit_api_key = secrets
             .get("LIVEKIT_API_KEY")
             .context("LIVEKIT_API_KEY is required")?;
@@ -23,12 +31,49 @@ impl ShuttleConfig {
             .get("LIVEKIT_SECRET")
             .context("LIVEKIT_SECRET is required")?;
 
+        let invitation_secret = secrets
+            .get("INVITATION_SECRET")
+            .context("INVITATION_SECRET is required")?;
+
+        let jwt_key = match (
+            secrets.get("JWT_RS256_PUBLIC_KEY"),
+            secrets.get("JWT_HS256_SECRET"),
+        ) {
+            (Some(public_key), _) => Some(JwtKey::Rs256(public_key)),
+            (None, Some(secret)) => Some(JwtKey::Hs256(secret)),
+            (None, None) => None,
+        };
+
+        let oidc = match secrets.get("OIDC_ISSUER") {
+            Some(issuer) => Some(OidcSettings {
+                issuer,
+                audience: secrets
+                    .get("OIDC_AUDIENCE")
+                    .context("OIDC_AUDIENCE is required with OIDC_ISSUER")?,
+                studio_claim: secrets
+                    .get("OIDC_STUDIO_CLAIM")
+                    .unwrap_or_else(|| "studio_id".to_string()),
+                user_claim: secrets
+                    .get("OIDC_USER_CLAIM")
+                    .unwrap_or_else(|| "sub".to_string()),
+            }),
+            None => None,
+        };
+
+        if jwt_key.is_none() && oidc.is_none() {
+            anyhow::bail!("JWT_RS256_PUBLIC_KEY, JWT_HS256_SECRET or OIDC_ISSUER is required");
+        }
+
         let swagger_uri = url::Url::parse(&swagger_uri).context("Cannot parse swagger uri")?;
 
         Ok(Self {
             swagger_uri,
+            livekit_url,
             livekit_api_key,
             livekit_secret,
+            jwt_key,
+            oidc,
+            invitation_secret,
         })
     }
 }
@@ -38,6 +83,10 @@ impl Config for ShuttleConfig {
         self.swagger_uri.clone()
     }
 
+    fn livekit_url(&self) -> String {
+        self.livekit_url.clone()
+    }
+
     fn livekit_api_key(&self) -> String {
         self.livekit_api_key.clone()
     }
@@ -45,4 +94,16 @@ impl Config for ShuttleConfig {
     fn livekit_secret(&self) -> String {
         self.livekit_secret.clone()
     }
+
+    fn jwt_key(&self) -> Option<JwtKey> {
+        self.jwt_key.clone()
+    }
+
+    fn oidc(&self) -> Option<OidcSettings> {
+        self.oidc.clone()
+    }
+
+    fn invitation_secret(&self) -> String {
+        self.invitation_secret.clone()
+    }
 }
@@ -1,23 +1,73 @@
 use crate::{
-    adapters::input::http::handlers::meeting::MeetingRouter,
+    adapters::input::http::{
+        auth::StudioTokenVerifier,
+        handlers::{
+            api_key::ApiKeyRouter, meeting::MeetingRouter, member::MemberRouter,
+            studio::StudioRouter, webhook::LivekitWebhook,
+        },
+    },
+    domain::invitation::InvitationKey,
     ports::output::{
-        config::Config, meeting_repository::MeetingRepository, room_manager::RoomManager,
+        api_key_repository::ApiKeyRepository, calendar_feed_repository::CalendarFeedRepository,
+        config::Config, invitation_repository::InvitationRepository,
+        meeting_repository::MeetingRepository, member_repository::MemberRepository,
+        moderation_repository::ModerationRepository, room_event_repository::RoomEventRepository,
+        room_manager::RoomManager, studio_repository::StudioRepository,
+        waiting_room_repository::WaitingRoomRepository,
     },
 };
-use anyhow::Error;
-use poem::{middleware::Cors, Endpoint, EndpointExt, Route};
+use anyhow::{Context, Error};
+use livekit_api::{access_token::TokenVerifier, webhooks::WebhookReceiver};
+use poem::{middleware::Cors, post, Endpoint, EndpointExt, Route};
+use std::sync::Arc;
 
 pub async fn app<C, R, M>(config: C, repository: R, room_manager: M) -> Result<impl Endpoint, Error>
 where
     C: Config,
-    R: MeetingRepository + Send + Sync + 'static,
+    R: MeetingRepository
+        + CalendarFeedRepository
+        + ApiKeyRepository
+        + StudioRepository
+        + MemberRepository
+        + InvitationRepository
+        + WaitingRoomRepository
+        + ModerationRepository
+        + RoomEventRepository
+        + Send
+        + Sync
+        + 'static,
     M: RoomManager + Send + Sync + 'static,
 {
+    let repository = Arc::new(repository);
+
+    let verifier = StudioTokenVerifier::new(config.jwt_key().as_ref(), config.oidc())
+        .context("Cannot load the token key")?
+        .with_api_keys(repository.clone());
+
+    let livekit_webhook = LivekitWebhook {
+        repository: repository.clone(),
+        receiver: WebhookReceiver::new(TokenVerifier::with_api_key(
+            &config.livekit_api_key(),
+            &config.livekit_secret(),
+        )),
+    };
+
     let api_service = poem_openapi::OpenApiService::new(
-        MeetingRouter {
-            repository,
-            room_manager,
-        },
+        (
+            MeetingRouter {
+                repository: repository.clone(),
+                room_manager,
+                api_url: config.swagger_uri(),
+                invitation_key: InvitationKey::from(config.invitation_secret()),
+            },
+            ApiKeyRouter {
+                repository: repository.clone(),
+            },
+            StudioRouter {
+                repository: repository.clone(),
+            },
+            MemberRouter { repository },
+        ),
         "API",
         "1.0",
     )
@@ -30,6 +80,8 @@ where
         .nest("/api", api_service)
         .nest("/ui", api_swagger)
         .nest("openapi.json", spec_json)
+        .at("/webhooks/livekit", post(livekit_webhook))
+        .data(verifier)
         .with(Cors::new());
 
     Ok(endpoint)
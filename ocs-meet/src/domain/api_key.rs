@@ -0,0 +1,145 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use derive_more::AsRef;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
+
+use super::studio::StudioId;
+
+#[derive(AsRef, Debug, PartialEq, Clone)]
+pub struct ApiKeyId(uuid::Uuid);
+
+impl ApiKeyId {
+    pub fn new() -> Self {
+        ApiKeyId(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for ApiKeyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<uuid::Uuid> for ApiKeyId {
+    fn from(uuid: uuid::Uuid) -> Self {
+        ApiKeyId(uuid)
+    }
+}
+
+/// Secret of an API key, shown once on creation since only its hash is stored
+#[derive(AsRef)]
+pub struct ApiKeyToken(String);
+
+impl ApiKeyToken {
+    /// Prefix telling the API keys apart from the other bearer tokens
+    pub const PREFIX: &'static str = "ocsk_";
+
+    pub fn generate() -> Self {
+        let mut bytes = [0; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        ApiKeyToken(format!("{}{}", Self::PREFIX, URL_SAFE_NO_PAD.encode(bytes)))
+    }
+
+    /// Reads a bearer token as an API key, `None` when it is not shaped like one
+    pub fn parse(token: &str) -> Option<Self> {
+        token
+            .starts_with(Self::PREFIX)
+            .then(|| ApiKeyToken(token.to_string()))
+    }
+
+    pub fn hash(&self) -> ApiKeyTokenHash {
+        ApiKeyTokenHash(Sha256::digest(self.0.as_bytes()).to_vec())
+    }
+}
+
+#[derive(Debug, AsRef, PartialEq, Clone)]
+pub struct ApiKeyTokenHash(Vec<u8>);
+
+impl From<Vec<u8>> for ApiKeyTokenHash {
+    fn from(hash: Vec<u8>) -> Self {
+        ApiKeyTokenHash(hash)
+    }
+}
+
+/// What an API key is allowed to do on its studio
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ApiKeyScope {
+    /// Reading the meetings
+    Read,
+    /// Creating and changing the meetings
+    Write,
+    /// Getting tokens to enter the rooms
+    Join,
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown API key scope {0}")]
+pub struct UnknownApiKeyScope(String);
+
+impl FromStr for ApiKeyScope {
+    type Err = UnknownApiKeyScope;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(ApiKeyScope::Read),
+            "write" => Ok(ApiKeyScope::Write),
+            "join" => Ok(ApiKeyScope::Join),
+            _ => Err(UnknownApiKeyScope(s.to_string())),
+        }
+    }
+}
+
+impl Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyScope::Read => write!(f, "read"),
+            ApiKeyScope::Write => write!(f, "write"),
+            ApiKeyScope::Join => write!(f, "join"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub studio_id: StudioId,
+    /// Label to recognize the key, e.g. the integration using it
+    pub name: String,
+    pub token_hash: ApiKeyTokenHash,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiKeyScope, ApiKeyToken};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_token_shape() {
+        let token = ApiKeyToken::generate();
+        let parsed = ApiKeyToken::parse(token.as_ref()).expect("A generated key should parse");
+        assert_eq!(parsed.hash(), token.hash());
+        assert_ne!(ApiKeyToken::generate().hash(), token.hash());
+
+        assert!(ApiKeyToken::parse("eyJhbGciOiJIUzI1NiJ9.e30.sig").is_none());
+    }
+
+    #[test]
+    fn test_scope_round_trip() {
+        for scope in [ApiKeyScope::Read, ApiKeyScope::Write, ApiKeyScope::Join] {
+            assert_eq!(ApiKeyScope::from_str(&scope.to_string()).unwrap(), scope);
+        }
+        assert!(ApiKeyScope::from_str("admin").is_err());
+    }
+}
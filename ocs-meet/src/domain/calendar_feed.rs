@@ -0,0 +1,72 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use derive_more::AsRef;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use super::studio::StudioId;
+
+/// Secret giving read access to the calendar feed of a studio, only its hash is stored
+#[derive(AsRef)]
+pub struct CalendarFeedToken(String);
+
+impl From<String> for CalendarFeedToken {
+    fn from(token: String) -> Self {
+        CalendarFeedToken(token)
+    }
+}
+
+impl CalendarFeedToken {
+    pub fn generate() -> Self {
+        let mut bytes = [0; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        CalendarFeedToken(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn hash(&self) -> CalendarFeedTokenHash {
+        CalendarFeedTokenHash(Sha256::digest(self.0.as_bytes()).to_vec())
+    }
+}
+
+#[derive(Debug, AsRef, PartialEq, Clone)]
+pub struct CalendarFeedTokenHash(Vec<u8>);
+
+impl From<Vec<u8>> for CalendarFeedTokenHash {
+    fn from(hash: Vec<u8>) -> Self {
+        CalendarFeedTokenHash(hash)
+    }
+}
+
+/// Calendar subscription of a studio, there is at most one per studio
+#[derive(Debug, PartialEq, Clone)]
+pub struct CalendarFeed {
+    pub studio_id: StudioId,
+    pub token_hash: CalendarFeedTokenHash,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeed {
+    pub fn accepts(&self, token: &CalendarFeedToken) -> bool {
+        self.token_hash == token.hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CalendarFeed, CalendarFeedToken};
+    use crate::domain::studio::StudioId;
+    use chrono::Utc;
+
+    #[test]
+    fn test_token_check() {
+        let token = CalendarFeedToken::generate();
+        let feed = CalendarFeed {
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            token_hash: token.hash(),
+            created_at: Utc::now(),
+        };
+
+        assert!(feed.accepts(&CalendarFeedToken::from(token.as_ref().clone())));
+        assert!(!feed.accepts(&CalendarFeedToken::generate()));
+    }
+}
@@ -0,0 +1,134 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use derive_more::AsRef;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{meeting::MeetingId, room::ParticipantRole, studio::StudioId};
+
+#[derive(AsRef, Debug, PartialEq, Clone)]
+pub struct InvitationId(uuid::Uuid);
+
+impl InvitationId {
+    pub fn new() -> Self {
+        InvitationId(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for InvitationId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<uuid::Uuid> for InvitationId {
+    fn from(uuid: uuid::Uuid) -> Self {
+        InvitationId(uuid)
+    }
+}
+
+/// Invitation of a guest without an account to a meeting, redeemed through a signed link
+#[derive(Debug, PartialEq, Clone)]
+pub struct Invitation {
+    pub id: InvitationId,
+    pub meeting_id: MeetingId,
+    pub studio_id: StudioId,
+    /// Name shown to the others once the guest is in the room
+    pub display_name: String,
+    pub role: ParticipantRole,
+    /// Number of times the link can be redeemed, without limit when `None`
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.max_uses.is_some_and(|max_uses| self.uses >= max_uses)
+    }
+}
+
+/// Token of an invitation link, the id of the invitation followed by its signature
+#[derive(AsRef, Debug, Clone)]
+pub struct InvitationToken(String);
+
+impl From<String> for InvitationToken {
+    fn from(token: String) -> Self {
+        InvitationToken(token)
+    }
+}
+
+/// Secret signing the invitation links, so their ids cannot be guessed nor made up
+#[derive(Clone)]
+pub struct InvitationKey(Vec<u8>);
+
+impl From<String> for InvitationKey {
+    fn from(secret: String) -> Self {
+        InvitationKey(secret.into_bytes())
+    }
+}
+
+impl InvitationKey {
+    fn mac(&self, invitation_id: &uuid::Uuid) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.0).expect("HMAC accepts keys of any size");
+        mac.update(invitation_id.as_bytes());
+        mac
+    }
+
+    pub fn sign(&self, invitation_id: &InvitationId) -> InvitationToken {
+        let signature = self.mac(&invitation_id.0).finalize().into_bytes();
+        InvitationToken(format!(
+            "{}.{}",
+            invitation_id.0.simple(),
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// The invitation the token has been signed for, `None` when it is malformed or forged
+    pub fn verify(&self, token: &InvitationToken) -> Option<InvitationId> {
+        let (invitation_id, signature) = token.0.split_once('.')?;
+        let invitation_id = uuid::Uuid::try_parse(invitation_id).ok()?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+        self.mac(&invitation_id)
+            .verify_slice(&signature)
+            .ok()
+            .map(|()| InvitationId(invitation_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvitationId, InvitationKey, InvitationToken};
+
+    #[test]
+    fn test_signature() {
+        let key = InvitationKey::from("a secret only known by the tests".to_string());
+        let invitation_id = InvitationId::new();
+
+        let token = key.sign(&invitation_id);
+        assert_eq!(key.verify(&token), Some(invitation_id.clone()));
+
+        let other_key = InvitationKey::from("another secret".to_string());
+        assert_eq!(other_key.verify(&token), None);
+
+        // Another invitation cannot reuse the signature
+        let (_, signature) = token.as_ref().split_once('.').unwrap();
+        let forged = InvitationToken::from(format!(
+            "{}.{signature}",
+            InvitationId::new().as_ref().simple()
+        ));
+        assert_eq!(key.verify(&forged), None);
+
+        assert_eq!(
+            key.verify(&InvitationToken::from("garbage".to_string())),
+            None
+        );
+    }
+}
@@ -1,8 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_more::AsRef;
+use std::str::FromStr;
+use thiserror::Error;
 use validator::{Validate, ValidationErrors};
 
-use super::studio::StudioId;
+use super::{passcode::PasscodeHash, recurrence::RecurrenceRule, studio::StudioId};
 
 #[derive(Debug, AsRef, PartialEq, Clone)]
 pub struct MeetingId(pub uuid::Uuid);
@@ -25,7 +27,28 @@ impl Default for MeetingId {
     }
 }
 
-#[derive(Validate, AsRef)]
+#[derive(Debug, AsRef, PartialEq, Clone)]
+pub struct MeetingSeriesId(pub uuid::Uuid);
+
+impl From<uuid::Uuid> for MeetingSeriesId {
+    fn from(id: uuid::Uuid) -> Self {
+        MeetingSeriesId(id)
+    }
+}
+
+impl MeetingSeriesId {
+    pub fn new() -> Self {
+        MeetingSeriesId::from(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for MeetingSeriesId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Validate, AsRef, Clone)]
 pub struct MeetingName {
     #[validate(length(min = 1, message = "Name cannot be empty"))]
     inner: String,
@@ -41,16 +64,217 @@ impl TryFrom<String> for MeetingName {
     }
 }
 
+#[derive(Debug, Validate, AsRef, PartialEq, Clone, Copy)]
+pub struct MeetingDuration {
+    #[validate(range(
+        min = 1,
+        max = 1440,
+        message = "Duration must be between one minute and one day"
+    ))]
+    minutes: u32,
+}
+
+impl MeetingDuration {
+    /// Longest duration a meeting can have, used to bound overlap lookups
+    pub const MAX: TimeDelta = TimeDelta::days(1);
+
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    pub fn to_time_delta(&self) -> TimeDelta {
+        TimeDelta::minutes(i64::from(self.minutes))
+    }
+}
+
+impl TryFrom<u32> for MeetingDuration {
+    type Error = ValidationErrors;
+
+    fn try_from(minutes: u32) -> Result<Self, Self::Error> {
+        let duration = MeetingDuration { minutes };
+        let () = duration.validate()?;
+        Ok(duration)
+    }
+}
+
+impl Default for MeetingDuration {
+    fn default() -> Self {
+        MeetingDuration { minutes: 60 }
+    }
+}
+
+/// Period around a meeting during which participants are allowed to join it
+#[derive(Debug, Validate, PartialEq, Clone, Copy)]
+pub struct JoinWindow {
+    #[validate(range(max = 240, message = "Early entry cannot exceed four hours"))]
+    early_entry_minutes: u32,
+    #[validate(range(max = 240, message = "Late join grace cannot exceed four hours"))]
+    late_join_minutes: u32,
+}
+
+impl JoinWindow {
+    pub fn new(early_entry_minutes: u32, late_join_minutes: u32) -> Result<Self, ValidationErrors> {
+        let window = JoinWindow {
+            early_entry_minutes,
+            late_join_minutes,
+        };
+        let () = window.validate()?;
+        Ok(window)
+    }
+
+    /// Minutes before the start during which participants can already enter
+    pub fn early_entry_minutes(&self) -> u32 {
+        self.early_entry_minutes
+    }
+
+    /// Minutes after the end during which participants can still join
+    pub fn late_join_minutes(&self) -> u32 {
+        self.late_join_minutes
+    }
+}
+
+impl Default for JoinWindow {
+    fn default() -> Self {
+        JoinWindow {
+            early_entry_minutes: 15,
+            late_join_minutes: 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MeetingStatus {
+    Scheduled,
+    /// Somebody entered the room of the meeting
+    Live,
+    Cancelled,
+    Ended,
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown meeting status {0}")]
+pub struct UnknownMeetingStatus(String);
+
+impl MeetingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingStatus::Scheduled => "scheduled",
+            MeetingStatus::Live => "live",
+            MeetingStatus::Cancelled => "cancelled",
+            MeetingStatus::Ended => "ended",
+        }
+    }
+}
+
+impl FromStr for MeetingStatus {
+    type Err = UnknownMeetingStatus;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "scheduled" => Ok(MeetingStatus::Scheduled),
+            "live" => Ok(MeetingStatus::Live),
+            "cancelled" => Ok(MeetingStatus::Cancelled),
+            "ended" => Ok(MeetingStatus::Ended),
+            other => Err(UnknownMeetingStatus(other.to_string())),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct Meeting {
     pub id: MeetingId,
     pub studio_id: StudioId,
     pub name: MeetingName,
     pub date: DateTime<Utc>,
+    pub duration: MeetingDuration,
+    pub join_window: JoinWindow,
+    pub status: MeetingStatus,
+    /// The series the meeting is an occurrence of, if any
+    pub series_id: Option<MeetingSeriesId>,
+    /// Asked to the participants who are not members of the studio when set
+    pub passcode: Option<PasscodeHash>,
+    /// Whether the participants who are not members wait for a host to let them in
+    pub waiting_room: bool,
+    /// Participants the room holds at once, without limit when `None`
+    pub max_participants: Option<u32>,
+    /// When the first participant entered the room, as reported by LiveKit
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the room was closed, as reported by LiveKit
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl Meeting {
+    pub fn end_date(&self) -> DateTime<Utc> {
+        self.date + self.duration.to_time_delta()
+    }
+
+    pub fn join_opens_at(&self) -> DateTime<Utc> {
+        self.date - TimeDelta::minutes(i64::from(self.join_window.early_entry_minutes))
+    }
+
+    pub fn join_closes_at(&self) -> DateTime<Utc> {
+        self.end_date() + TimeDelta::minutes(i64::from(self.join_window.late_join_minutes))
+    }
+
+    /// Marks the meeting as live from the first time somebody entered its room, returns whether
+    /// anything changed so that replayed events leave the meeting untouched
+    pub fn start(&mut self, at: DateTime<Utc>) -> bool {
+        match self.status {
+            MeetingStatus::Scheduled => {
+                self.status = MeetingStatus::Live;
+                self.started_at.get_or_insert(at);
+                true
+            }
+            MeetingStatus::Live | MeetingStatus::Cancelled | MeetingStatus::Ended => false,
+        }
+    }
+
+    /// Marks the meeting as ended once its room is closed, returns whether anything changed
+    ///
+    /// A meeting nobody entered only ends when its join window has closed, its room being closed
+    /// earlier when the meeting is rescheduled.
+    pub fn finish(&mut self, at: DateTime<Utc>) -> bool {
+        match self.status {
+            MeetingStatus::Live => {
+                self.status = MeetingStatus::Ended;
+                self.ended_at = Some(at);
+                true
+            }
+            MeetingStatus::Scheduled if at >= self.join_closes_at() => {
+                self.status = MeetingStatus::Ended;
+                self.ended_at = Some(at);
+                true
+            }
+            // Ended by a host before the room was closed
+            MeetingStatus::Ended if self.ended_at.is_none() => {
+                self.ended_at = Some(at);
+                true
+            }
+            MeetingStatus::Scheduled | MeetingStatus::Ended | MeetingStatus::Cancelled => false,
+        }
+    }
+
+    /// Whether the meeting shares some time with the half-open interval `[start, end)`
+    pub fn overlaps(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        self.date < end && start < self.end_date()
+    }
+}
+
+/// Meetings repeating after a recurrence rule, each occurrence being stored as its own meeting
+pub struct MeetingSeries {
+    pub id: MeetingSeriesId,
+    pub studio_id: StudioId,
+    pub rule: RecurrenceRule,
+    /// Start of the first occurrence, from which the rule is expanded
+    pub starts_at: DateTime<Utc>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::MeetingName;
+    use super::{JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus};
+    use crate::domain::studio::StudioId;
+    use chrono::{TimeDelta, Utc};
+    use std::str::FromStr;
 
     #[test]
     fn test_name_length_ko() {
@@ -65,4 +289,127 @@ mod tests {
         let name = MeetingName::try_from(raw_name);
         assert!(name.is_ok());
     }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in [
+            MeetingStatus::Scheduled,
+            MeetingStatus::Live,
+            MeetingStatus::Cancelled,
+            MeetingStatus::Ended,
+        ] {
+            assert_eq!(MeetingStatus::from_str(status.as_str()).unwrap(), status);
+        }
+        assert!(MeetingStatus::from_str("postponed").is_err());
+    }
+
+    #[test]
+    fn test_duration_bounds() {
+        assert!(MeetingDuration::try_from(0).is_err());
+        assert!(MeetingDuration::try_from(1).is_ok());
+        assert!(MeetingDuration::try_from(1440).is_ok());
+        assert!(MeetingDuration::try_from(1441).is_err());
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let date = Utc::now();
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date,
+            duration: MeetingDuration::try_from(60).unwrap(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+
+        let minutes = TimeDelta::minutes;
+        assert!(meeting.overlaps(date + minutes(1), date + minutes(2)));
+        assert!(meeting.overlaps(date - minutes(30), date + minutes(30)));
+        assert!(meeting.overlaps(date - minutes(30), date + minutes(90)));
+        assert!(!meeting.overlaps(date + minutes(60), date + minutes(90)));
+        assert!(!meeting.overlaps(date - minutes(30), date));
+    }
+
+    #[test]
+    fn test_join_window() {
+        let date = Utc::now();
+        let meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date,
+            duration: MeetingDuration::try_from(60).unwrap(),
+            join_window: JoinWindow::new(15, 5).unwrap(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+
+        assert_eq!(meeting.join_opens_at(), date - TimeDelta::minutes(15));
+        assert_eq!(meeting.join_closes_at(), date + TimeDelta::minutes(65));
+        assert!(JoinWindow::new(241, 0).is_err());
+        assert!(JoinWindow::new(0, 241).is_err());
+    }
+
+    #[test]
+    fn test_room_lifecycle() {
+        let date = Utc::now();
+        let mut meeting = Meeting {
+            id: MeetingId::new(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date,
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        };
+
+        let later = date + TimeDelta::minutes(5);
+        assert!(!meeting.finish(later));
+        assert_eq!(meeting.status, MeetingStatus::Scheduled);
+
+        let mut missed = meeting.clone();
+        let closed_at = missed.join_closes_at();
+        assert!(missed.finish(closed_at));
+        assert_eq!(missed.status, MeetingStatus::Ended);
+        assert_eq!(missed.ended_at, Some(closed_at));
+
+        assert!(meeting.start(date));
+        assert!(!meeting.start(later));
+        assert_eq!(meeting.status, MeetingStatus::Live);
+        assert_eq!(meeting.started_at, Some(date));
+
+        assert!(meeting.finish(later));
+        assert!(!meeting.finish(later + TimeDelta::minutes(5)));
+        assert!(!meeting.start(later));
+        assert_eq!(meeting.status, MeetingStatus::Ended);
+        assert_eq!(meeting.ended_at, Some(later));
+
+        let mut cancelled = Meeting {
+            status: MeetingStatus::Cancelled,
+            ended_at: None,
+            ..meeting
+        };
+        assert!(!cancelled.start(date));
+        assert!(!cancelled.finish(later));
+        assert_eq!(cancelled.status, MeetingStatus::Cancelled);
+    }
 }
@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
+
+use super::{studio::StudioId, user::UserId};
+
+/// Role of a user in a studio, from the most to the least powerful
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MemberRole {
+    /// Everything, including deleting the studio
+    Owner,
+    /// Manages the settings, the integrations and the members of the studio
+    Admin,
+    /// Schedules and runs the meetings
+    Member,
+    /// Only sees the meetings
+    Viewer,
+}
+
+/// What an operation on a studio requires
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Permission {
+    View,
+    JoinMeetings,
+    ManageMeetings,
+    ManageStudio,
+    ManageMembers,
+    DeleteStudio,
+}
+
+impl MemberRole {
+    pub fn allows(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::View | Permission::JoinMeetings => true,
+            Permission::ManageMeetings => *self != MemberRole::Viewer,
+            Permission::ManageStudio | Permission::ManageMembers => {
+                matches!(self, MemberRole::Owner | MemberRole::Admin)
+            }
+            Permission::DeleteStudio => *self == MemberRole::Owner,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown member role {0}")]
+pub struct UnknownMemberRole(String);
+
+impl FromStr for MemberRole {
+    type Err = UnknownMemberRole;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owner" => Ok(MemberRole::Owner),
+            "admin" => Ok(MemberRole::Admin),
+            "member" => Ok(MemberRole::Member),
+            "viewer" => Ok(MemberRole::Viewer),
+            _ => Err(UnknownMemberRole(s.to_string())),
+        }
+    }
+}
+
+impl Display for MemberRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemberRole::Owner => write!(f, "owner"),
+            MemberRole::Admin => write!(f, "admin"),
+            MemberRole::Member => write!(f, "member"),
+            MemberRole::Viewer => write!(f, "viewer"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Member {
+    pub studio_id: StudioId,
+    pub user_id: UserId,
+    /// Name to show in the member list, the identity provider keeps the rest of the profile
+    pub display_name: Option<String>,
+    pub role: MemberRole,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The studio a request is allowed to act on, and with which role
+#[derive(Debug, PartialEq, Clone)]
+pub struct Access {
+    pub studio_id: StudioId,
+    pub role: MemberRole,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemberRole, Permission};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_role_round_trip() {
+        for role in [
+            MemberRole::Owner,
+            MemberRole::Admin,
+            MemberRole::Member,
+            MemberRole::Viewer,
+        ] {
+            assert_eq!(MemberRole::from_str(&role.to_string()).unwrap(), role);
+        }
+        assert!(MemberRole::from_str("guest").is_err());
+    }
+
+    #[test]
+    fn test_role_permissions() {
+        assert!(MemberRole::Viewer.allows(Permission::View));
+        assert!(!MemberRole::Viewer.allows(Permission::ManageMeetings));
+        assert!(MemberRole::Member.allows(Permission::ManageMeetings));
+        assert!(!MemberRole::Member.allows(Permission::ManageMembers));
+        assert!(MemberRole::Admin.allows(Permission::ManageMembers));
+        assert!(!MemberRole::Admin.allows(Permission::DeleteStudio));
+        assert!(MemberRole::Owner.allows(Permission::DeleteStudio));
+    }
+}
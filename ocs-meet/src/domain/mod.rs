@@ -1,3 +1,12 @@
+pub mod api_key;
+pub mod calendar_feed;
+pub mod invitation;
 pub mod meeting;
+pub mod member;
+pub mod moderation;
+pub mod passcode;
+pub mod recurrence;
 pub mod room;
 pub mod studio;
+pub mod user;
+pub mod waiting_room;
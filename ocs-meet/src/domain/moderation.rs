@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use derive_more::AsRef;
+
+use super::{
+    meeting::MeetingId,
+    room::{PermissionChanges, TrackSource},
+    studio::StudioId,
+    user::UserId,
+};
+
+#[derive(AsRef, Debug, PartialEq, Clone)]
+pub struct ModerationActionId(uuid::Uuid);
+
+impl ModerationActionId {
+    pub fn new() -> Self {
+        ModerationActionId(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for ModerationActionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<uuid::Uuid> for ModerationActionId {
+    fn from(uuid: uuid::Uuid) -> Self {
+        ModerationActionId(uuid)
+    }
+}
+
+/// What a host did to a participant
+#[derive(Debug, PartialEq, Clone)]
+pub enum ModerationKind {
+    Mute(TrackSource),
+    UpdatePermissions(PermissionChanges),
+}
+
+/// Entry of the audit trail of a meeting, keeping who acted on whom in its room
+#[derive(Debug, PartialEq, Clone)]
+pub struct ModerationAction {
+    pub id: ModerationActionId,
+    pub meeting_id: MeetingId,
+    pub studio_id: StudioId,
+    /// User who acted, `None` for the backend of the studio
+    pub performed_by: Option<UserId>,
+    /// Identity in the room of the participant acted on
+    pub participant_identity: String,
+    pub kind: ModerationKind,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,105 @@
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+use derive_more::AsRef;
+use hmac::Hmac;
+use rand::RngCore;
+use sha2::Sha256;
+use validator::{Validate, ValidationErrors};
+
+/// Secret to enter a meeting shared publicly, only its hash is stored
+#[derive(Validate)]
+pub struct MeetingPasscode {
+    #[validate(length(min = 4, max = 64, message = "Passcode must have 4 to 64 characters"))]
+    inner: String,
+}
+
+impl TryFrom<String> for MeetingPasscode {
+    type Error = ValidationErrors;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let passcode = MeetingPasscode { inner: value };
+        let () = passcode.validate()?;
+        Ok(passcode)
+    }
+}
+
+const ALGORITHM: &str = "pbkdf2-sha256";
+const ROUNDS: u32 = 100_000;
+
+fn derive(passcode: &str, salt: &[u8], rounds: u32) -> [u8; 32] {
+    let mut hash = [0; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passcode.as_bytes(), salt, rounds, &mut hash);
+    hash
+}
+
+impl MeetingPasscode {
+    /// Salted and slow hash, passcodes being short enough to be guessed from a fast one
+    pub fn hash(&self) -> PasscodeHash {
+        let mut salt = [0; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let hash = derive(&self.inner, &salt, ROUNDS);
+        PasscodeHash(format!(
+            "{ALGORITHM}${ROUNDS}${}${}",
+            STANDARD_NO_PAD.encode(salt),
+            STANDARD_NO_PAD.encode(hash)
+        ))
+    }
+}
+
+/// Hash of a passcode as `pbkdf2-sha256$<rounds>$<salt>$<hash>`, keeping what is needed to check
+/// it when the rounds are raised
+#[derive(Debug, AsRef, PartialEq, Clone)]
+pub struct PasscodeHash(String);
+
+impl From<String> for PasscodeHash {
+    fn from(value: String) -> Self {
+        PasscodeHash(value)
+    }
+}
+
+impl PasscodeHash {
+    pub fn verify(&self, passcode: &str) -> bool {
+        let mut parts = self.0.split('$');
+        let (Some(ALGORITHM), Some(rounds), Some(salt), Some(expected), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return false;
+        };
+        let (Ok(rounds), Ok(salt), Ok(expected)) = (
+            rounds.parse(),
+            STANDARD_NO_PAD.decode(salt),
+            STANDARD_NO_PAD.decode(expected),
+        ) else {
+            return false;
+        };
+
+        // Compared without stopping at the first difference, so timing tells nothing
+        let hash = derive(passcode, &salt, rounds);
+        hash.len() == expected.len()
+            && hash
+                .iter()
+                .zip(&expected)
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MeetingPasscode, PasscodeHash};
+
+    #[test]
+    fn test_passcode_hash() {
+        let passcode = MeetingPasscode::try_from("sunrise-42".to_string()).unwrap();
+        let hash = passcode.hash();
+
+        assert!(hash.verify("sunrise-42"));
+        assert!(!hash.verify("sunrise-43"));
+        assert_ne!(hash, passcode.hash(), "Each hash should have its own salt");
+        assert!(!PasscodeHash::from("sunrise-42".to_string()).verify("sunrise-42"));
+        assert!(MeetingPasscode::try_from("123".to_string()).is_err());
+    }
+}
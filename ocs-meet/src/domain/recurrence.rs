@@ -0,0 +1,450 @@
+use chrono::{
+    DateTime, Datelike, Days, LocalResult, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Most occurrences a series can have, so that materialising it stays bounded
+pub const MAX_OCCURRENCES: u32 = 366;
+
+/// Longest `INTERVAL` between two periods of a series, in days or weeks after its frequency
+pub const MAX_INTERVAL: u32 = 366;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum RecurrenceRuleError {
+    #[error("Malformed recurrence rule part {0}")]
+    Malformed(String),
+    #[error("Unsupported recurrence rule part {0}")]
+    Unsupported(String),
+    #[error("The recurrence rule needs a FREQ")]
+    MissingFrequency,
+    #[error("The recurrence rule needs either a COUNT or an UNTIL")]
+    Unbounded,
+    #[error("A series cannot have more than {MAX_OCCURRENCES} occurrences")]
+    TooManyOccurrences,
+    #[error("The recurrence rule has no occurrence")]
+    NoOccurrence,
+    #[error("The interval cannot be longer than {MAX_INTERVAL}")]
+    IntervalTooLong,
+    #[error("The occurrences of the series go past the supported dates")]
+    OutOfRange,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RecurrenceEnd {
+    Count(u32),
+    /// Inclusive, an occurrence starting at this instant is part of the series
+    Until(DateTime<Utc>),
+}
+
+/// The subset of RFC 5545 recurrence rules we support: `FREQ` (daily or weekly), `INTERVAL`,
+/// `BYDAY` without ordinals, and a mandatory `COUNT` or `UNTIL`
+///
+/// Occurrences are expanded in the timezone of the studio and keep the local time of day of the
+/// first one, across the changes of offset.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    by_day: Vec<Weekday>,
+    end: RecurrenceEnd,
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, RecurrenceRuleError> {
+    match value {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        other => Err(RecurrenceRuleError::Unsupported(format!("BYDAY={other}"))),
+    }
+}
+
+fn format_weekday(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_until(value: &str) -> Result<DateTime<Utc>, RecurrenceRuleError> {
+    let malformed = || RecurrenceRuleError::Malformed(format!("UNTIL={value}"));
+    if let Some(value) = value.strip_suffix('Z') {
+        let until =
+            NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| malformed())?;
+        return Ok(until.and_utc());
+    }
+    // A date alone includes the whole day
+    let until = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| malformed())?;
+    Ok(until
+        .and_hms_opt(23, 59, 59)
+        .ok_or_else(malformed)?
+        .and_utc())
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceRuleError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut by_day = Vec::new();
+        let mut count = None;
+        let mut until = None;
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let Some((key, value)) = part.split_once('=') else {
+                return Err(RecurrenceRuleError::Malformed(part.to_string()));
+            };
+            let malformed = || RecurrenceRuleError::Malformed(part.to_string());
+            match key {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        _ => return Err(RecurrenceRuleError::Unsupported(part.to_string())),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = u32::from_str(value).map_err(|_| malformed())?;
+                    if interval == 0 {
+                        return Err(malformed());
+                    }
+                    if interval > MAX_INTERVAL {
+                        return Err(RecurrenceRuleError::IntervalTooLong);
+                    }
+                }
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_weekday)
+                        .collect::<Result<Vec<_>, _>>()?;
+                }
+                "COUNT" => {
+                    let value = u32::from_str(value).map_err(|_| malformed())?;
+                    if value == 0 {
+                        return Err(RecurrenceRuleError::NoOccurrence);
+                    }
+                    if value > MAX_OCCURRENCES {
+                        return Err(RecurrenceRuleError::TooManyOccurrences);
+                    }
+                    count = Some(value);
+                }
+                "UNTIL" => until = Some(parse_until(value)?),
+                "WKST" if value == "MO" => {}
+                _ => return Err(RecurrenceRuleError::Unsupported(part.to_string())),
+            }
+        }
+
+        let frequency = frequency.ok_or(RecurrenceRuleError::MissingFrequency)?;
+        let end = match (count, until) {
+            (Some(count), None) => RecurrenceEnd::Count(count),
+            (None, Some(until)) => RecurrenceEnd::Until(until),
+            (Some(_), Some(_)) => {
+                return Err(RecurrenceRuleError::Malformed(
+                    "COUNT and UNTIL are exclusive".to_string(),
+                ))
+            }
+            (None, None) => return Err(RecurrenceRuleError::Unbounded),
+        };
+
+        by_day.sort_by_key(Weekday::num_days_from_monday);
+        by_day.dedup();
+
+        Ok(RecurrenceRule {
+            frequency,
+            interval,
+            by_day,
+            end,
+        })
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+        };
+        write!(f, "FREQ={frequency}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<_> = self.by_day.iter().copied().map(format_weekday).collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        match self.end {
+            RecurrenceEnd::Count(count) => write!(f, ";COUNT={count}"),
+            RecurrenceEnd::Until(until) => write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ")),
+        }
+    }
+}
+
+/// The instant of a local time in the timezone, the earliest one when the time is repeated and
+/// moved forward by the change of offset when it is skipped
+fn local_to_utc(local: NaiveDateTime, timezone: Tz) -> DateTime<Utc> {
+    match timezone.from_local_datetime(&local) {
+        LocalResult::Single(date) | LocalResult::Ambiguous(date, _) => date.with_timezone(&Utc),
+        LocalResult::None => timezone
+            .from_local_datetime(&(local - TimeDelta::hours(1)))
+            .earliest()
+            .map(|date| date.with_timezone(&Utc) + TimeDelta::hours(1))
+            .unwrap_or_else(|| local.and_utc()),
+    }
+}
+
+impl RecurrenceRule {
+    /// Start dates of the series beginning at `start`, the days being those of `timezone`
+    ///
+    /// `start` is the first occurrence only when it matches the rule, as with `BYDAY`, otherwise
+    /// the series begins at the next matching day.
+    pub fn occurrences(
+        &self,
+        start: DateTime<Utc>,
+        timezone: Tz,
+    ) -> Result<Vec<DateTime<Utc>>, RecurrenceRuleError> {
+        let start = start.with_timezone(&timezone).naive_local();
+        // Each period begins on `start` for daily rules and on the monday of its week for weekly
+        // ones, the offsets are the days of the period on which the series happens
+        let (period_start, period_days, offsets) = match self.frequency {
+            Frequency::Daily => (start, 1, vec![0]),
+            Frequency::Weekly => {
+                let monday = start - Days::new(u64::from(start.weekday().num_days_from_monday()));
+                let offsets = if self.by_day.is_empty() {
+                    vec![start.weekday().num_days_from_monday()]
+                } else {
+                    self.by_day
+                        .iter()
+                        .map(Weekday::num_days_from_monday)
+                        .collect()
+                };
+                (monday, 7, offsets)
+            }
+        };
+
+        let mut occurrences = Vec::new();
+        let mut period = 0_u64;
+        loop {
+            // A daily rule whose BYDAY never falls on its interval would never end
+            if occurrences.is_empty() && period > 7 {
+                return Err(RecurrenceRuleError::NoOccurrence);
+            }
+            for offset in &offsets {
+                let date = period_start
+                    .checked_add_days(Days::new(
+                        period * period_days * u64::from(self.interval) + u64::from(*offset),
+                    ))
+                    .ok_or(RecurrenceRuleError::OutOfRange)?;
+                if date < start
+                    || (self.frequency == Frequency::Daily
+                        && !self.by_day.is_empty()
+                        && !self.by_day.contains(&date.weekday()))
+                {
+                    continue;
+                }
+                let date = local_to_utc(date, timezone);
+                let done = match self.end {
+                    RecurrenceEnd::Count(count) => occurrences.len() >= count as usize,
+                    RecurrenceEnd::Until(until) => date > until,
+                };
+                if done {
+                    return match occurrences.is_empty() {
+                        true => Err(RecurrenceRuleError::NoOccurrence),
+                        false => Ok(occurrences),
+                    };
+                }
+                if occurrences.len() >= MAX_OCCURRENCES as usize {
+                    return Err(RecurrenceRuleError::TooManyOccurrences);
+                }
+                occurrences.push(date);
+            }
+            period += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RecurrenceRule, RecurrenceRuleError, MAX_INTERVAL, MAX_OCCURRENCES};
+    use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+    use chrono_tz::{Europe::Paris, UTC};
+    use std::str::FromStr;
+
+    fn monday() -> DateTime<Utc> {
+        // 2025-03-03 is a monday
+        Utc.with_ymd_and_hms(2025, 3, 3, 9, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_weekly_by_day() {
+        let rule = RecurrenceRule::from_str("RRULE:FREQ=WEEKLY;BYDAY=WE,MO;COUNT=4").unwrap();
+        let occurrences = rule.occurrences(monday(), UTC).unwrap();
+        let days: Vec<_> = occurrences
+            .iter()
+            .map(|date| (date.day(), date.weekday()))
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                (3, Weekday::Mon),
+                (5, Weekday::Wed),
+                (10, Weekday::Mon),
+                (12, Weekday::Wed)
+            ]
+        );
+        assert!(occurrences
+            .iter()
+            .all(|date| date.time() == monday().time()));
+    }
+
+    #[test]
+    fn test_weekly_start_not_matching() {
+        let rule = RecurrenceRule::from_str("FREQ=WEEKLY;BYDAY=MO,FR;COUNT=3").unwrap();
+        let start = monday() + chrono::Days::new(2);
+        let days: Vec<_> = rule
+            .occurrences(start, UTC)
+            .unwrap()
+            .iter()
+            .map(|date| date.day())
+            .collect();
+        assert_eq!(days, vec![7, 10, 14]);
+    }
+
+    #[test]
+    fn test_interval_until() {
+        let rule = RecurrenceRule::from_str("FREQ=WEEKLY;INTERVAL=2;UNTIL=20250331").unwrap();
+        let days: Vec<_> = rule
+            .occurrences(monday(), UTC)
+            .unwrap()
+            .iter()
+            .map(|date| date.day())
+            .collect();
+        assert_eq!(days, vec![3, 17, 31]);
+    }
+
+    #[test]
+    fn test_interval_weeks_start_on_monday() {
+        let rule = RecurrenceRule::from_str("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=3").unwrap();
+        let start = monday() + chrono::Days::new(2);
+        let days: Vec<_> = rule
+            .occurrences(start, UTC)
+            .unwrap()
+            .iter()
+            .map(|date| date.day())
+            .collect();
+        assert_eq!(days, vec![7, 17, 21]);
+    }
+
+    #[test]
+    fn test_daily_by_day() {
+        let rule = RecurrenceRule::from_str("FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;COUNT=6").unwrap();
+        let occurrences = rule.occurrences(monday(), UTC).unwrap();
+        assert_eq!(occurrences.len(), 6);
+        assert_eq!(occurrences.last().unwrap().day(), 10);
+    }
+
+    #[test]
+    fn test_too_many_occurrences() {
+        assert_eq!(
+            RecurrenceRule::from_str(&format!("FREQ=DAILY;COUNT={}", MAX_OCCURRENCES + 1)),
+            Err(RecurrenceRuleError::TooManyOccurrences)
+        );
+        let rule = RecurrenceRule::from_str("FREQ=DAILY;UNTIL=20300101T000000Z").unwrap();
+        assert_eq!(
+            rule.occurrences(monday(), UTC),
+            Err(RecurrenceRuleError::TooManyOccurrences)
+        );
+    }
+
+    #[test]
+    fn test_interval_out_of_range() {
+        assert_eq!(
+            RecurrenceRule::from_str("FREQ=DAILY;INTERVAL=4294967295;COUNT=2"),
+            Err(RecurrenceRuleError::IntervalTooLong)
+        );
+        let rule =
+            RecurrenceRule::from_str(&format!("FREQ=WEEKLY;INTERVAL={MAX_INTERVAL};COUNT=2"))
+                .unwrap();
+        let last_day = NaiveDate::MAX.and_hms_opt(9, 30, 0).unwrap().and_utc();
+        assert_eq!(
+            rule.occurrences(last_day - chrono::TimeDelta::days(30), UTC),
+            Err(RecurrenceRuleError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(
+            RecurrenceRule::from_str("FREQ=WEEKLY"),
+            Err(RecurrenceRuleError::Unbounded)
+        );
+        assert_eq!(
+            RecurrenceRule::from_str("COUNT=2"),
+            Err(RecurrenceRuleError::MissingFrequency)
+        );
+        assert!(matches!(
+            RecurrenceRule::from_str("FREQ=MONTHLY;COUNT=2"),
+            Err(RecurrenceRuleError::Unsupported(_))
+        ));
+        assert!(matches!(
+            RecurrenceRule::from_str("FREQ=WEEKLY;BYDAY=1MO;COUNT=2"),
+            Err(RecurrenceRuleError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for raw in [
+            "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=10",
+            "FREQ=DAILY;INTERVAL=3;UNTIL=20250401T120000Z",
+        ] {
+            let rule = RecurrenceRule::from_str(raw).unwrap();
+            assert_eq!(rule.to_string(), raw);
+        }
+    }
+
+    #[test]
+    fn test_local_time_across_dst() {
+        // Paris switches to summer time on 2025-03-30, 09:00 is 08:00 UTC before and 07:00 after
+        let start = Utc.with_ymd_and_hms(2025, 3, 24, 8, 0, 0).unwrap();
+        let rule = RecurrenceRule::from_str("FREQ=WEEKLY;COUNT=3").unwrap();
+        let occurrences = rule.occurrences(start, Paris).unwrap();
+        let hours: Vec<_> = occurrences.iter().map(|date| date.hour()).collect();
+        assert_eq!(hours, vec![8, 7, 7]);
+        assert!(occurrences
+            .iter()
+            .all(|date| date.with_timezone(&Paris).hour() == 9));
+    }
+
+    #[test]
+    fn test_by_day_in_local_days() {
+        // 00:30 on tuesday in Paris is still monday in UTC
+        let start = Utc.with_ymd_and_hms(2025, 3, 3, 23, 30, 0).unwrap();
+        let rule = RecurrenceRule::from_str("FREQ=WEEKLY;BYDAY=TU;COUNT=3").unwrap();
+        let occurrences = rule.occurrences(start, Paris).unwrap();
+        assert_eq!(occurrences.first(), Some(&start));
+        assert!(occurrences
+            .iter()
+            .all(|date| date.with_timezone(&Paris).weekday() == Weekday::Tue));
+    }
+}
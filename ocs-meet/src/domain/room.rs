@@ -1,4 +1,13 @@
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_more::AsRef;
+use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
+
+use super::{
+    meeting::{Meeting, MeetingId},
+    member::MemberRole,
+    studio::StudioId,
+};
 
 #[derive(Debug, AsRef)]
 pub struct RoomToken(String);
@@ -8,3 +17,195 @@ impl From<String> for RoomToken {
         RoomToken(value)
     }
 }
+
+/// Who enters a room, `identity` stays the same across their visits and `name` is shown to the
+/// other participants
+#[derive(Debug, PartialEq, Clone)]
+pub struct Participant {
+    pub identity: String,
+    pub name: String,
+}
+
+/// Settings of the room of a meeting, created ahead of its participants
+#[derive(Debug, PartialEq, Clone)]
+pub struct RoomSettings {
+    /// Participants the room holds at once, without limit when `None`
+    pub max_participants: Option<u32>,
+    /// How long the room stays open while nobody is in it
+    pub empty_timeout: TimeDelta,
+    pub meeting_name: String,
+    pub studio_id: StudioId,
+}
+
+impl RoomSettings {
+    /// The room waits for its participants until the join window of the meeting closes
+    pub fn for_meeting(meeting: &Meeting, now: DateTime<Utc>) -> Self {
+        RoomSettings {
+            max_participants: meeting.max_participants,
+            empty_timeout: (meeting.join_closes_at() - now).max(TimeDelta::zero()),
+            meeting_name: meeting.name.as_ref().to_string(),
+            studio_id: meeting.studio_id.clone(),
+        }
+    }
+}
+
+/// Someone connected to the room of a meeting right now
+#[derive(Debug, PartialEq, Clone)]
+pub struct RoomParticipant {
+    pub identity: String,
+    pub name: String,
+    pub joined_at: DateTime<Utc>,
+    /// Whether they are sharing their camera, microphone or screen
+    pub is_publishing: bool,
+}
+
+/// Kind of media a participant publishes in the room
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TrackSource {
+    Microphone,
+    Camera,
+    /// The screen shared, along with its sound
+    ScreenShare,
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown track source {0}")]
+pub struct UnknownTrackSource(String);
+
+impl FromStr for TrackSource {
+    type Err = UnknownTrackSource;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "microphone" => Ok(TrackSource::Microphone),
+            "camera" => Ok(TrackSource::Camera),
+            "screen_share" => Ok(TrackSource::ScreenShare),
+            _ => Err(UnknownTrackSource(s.to_string())),
+        }
+    }
+}
+
+impl Display for TrackSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrackSource::Microphone => write!(f, "microphone"),
+            TrackSource::Camera => write!(f, "camera"),
+            TrackSource::ScreenShare => write!(f, "screen_share"),
+        }
+    }
+}
+
+/// Permissions of a participant to change while they are in the room, the others are kept
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct PermissionChanges {
+    /// Whether they can share their camera, microphone or screen
+    pub can_publish: Option<bool>,
+    /// Whether they can chat
+    pub can_publish_data: Option<bool>,
+}
+
+/// What a participant can do once in the room
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParticipantRole {
+    /// Runs the meeting, including the other participants
+    Host,
+    /// Takes part with their camera and microphone
+    Speaker,
+    /// Only watches and chats
+    Viewer,
+    /// Records the meeting without showing up in it
+    RecorderBot,
+}
+
+impl ParticipantRole {
+    /// Whether only the members allowed to manage the meetings may hand the role out
+    pub fn is_privileged(&self) -> bool {
+        matches!(self, ParticipantRole::Host | ParticipantRole::RecorderBot)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown participant role {0}")]
+pub struct UnknownParticipantRole(String);
+
+impl FromStr for ParticipantRole {
+    type Err = UnknownParticipantRole;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "host" => Ok(ParticipantRole::Host),
+            "speaker" => Ok(ParticipantRole::Speaker),
+            "viewer" => Ok(ParticipantRole::Viewer),
+            "recorder_bot" => Ok(ParticipantRole::RecorderBot),
+            _ => Err(UnknownParticipantRole(s.to_string())),
+        }
+    }
+}
+
+impl Display for ParticipantRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipantRole::Host => write!(f, "host"),
+            ParticipantRole::Speaker => write!(f, "speaker"),
+            ParticipantRole::Viewer => write!(f, "viewer"),
+            ParticipantRole::RecorderBot => write!(f, "recorder_bot"),
+        }
+    }
+}
+
+impl From<MemberRole> for ParticipantRole {
+    fn from(role: MemberRole) -> Self {
+        match role {
+            MemberRole::Owner | MemberRole::Admin | MemberRole::Member => ParticipantRole::Host,
+            MemberRole::Viewer => ParticipantRole::Viewer,
+        }
+    }
+}
+
+/// What LiveKit reports about the room of a meeting
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RoomEventKind {
+    RoomStarted,
+    RoomFinished,
+    ParticipantJoined,
+    ParticipantLeft,
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown room event {0}")]
+pub struct UnknownRoomEvent(String);
+
+impl FromStr for RoomEventKind {
+    type Err = UnknownRoomEvent;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "room_started" => Ok(RoomEventKind::RoomStarted),
+            "room_finished" => Ok(RoomEventKind::RoomFinished),
+            "participant_joined" => Ok(RoomEventKind::ParticipantJoined),
+            "participant_left" => Ok(RoomEventKind::ParticipantLeft),
+            _ => Err(UnknownRoomEvent(s.to_string())),
+        }
+    }
+}
+
+impl Display for RoomEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoomEventKind::RoomStarted => write!(f, "room_started"),
+            RoomEventKind::RoomFinished => write!(f, "room_finished"),
+            RoomEventKind::ParticipantJoined => write!(f, "participant_joined"),
+            RoomEventKind::ParticipantLeft => write!(f, "participant_left"),
+        }
+    }
+}
+
+/// Something which happened in the room of a meeting, `id` is kept by LiveKit across the
+/// deliveries of the event
+#[derive(Debug, PartialEq, Clone)]
+pub struct RoomEvent {
+    pub id: String,
+    pub meeting_id: MeetingId,
+    pub kind: RoomEventKind,
+    pub occurred_at: DateTime<Utc>,
+}
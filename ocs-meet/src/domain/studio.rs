@@ -1,5 +1,10 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use derive_more::AsRef;
 use uuid::Uuid;
+use validator::{Validate, ValidationErrors};
+
+use super::meeting::MeetingDuration;
 
 #[derive(AsRef, Debug, PartialEq, Clone)]
 pub struct StudioId(pub uuid::Uuid);
@@ -9,3 +14,37 @@ impl From<Uuid> for StudioId {
         StudioId(uuid)
     }
 }
+
+#[derive(Validate, AsRef, Debug, PartialEq, Clone)]
+pub struct StudioName {
+    #[validate(length(min = 1, max = 100, message = "Name must have 1 to 100 characters"))]
+    inner: String,
+}
+
+impl TryFrom<String> for StudioName {
+    type Error = ValidationErrors;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let name = StudioName { inner: value };
+        let () = name.validate()?;
+        Ok(name)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Studio {
+    pub id: StudioId,
+    pub name: StudioName,
+    /// Where the studio is, to present the dates of its meetings
+    pub timezone: Tz,
+    /// Duration of the meetings created without one
+    pub default_duration: MeetingDuration,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Studio {
+    /// Duration of a meeting of the studio, its default one when none is given
+    pub fn meeting_duration(&self, duration: Option<MeetingDuration>) -> MeetingDuration {
+        duration.unwrap_or(self.default_duration)
+    }
+}
@@ -0,0 +1,39 @@
+use derive_more::AsRef;
+
+use super::{
+    api_key::{ApiKeyId, ApiKeyScope},
+    studio::StudioId,
+};
+
+/// Identifier of a user given by the identity provider, e.g. the `sub` claim of its tokens
+#[derive(AsRef, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct UserId(String);
+
+impl From<String> for UserId {
+    fn from(id: String) -> Self {
+        UserId(id)
+    }
+}
+
+/// Who is calling: the studio the token grants access to, and the user when the token names one
+///
+/// Tokens naming no user are issued by the backend of the studio itself, they act as its owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identity {
+    pub studio_id: StudioId,
+    pub user_id: Option<UserId>,
+    /// Real name of the user given by the token, shown to the other participants of the meetings
+    pub display_name: Option<String>,
+    /// Scopes of the API key used, `None` for the tokens of users which are not restricted
+    pub scopes: Option<Vec<ApiKeyScope>>,
+    /// API key used, `None` for the signed tokens
+    pub api_key_id: Option<ApiKeyId>,
+}
+
+impl Identity {
+    pub fn allows(&self, scope: ApiKeyScope) -> bool {
+        self.scopes
+            .as_ref()
+            .is_none_or(|scopes| scopes.contains(&scope))
+    }
+}
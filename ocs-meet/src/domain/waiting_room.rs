@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use derive_more::AsRef;
+use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
+
+use super::{
+    meeting::MeetingId,
+    room::{Participant, ParticipantRole, RoomToken},
+    studio::StudioId,
+};
+
+#[derive(AsRef, Debug, PartialEq, Clone)]
+pub struct WaitingTicketId(uuid::Uuid);
+
+impl WaitingTicketId {
+    pub fn new() -> Self {
+        WaitingTicketId(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for WaitingTicketId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<uuid::Uuid> for WaitingTicketId {
+    fn from(uuid: uuid::Uuid) -> Self {
+        WaitingTicketId(uuid)
+    }
+}
+
+/// Answer of the hosts to a participant in the waiting room
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AdmissionStatus {
+    Pending,
+    Admitted,
+    Denied,
+}
+
+#[derive(Debug, Error)]
+#[error("Unknown admission status {0}")]
+pub struct UnknownAdmissionStatus(String);
+
+impl FromStr for AdmissionStatus {
+    type Err = UnknownAdmissionStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(AdmissionStatus::Pending),
+            "admitted" => Ok(AdmissionStatus::Admitted),
+            "denied" => Ok(AdmissionStatus::Denied),
+            _ => Err(UnknownAdmissionStatus(s.to_string())),
+        }
+    }
+}
+
+impl Display for AdmissionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdmissionStatus::Pending => write!(f, "pending"),
+            AdmissionStatus::Admitted => write!(f, "admitted"),
+            AdmissionStatus::Denied => write!(f, "denied"),
+        }
+    }
+}
+
+/// Place of a participant in the waiting room of a meeting, its id is what they poll with
+#[derive(Debug, PartialEq, Clone)]
+pub struct WaitingTicket {
+    pub id: WaitingTicketId,
+    pub meeting_id: MeetingId,
+    pub studio_id: StudioId,
+    pub participant: Participant,
+    /// Role the participant enters with once admitted
+    pub role: ParticipantRole,
+    pub status: AdmissionStatus,
+    pub created_at: DateTime<Utc>,
+    pub decided_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of asking to join a meeting
+#[derive(Debug)]
+pub enum Admission {
+    /// The participant can enter the room right away
+    Granted(RoomToken),
+    /// The participant waits for a host to let them in
+    Waiting(WaitingTicket),
+}
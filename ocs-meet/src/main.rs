@@ -21,7 +21,11 @@ async fn main(
         .await
         .context("Cannot instanciate the repository")?;
 
-    let room_manager = Livekit::new(config.livekit_api_key(), config.livekit_secret());
+    let room_manager = Livekit::new(
+        config.livekit_url(),
+        config.livekit_api_key(),
+        config.livekit_secret(),
+    );
 
     let app = app::app(config, repository, room_manager).await?;
 
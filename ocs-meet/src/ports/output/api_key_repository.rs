@@ -0,0 +1,41 @@
+use crate::domain::{
+    api_key::{ApiKey, ApiKeyId, ApiKeyTokenHash, UnknownApiKeyScope},
+    studio::StudioId,
+};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ApiKeyRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    #[error(transparent)]
+    Scope(#[from] UnknownApiKeyScope),
+}
+
+#[automock]
+pub trait ApiKeyRepository {
+    fn create_api_key(
+        &self,
+        api_key: &ApiKey,
+    ) -> impl Future<Output = Result<(), ApiKeyRepositoryError>> + Send;
+
+    /// Keys of the studio, the most recent first
+    fn list_api_keys(
+        &self,
+        studio_id: &StudioId,
+    ) -> impl Future<Output = Result<Vec<ApiKey>, ApiKeyRepositoryError>> + Send;
+
+    fn find_api_key_by_hash(
+        &self,
+        token_hash: &ApiKeyTokenHash,
+    ) -> impl Future<Output = Result<Option<ApiKey>, ApiKeyRepositoryError>> + Send;
+
+    /// Deletes the key if it belongs to the studio, returns whether there was one
+    fn delete_api_key(
+        &self,
+        studio_id: &StudioId,
+        api_key_id: &ApiKeyId,
+    ) -> impl Future<Output = Result<bool, ApiKeyRepositoryError>> + Send;
+}
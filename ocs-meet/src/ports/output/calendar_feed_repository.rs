@@ -0,0 +1,29 @@
+use crate::domain::{calendar_feed::CalendarFeed, studio::StudioId};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CalendarFeedRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+}
+
+#[automock]
+pub trait CalendarFeedRepository {
+    /// Saves the feed of the studio, replacing the previous one and so revoking its token
+    fn save_calendar_feed(
+        &self,
+        feed: &CalendarFeed,
+    ) -> impl Future<Output = Result<(), CalendarFeedRepositoryError>> + Send;
+
+    fn find_calendar_feed(
+        &self,
+        studio_id: &StudioId,
+    ) -> impl Future<Output = Result<Option<CalendarFeed>, CalendarFeedRepositoryError>> + Send;
+
+    fn delete_calendar_feed(
+        &self,
+        studio_id: &StudioId,
+    ) -> impl Future<Output = Result<(), CalendarFeedRepositoryError>> + Send;
+}
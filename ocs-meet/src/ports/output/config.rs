@@ -1,8 +1,36 @@
 use mockall::automock;
 
+/// Key checking the signature of the bearer tokens
+#[derive(Debug, Clone)]
+pub enum JwtKey {
+    /// Shared secret of HS256 tokens
+    Hs256(String),
+    /// PEM encoded public key of RS256 tokens
+    Rs256(String),
+}
+
+/// OpenID Connect provider whose tokens are accepted, its keys are discovered from the issuer
+#[derive(Debug, Clone)]
+pub struct OidcSettings {
+    /// Issuer as written in the `iss` claim, the discovery document lives below it
+    pub issuer: String,
+    /// Expected `aud` claim
+    pub audience: String,
+    /// Claim holding the id of the studio
+    pub studio_claim: String,
+    /// Claim identifying the user
+    pub user_claim: String,
+}
+
 #[automock]
 pub trait Config {
     fn swagger_uri(&self) -> url::Url;
+    /// Address of the LiveKit server, for its RoomService API
+    fn livekit_url(&self) -> String;
     fn livekit_api_key(&self) -> String;
     fn livekit_secret(&self) -> String;
+    fn jwt_key(&self) -> Option<JwtKey>;
+    fn oidc(&self) -> Option<OidcSettings>;
+    /// Secret signing the invitation links of the guests
+    fn invitation_secret(&self) -> String;
 }
@@ -0,0 +1,46 @@
+use crate::domain::{
+    invitation::{Invitation, InvitationId},
+    room::UnknownParticipantRole,
+    studio::StudioId,
+};
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum InvitationRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    #[error(transparent)]
+    Role(#[from] UnknownParticipantRole),
+}
+
+#[automock]
+pub trait InvitationRepository {
+    fn create_invitation(
+        &self,
+        invitation: &Invitation,
+    ) -> impl Future<Output = Result<(), InvitationRepositoryError>> + Send;
+
+    fn find_invitation(
+        &self,
+        invitation_id: &InvitationId,
+    ) -> impl Future<Output = Result<Option<Invitation>, InvitationRepositoryError>> + Send;
+
+    /// Revokes the invitation if it belongs to the studio, returns whether there was one
+    fn revoke_invitation(
+        &self,
+        studio_id: &StudioId,
+        invitation_id: &InvitationId,
+        revoked_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<bool, InvitationRepositoryError>> + Send;
+
+    /// Counts a use of the invitation unless it is revoked, expired or exhausted by then, returns
+    /// the number of uses including this one, `None` when it was not counted
+    fn use_invitation(
+        &self,
+        invitation_id: &InvitationId,
+        used_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Option<u32>, InvitationRepositoryError>> + Send;
+}
@@ -1,7 +1,9 @@
 use crate::domain::{
-    meeting::{Meeting, MeetingId},
+    meeting::{Meeting, MeetingId, MeetingSeries, MeetingSeriesId, UnknownMeetingStatus},
+    recurrence::RecurrenceRuleError,
     studio::StudioId,
 };
+use chrono::{DateTime, Utc};
 use mockall::automock;
 use std::future::Future;
 use thiserror::Error;
@@ -15,6 +17,73 @@ pub enum MeetingRepositoryError {
     SqlxError(#[from] sqlx::Error),
     #[error(transparent)]
     Validation(#[from] ValidationErrors),
+    #[error(transparent)]
+    Status(#[from] UnknownMeetingStatus),
+    #[error(transparent)]
+    Recurrence(#[from] RecurrenceRuleError),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum MeetingOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Position of a meeting in the (date, id) ordering, used to resume a listing after it
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeetingCursor {
+    pub date: DateTime<Utc>,
+    pub id: MeetingId,
+}
+
+impl From<&Meeting> for MeetingCursor {
+    fn from(meeting: &Meeting) -> Self {
+        MeetingCursor {
+            date: meeting.date,
+            id: meeting.id.clone(),
+        }
+    }
+}
+
+/// Selection of the meetings of a studio, by start date within `[from, to)`, ordered by date then
+/// id and starting right after `after` when set
+#[derive(Debug, PartialEq, Clone)]
+pub struct MeetingQuery {
+    pub studio_id: StudioId,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub include_cancelled: bool,
+    pub order: MeetingOrder,
+    pub after: Option<MeetingCursor>,
+    pub limit: Option<u32>,
+    /// Restricts the selection to the occurrences of a series
+    pub series_id: Option<MeetingSeriesId>,
+}
+
+impl MeetingQuery {
+    /// Every meeting of the studio, cancelled ones included, oldest first
+    pub fn new(studio_id: StudioId) -> Self {
+        MeetingQuery {
+            studio_id,
+            from: None,
+            to: None,
+            include_cancelled: true,
+            order: MeetingOrder::default(),
+            after: None,
+            limit: None,
+            series_id: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl MeetingQuery {
+    /// Mock predicate accepting the queries on the given studio
+    pub fn on_studio(studio_id: &StudioId) -> impl Fn(&MeetingQuery) -> bool {
+        let studio_id = studio_id.clone();
+        move |query| query.studio_id == studio_id
+    }
 }
 
 #[automock]
@@ -24,13 +93,52 @@ pub trait MeetingRepository {
         meeting: &Meeting,
     ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
 
+    fn update_meeting(
+        &self,
+        meeting: &Meeting,
+    ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+    /// Updates all the meetings or none of them
+    fn update_meetings(
+        &self,
+        meetings: &[Meeting],
+    ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
     fn list_meetings(
         &self,
-        studio_id: &StudioId,
+        query: &MeetingQuery,
     ) -> impl Future<Output = Result<Vec<Meeting>, MeetingRepositoryError>> + Send;
 
     fn find_meeting(
         &self,
         meeting_id: &MeetingId,
     ) -> impl Future<Output = Result<Option<Meeting>, MeetingRepositoryError>> + Send;
+
+    /// Creates the series along with its occurrences, all of them or none
+    fn create_series(
+        &self,
+        series: &MeetingSeries,
+        meetings: &[Meeting],
+    ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+    fn find_series(
+        &self,
+        series_id: &MeetingSeriesId,
+    ) -> impl Future<Output = Result<Option<MeetingSeries>, MeetingRepositoryError>> + Send;
+
+    /// Records a wrong passcode entered by the caller to join the meeting
+    fn record_passcode_failure(
+        &self,
+        meeting_id: &MeetingId,
+        caller: &str,
+        failed_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+    /// Number of wrong passcodes entered by the caller to join the meeting since the date
+    fn count_passcode_failures(
+        &self,
+        meeting_id: &MeetingId,
+        caller: &str,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u32, MeetingRepositoryError>> + Send;
 }
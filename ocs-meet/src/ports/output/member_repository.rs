@@ -0,0 +1,48 @@
+use crate::domain::{
+    member::{Member, UnknownMemberRole},
+    studio::StudioId,
+    user::UserId,
+};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MemberRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    #[error(transparent)]
+    Role(#[from] UnknownMemberRole),
+}
+
+#[automock]
+pub trait MemberRepository {
+    fn add_member(
+        &self,
+        member: &Member,
+    ) -> impl Future<Output = Result<(), MemberRepositoryError>> + Send;
+
+    fn find_member(
+        &self,
+        studio_id: &StudioId,
+        user_id: &UserId,
+    ) -> impl Future<Output = Result<Option<Member>, MemberRepositoryError>> + Send;
+
+    /// Members of the studio, the oldest first
+    fn list_members(
+        &self,
+        studio_id: &StudioId,
+    ) -> impl Future<Output = Result<Vec<Member>, MemberRepositoryError>> + Send;
+
+    fn update_member(
+        &self,
+        member: &Member,
+    ) -> impl Future<Output = Result<(), MemberRepositoryError>> + Send;
+
+    /// Removes the user from the studio, returns whether they were a member
+    fn remove_member(
+        &self,
+        studio_id: &StudioId,
+        user_id: &UserId,
+    ) -> impl Future<Output = Result<bool, MemberRepositoryError>> + Send;
+}
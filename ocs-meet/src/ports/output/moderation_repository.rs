@@ -0,0 +1,28 @@
+use crate::domain::{meeting::MeetingId, moderation::ModerationAction, room::UnknownTrackSource};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ModerationRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    #[error(transparent)]
+    TrackSource(#[from] UnknownTrackSource),
+    #[error("Unknown moderation action {0}")]
+    UnknownAction(String),
+}
+
+#[automock]
+pub trait ModerationRepository {
+    fn record_action(
+        &self,
+        action: &ModerationAction,
+    ) -> impl Future<Output = Result<(), ModerationRepositoryError>> + Send;
+
+    /// Actions taken in the room of the meeting, the oldest first
+    fn list_actions(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> impl Future<Output = Result<Vec<ModerationAction>, ModerationRepositoryError>> + Send;
+}
@@ -0,0 +1,247 @@
+use super::{
+    api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError},
+    calendar_feed_repository::{CalendarFeedRepository, CalendarFeedRepositoryError},
+    invitation_repository::{InvitationRepository, InvitationRepositoryError},
+    meeting_repository::{MeetingQuery, MeetingRepository, MeetingRepositoryError},
+    member_repository::{MemberRepository, MemberRepositoryError},
+    moderation_repository::{ModerationRepository, ModerationRepositoryError},
+    room_event_repository::{RoomEventRepository, RoomEventRepositoryError},
+    studio_repository::{StudioRepository, StudioRepositoryError},
+    waiting_room_repository::{WaitingRoomRepository, WaitingRoomRepositoryError},
+};
+use crate::domain::{
+    api_key::{ApiKey, ApiKeyId, ApiKeyTokenHash},
+    calendar_feed::CalendarFeed,
+    invitation::{Invitation, InvitationId},
+    meeting::{Meeting, MeetingId, MeetingSeries, MeetingSeriesId},
+    member::Member,
+    moderation::ModerationAction,
+    room::RoomEvent,
+    studio::{Studio, StudioId},
+    user::UserId,
+    waiting_room::{AdmissionStatus, WaitingTicket, WaitingTicketId},
+};
+use chrono::{DateTime, Utc};
+use mockall::mock;
+use std::future::Future;
+
+// A single mock implementing all the repositories, for the code needing several of them at once
+mock! {
+    pub Repository {}
+
+    impl MeetingRepository for Repository {
+        fn create_meeting(
+            &self,
+            meeting: &Meeting,
+        ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+        fn update_meeting(
+            &self,
+            meeting: &Meeting,
+        ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+        fn update_meetings(
+            &self,
+            meetings: &[Meeting],
+        ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+        fn list_meetings(
+            &self,
+            query: &MeetingQuery,
+        ) -> impl Future<Output = Result<Vec<Meeting>, MeetingRepositoryError>> + Send;
+
+        fn find_meeting(
+            &self,
+            meeting_id: &MeetingId,
+        ) -> impl Future<Output = Result<Option<Meeting>, MeetingRepositoryError>> + Send;
+
+        fn create_series(
+            &self,
+            series: &MeetingSeries,
+            meetings: &[Meeting],
+        ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+        fn find_series(
+            &self,
+            series_id: &MeetingSeriesId,
+        ) -> impl Future<Output = Result<Option<MeetingSeries>, MeetingRepositoryError>> + Send;
+
+        fn record_passcode_failure(
+            &self,
+            meeting_id: &MeetingId,
+            caller: &str,
+            failed_at: DateTime<Utc>,
+        ) -> impl Future<Output = Result<(), MeetingRepositoryError>> + Send;
+
+        fn count_passcode_failures(
+            &self,
+            meeting_id: &MeetingId,
+            caller: &str,
+            since: DateTime<Utc>,
+        ) -> impl Future<Output = Result<u32, MeetingRepositoryError>> + Send;
+    }
+
+    impl CalendarFeedRepository for Repository {
+        fn save_calendar_feed(
+            &self,
+            feed: &CalendarFeed,
+        ) -> impl Future<Output = Result<(), CalendarFeedRepositoryError>> + Send;
+
+        fn find_calendar_feed(
+            &self,
+            studio_id: &StudioId,
+        ) -> impl Future<Output = Result<Option<CalendarFeed>, CalendarFeedRepositoryError>> + Send;
+
+        fn delete_calendar_feed(
+            &self,
+            studio_id: &StudioId,
+        ) -> impl Future<Output = Result<(), CalendarFeedRepositoryError>> + Send;
+    }
+
+    impl ApiKeyRepository for Repository {
+        fn create_api_key(
+            &self,
+            api_key: &ApiKey,
+        ) -> impl Future<Output = Result<(), ApiKeyRepositoryError>> + Send;
+
+        fn list_api_keys(
+            &self,
+            studio_id: &StudioId,
+        ) -> impl Future<Output = Result<Vec<ApiKey>, ApiKeyRepositoryError>> + Send;
+
+        fn find_api_key_by_hash(
+            &self,
+            token_hash: &ApiKeyTokenHash,
+        ) -> impl Future<Output = Result<Option<ApiKey>, ApiKeyRepositoryError>> + Send;
+
+        fn delete_api_key(
+            &self,
+            studio_id: &StudioId,
+            api_key_id: &ApiKeyId,
+        ) -> impl Future<Output = Result<bool, ApiKeyRepositoryError>> + Send;
+    }
+
+    impl StudioRepository for Repository {
+        fn create_studio(
+            &self,
+            studio: &Studio,
+        ) -> impl Future<Output = Result<(), StudioRepositoryError>> + Send;
+
+        fn update_studio(
+            &self,
+            studio: &Studio,
+        ) -> impl Future<Output = Result<(), StudioRepositoryError>> + Send;
+
+        fn find_studio(
+            &self,
+            studio_id: &StudioId,
+        ) -> impl Future<Output = Result<Option<Studio>, StudioRepositoryError>> + Send;
+
+        fn delete_studio(
+            &self,
+            studio_id: &StudioId,
+        ) -> impl Future<Output = Result<bool, StudioRepositoryError>> + Send;
+    }
+
+    impl MemberRepository for Repository {
+        fn add_member(
+            &self,
+            member: &Member,
+        ) -> impl Future<Output = Result<(), MemberRepositoryError>> + Send;
+
+        fn find_member(
+            &self,
+            studio_id: &StudioId,
+            user_id: &UserId,
+        ) -> impl Future<Output = Result<Option<Member>, MemberRepositoryError>> + Send;
+
+        fn list_members(
+            &self,
+            studio_id: &StudioId,
+        ) -> impl Future<Output = Result<Vec<Member>, MemberRepositoryError>> + Send;
+
+        fn update_member(
+            &self,
+            member: &Member,
+        ) -> impl Future<Output = Result<(), MemberRepositoryError>> + Send;
+
+        fn remove_member(
+            &self,
+            studio_id: &StudioId,
+            user_id: &UserId,
+        ) -> impl Future<Output = Result<bool, MemberRepositoryError>> + Send;
+    }
+
+    impl InvitationRepository for Repository {
+        fn create_invitation(
+            &self,
+            invitation: &Invitation,
+        ) -> impl Future<Output = Result<(), InvitationRepositoryError>> + Send;
+
+        fn find_invitation(
+            &self,
+            invitation_id: &InvitationId,
+        ) -> impl Future<Output = Result<Option<Invitation>, InvitationRepositoryError>> + Send;
+
+        fn revoke_invitation(
+            &self,
+            studio_id: &StudioId,
+            invitation_id: &InvitationId,
+            revoked_at: DateTime<Utc>,
+        ) -> impl Future<Output = Result<bool, InvitationRepositoryError>> + Send;
+
+        fn use_invitation(
+            &self,
+            invitation_id: &InvitationId,
+            used_at: DateTime<Utc>,
+        ) -> impl Future<Output = Result<Option<u32>, InvitationRepositoryError>> + Send;
+    }
+
+    impl WaitingRoomRepository for Repository {
+        fn create_ticket(
+            &self,
+            ticket: &WaitingTicket,
+        ) -> impl Future<Output = Result<(), WaitingRoomRepositoryError>> + Send;
+
+        fn find_ticket(
+            &self,
+            ticket_id: &WaitingTicketId,
+        ) -> impl Future<Output = Result<Option<WaitingTicket>, WaitingRoomRepositoryError>> + Send;
+
+        fn list_tickets(
+            &self,
+            meeting_id: &MeetingId,
+            status: AdmissionStatus,
+        ) -> impl Future<Output = Result<Vec<WaitingTicket>, WaitingRoomRepositoryError>> + Send;
+
+        fn update_ticket(
+            &self,
+            ticket: &WaitingTicket,
+        ) -> impl Future<Output = Result<(), WaitingRoomRepositoryError>> + Send;
+    }
+
+    impl ModerationRepository for Repository {
+        fn record_action(
+            &self,
+            action: &ModerationAction,
+        ) -> impl Future<Output = Result<(), ModerationRepositoryError>> + Send;
+
+        fn list_actions(
+            &self,
+            meeting_id: &MeetingId,
+        ) -> impl Future<Output = Result<Vec<ModerationAction>, ModerationRepositoryError>> + Send;
+    }
+
+    impl RoomEventRepository for Repository {
+        fn has_event(
+            &self,
+            event_id: &str,
+        ) -> impl Future<Output = Result<bool, RoomEventRepositoryError>> + Send;
+
+        fn record_event(
+            &self,
+            event: &RoomEvent,
+            received_at: DateTime<Utc>,
+        ) -> impl Future<Output = Result<(), RoomEventRepositoryError>> + Send;
+    }
+}
@@ -0,0 +1,27 @@
+use crate::domain::room::RoomEvent;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RoomEventRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+}
+
+#[automock]
+pub trait RoomEventRepository {
+    /// Whether the event was already handled, LiveKit delivering it again when unsure we got it
+    fn has_event(
+        &self,
+        event_id: &str,
+    ) -> impl Future<Output = Result<bool, RoomEventRepositoryError>> + Send;
+
+    /// Keeps the event once handled, recording it twice is a no-op
+    fn record_event(
+        &self,
+        event: &RoomEvent,
+        received_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), RoomEventRepositoryError>> + Send;
+}
@@ -1,21 +1,78 @@
 use std::future::Future;
 
-use livekit_api::access_token::AccessTokenError;
+use livekit_api::{access_token::AccessTokenError, services::ServiceError};
 use mockall::automock;
 use thiserror::Error;
 
-use crate::domain::{meeting::MeetingId, room::RoomToken};
+use crate::domain::{
+    meeting::MeetingId,
+    room::{
+        Participant, ParticipantRole, PermissionChanges, RoomParticipant, RoomSettings, RoomToken,
+        TrackSource,
+    },
+};
 
 #[derive(Debug, Error)]
 pub enum RoomManagerError {
     #[error(transparent)]
     LiveKitAccessToken(#[from] AccessTokenError),
+    #[error(transparent)]
+    LiveKitService(#[from] ServiceError),
+    #[error("The participant is not in the room")]
+    ParticipantNotFound,
+    #[error("The participant is not publishing this track")]
+    TrackNotFound,
 }
 
 #[automock]
 pub trait RoomManager {
+    /// Opens the room of the meeting with its settings, before anyone joins
+    fn create_room(
+        &self,
+        meeting_id: &MeetingId,
+        settings: RoomSettings,
+    ) -> impl Future<Output = Result<(), RoomManagerError>> + Send;
+
     fn create_token(
         &self,
         meeting_id: MeetingId,
+        participant: Participant,
+        role: ParticipantRole,
     ) -> impl Future<Output = Result<RoomToken, RoomManagerError>> + Send;
+
+    /// Participants in the room of the meeting, none when the room is not open
+    fn list_participants(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> impl Future<Output = Result<Vec<RoomParticipant>, RoomManagerError>> + Send;
+
+    /// Disconnects the participant from the room of the meeting, they can come back with a new
+    /// token
+    fn remove_participant(
+        &self,
+        meeting_id: &MeetingId,
+        identity: &str,
+    ) -> impl Future<Output = Result<(), RoomManagerError>> + Send;
+
+    /// Mutes the tracks of the source published by the participant, they can unmute it as long
+    /// as they are allowed to publish
+    fn mute_track(
+        &self,
+        meeting_id: &MeetingId,
+        identity: &str,
+        source: TrackSource,
+    ) -> impl Future<Output = Result<(), RoomManagerError>> + Send;
+
+    fn update_permissions(
+        &self,
+        meeting_id: &MeetingId,
+        identity: &str,
+        changes: PermissionChanges,
+    ) -> impl Future<Output = Result<(), RoomManagerError>> + Send;
+
+    /// Closes the room of the meeting, disconnecting everyone in it
+    fn delete_room(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> impl Future<Output = Result<(), RoomManagerError>> + Send;
 }
@@ -0,0 +1,36 @@
+use crate::domain::studio::{Studio, StudioId};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StudioRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    #[error("Invalid studio stored: {0}")]
+    InvalidStudio(String),
+}
+
+#[automock]
+pub trait StudioRepository {
+    fn create_studio(
+        &self,
+        studio: &Studio,
+    ) -> impl Future<Output = Result<(), StudioRepositoryError>> + Send;
+
+    fn update_studio(
+        &self,
+        studio: &Studio,
+    ) -> impl Future<Output = Result<(), StudioRepositoryError>> + Send;
+
+    fn find_studio(
+        &self,
+        studio_id: &StudioId,
+    ) -> impl Future<Output = Result<Option<Studio>, StudioRepositoryError>> + Send;
+
+    /// Deletes the studio, returns whether there was one
+    fn delete_studio(
+        &self,
+        studio_id: &StudioId,
+    ) -> impl Future<Output = Result<bool, StudioRepositoryError>> + Send;
+}
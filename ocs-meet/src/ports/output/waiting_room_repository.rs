@@ -0,0 +1,43 @@
+use crate::domain::{
+    meeting::MeetingId,
+    room::UnknownParticipantRole,
+    waiting_room::{AdmissionStatus, UnknownAdmissionStatus, WaitingTicket, WaitingTicketId},
+};
+use mockall::automock;
+use std::future::Future;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WaitingRoomRepositoryError {
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    #[error(transparent)]
+    Role(#[from] UnknownParticipantRole),
+    #[error(transparent)]
+    Status(#[from] UnknownAdmissionStatus),
+}
+
+#[automock]
+pub trait WaitingRoomRepository {
+    fn create_ticket(
+        &self,
+        ticket: &WaitingTicket,
+    ) -> impl Future<Output = Result<(), WaitingRoomRepositoryError>> + Send;
+
+    fn find_ticket(
+        &self,
+        ticket_id: &WaitingTicketId,
+    ) -> impl Future<Output = Result<Option<WaitingTicket>, WaitingRoomRepositoryError>> + Send;
+
+    /// Tickets of the meeting with the status, the oldest first
+    fn list_tickets(
+        &self,
+        meeting_id: &MeetingId,
+        status: AdmissionStatus,
+    ) -> impl Future<Output = Result<Vec<WaitingTicket>, WaitingRoomRepositoryError>> + Send;
+
+    fn update_ticket(
+        &self,
+        ticket: &WaitingTicket,
+    ) -> impl Future<Output = Result<(), WaitingRoomRepositoryError>> + Send;
+}
@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        member::{Access, Member, MemberRole},
+        user::UserId,
+    },
+    ports::output::{
+        member_repository::{MemberRepository, MemberRepositoryError},
+        studio_repository::{StudioRepository, StudioRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum AddMemberError {
+    #[error("The studio has not been created")]
+    StudioNotFound,
+    #[error("The user is already a member of the studio")]
+    AlreadyMember,
+    #[error("Only the owners can make other owners")]
+    Forbidden,
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+    #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+}
+
+/// Adds the user to the studio of the caller with the given role
+pub async fn add_member(
+    repo: &(impl StudioRepository + MemberRepository),
+    access: &Access,
+    user_id: UserId,
+    display_name: Option<String>,
+    role: MemberRole,
+    today: DateTime<Utc>,
+) -> Result<Member, AddMemberError> {
+    if role == MemberRole::Owner && access.role != MemberRole::Owner {
+        return Err(AddMemberError::Forbidden);
+    }
+
+    if repo
+        .find_studio(&access.studio_id)
+        .await
+        .map_err(AddMemberError::from)?
+        .is_none()
+    {
+        return Err(AddMemberError::StudioNotFound);
+    }
+
+    if repo
+        .find_member(&access.studio_id, &user_id)
+        .await
+        .map_err(AddMemberError::from)?
+        .is_some()
+    {
+        return Err(AddMemberError::AlreadyMember);
+    }
+
+    let member = Member {
+        studio_id: access.studio_id.clone(),
+        user_id,
+        display_name,
+        role,
+        created_at: today,
+    };
+
+    repo.add_member(&member)
+        .await
+        .map_err(AddMemberError::from)?;
+
+    Ok(member)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::{
+        domain::{
+            member::{Access, MemberRole},
+            studio::StudioId,
+            user::UserId,
+        },
+        ports::output::repository::MockRepository,
+        services::{add_member, create_studio::tests::studio, AddMemberError},
+    };
+
+    #[tokio::test]
+    async fn test_add_member_ok() {
+        let access = Access {
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            role: MemberRole::Admin,
+        };
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .returning(|studio_id| {
+                let studio = studio(studio_id);
+                Box::pin(async move { Ok(Some(studio)) })
+            });
+        mock_repo
+            .expect_find_member()
+            .once()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        mock_repo
+            .expect_add_member()
+            .once()
+            .withf(|member| member.role == MemberRole::Member)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let member = add_member(
+            &mock_repo,
+            &access,
+            UserId::from("bob".to_string()),
+            Some("Bob".to_string()),
+            MemberRole::Member,
+            Utc::now(),
+        )
+        .await
+        .expect("The member should be added");
+        assert_eq!(member.studio_id, access.studio_id);
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_add_owner() {
+        let access = Access {
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            role: MemberRole::Admin,
+        };
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo.expect_add_member().never();
+
+        let Err(AddMemberError::Forbidden) = add_member(
+            &mock_repo,
+            &access,
+            UserId::from("bob".to_string()),
+            None,
+            MemberRole::Owner,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("Admins should not make owners")
+        };
+    }
+}
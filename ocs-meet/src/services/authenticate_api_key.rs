@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::api_key::{ApiKey, ApiKeyToken},
+    ports::output::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum AuthenticateApiKeyError {
+    #[error("Unknown or revoked API key")]
+    Unknown,
+    #[error("The API key has expired")]
+    Expired,
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+}
+
+/// The key matching the token, as long as it has not been revoked and has not expired
+pub async fn authenticate_api_key(
+    repo: &impl ApiKeyRepository,
+    token: &ApiKeyToken,
+    now: DateTime<Utc>,
+) -> Result<ApiKey, AuthenticateApiKeyError> {
+    let Some(api_key) = repo
+        .find_api_key_by_hash(&token.hash())
+        .await
+        .map_err(AuthenticateApiKeyError::from)?
+    else {
+        return Err(AuthenticateApiKeyError::Unknown);
+    };
+
+    if api_key.is_expired(now) {
+        return Err(AuthenticateApiKeyError::Expired);
+    }
+
+    Ok(api_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            api_key::{ApiKey, ApiKeyId, ApiKeyScope, ApiKeyToken},
+            studio::StudioId,
+        },
+        ports::output::api_key_repository::MockApiKeyRepository,
+        services::{authenticate_api_key, AuthenticateApiKeyError},
+    };
+
+    #[tokio::test]
+    async fn test_expiry() {
+        let token = ApiKeyToken::generate();
+        let expires_at = Utc::now() + TimeDelta::days(1);
+
+        let mut mock_repo = MockApiKeyRepository::new();
+        mock_repo
+            .expect_find_api_key_by_hash()
+            .times(2)
+            .with(eq(token.hash()))
+            .returning(move |token_hash| {
+                let token_hash = token_hash.clone();
+                Box::pin(async move {
+                    Ok(Some(ApiKey {
+                        id: ApiKeyId::new(),
+                        studio_id: StudioId::from(uuid::Uuid::new_v4()),
+                        name: "Booking backend".to_string(),
+                        token_hash,
+                        scopes: vec![ApiKeyScope::Read],
+                        expires_at: Some(expires_at),
+                        created_at: Utc::now(),
+                    }))
+                })
+            });
+
+        authenticate_api_key(&mock_repo, &token, Utc::now())
+            .await
+            .expect("The key is still valid");
+
+        let Err(AuthenticateApiKeyError::Expired) =
+            authenticate_api_key(&mock_repo, &token, expires_at).await
+        else {
+            panic!("The key should have expired")
+        };
+    }
+}
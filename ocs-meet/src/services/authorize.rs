@@ -0,0 +1,195 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        api_key::ApiKeyScope,
+        member::{Access, MemberRole, Permission},
+        user::Identity,
+    },
+    ports::output::member_repository::{MemberRepository, MemberRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum AuthorizeError {
+    #[error("Not allowed on this studio")]
+    Forbidden,
+    #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+}
+
+/// The role the caller acts with on its studio, as long as it grants the permission
+///
+/// Tokens naming no user act as the owner. API keys act as a member with the write scope and as
+/// a viewer otherwise, they never manage the studio itself. Users act with their membership only,
+/// the backend of a studio created before the members adds its first owner.
+pub async fn authorize(
+    repo: &impl MemberRepository,
+    identity: &Identity,
+    permission: Permission,
+) -> Result<Access, AuthorizeError> {
+    let role = if let Some(scopes) = &identity.scopes {
+        let scope = match permission {
+            Permission::View => ApiKeyScope::Read,
+            Permission::JoinMeetings => ApiKeyScope::Join,
+            Permission::ManageMeetings => ApiKeyScope::Write,
+            _ => return Err(AuthorizeError::Forbidden),
+        };
+        if !scopes.contains(&scope) {
+            return Err(AuthorizeError::Forbidden);
+        }
+        if scopes.contains(&ApiKeyScope::Write) {
+            MemberRole::Member
+        } else {
+            MemberRole::Viewer
+        }
+    } else if let Some(user_id) = &identity.user_id {
+        match repo
+            .find_member(&identity.studio_id, user_id)
+            .await
+            .map_err(AuthorizeError::from)?
+        {
+            Some(member) => member.role,
+            None => return Err(AuthorizeError::Forbidden),
+        }
+    } else {
+        MemberRole::Owner
+    };
+
+    if !role.allows(permission) {
+        return Err(AuthorizeError::Forbidden);
+    }
+
+    Ok(Access {
+        studio_id: identity.studio_id.clone(),
+        role,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::{
+        domain::{
+            api_key::ApiKeyScope,
+            member::{Member, MemberRole, Permission},
+            studio::StudioId,
+            user::{Identity, UserId},
+        },
+        ports::output::member_repository::MockMemberRepository,
+        services::{authorize, AuthorizeError},
+    };
+
+    fn identity(user_id: Option<&str>, scopes: Option<Vec<ApiKeyScope>>) -> Identity {
+        Identity {
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            user_id: user_id.map(|user_id| UserId::from(user_id.to_string())),
+            display_name: None,
+            scopes,
+            api_key_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorize_member_roles() {
+        let mut mock_repo = MockMemberRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = Member {
+                    studio_id: studio_id.clone(),
+                    user_id: user_id.clone(),
+                    display_name: None,
+                    role: MemberRole::Viewer,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        let viewer = identity(Some("bob"), None);
+
+        let access = authorize(&mock_repo, &viewer, Permission::View)
+            .await
+            .expect("Viewers can see the meetings");
+        assert_eq!(access.role, MemberRole::Viewer);
+
+        let Err(AuthorizeError::Forbidden) =
+            authorize(&mock_repo, &viewer, Permission::ManageMeetings).await
+        else {
+            panic!("Viewers cannot change the meetings")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_authorize_not_a_member() {
+        let mut mock_repo = MockMemberRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        let Err(AuthorizeError::Forbidden) = authorize(
+            &mock_repo,
+            &identity(Some("mallory"), None),
+            Permission::View,
+        )
+        .await
+        else {
+            panic!("Only the members can see the studio")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_authorize_user_of_a_studio_without_members() {
+        let mut mock_repo = MockMemberRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        mock_repo
+            .expect_list_members()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let Err(AuthorizeError::Forbidden) = authorize(
+            &mock_repo,
+            &identity(Some("mallory"), None),
+            Permission::ManageMembers,
+        )
+        .await
+        else {
+            panic!("A user should not become the owner of a studio without members")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_authorize_api_key_scopes() {
+        let mut mock_repo = MockMemberRepository::new();
+        mock_repo.expect_find_member().never();
+        let read_only = identity(None, Some(vec![ApiKeyScope::Read]));
+
+        assert!(authorize(&mock_repo, &read_only, Permission::View)
+            .await
+            .is_ok());
+        assert!(
+            authorize(&mock_repo, &read_only, Permission::ManageMeetings)
+                .await
+                .is_err()
+        );
+
+        let read_write = identity(None, Some(vec![ApiKeyScope::Read, ApiKeyScope::Write]));
+        let access = authorize(&mock_repo, &read_write, Permission::ManageMeetings)
+            .await
+            .expect("The write scope changes the meetings");
+        assert_eq!(access.role, MemberRole::Member);
+        assert!(authorize(&mock_repo, &read_write, Permission::ManageStudio)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_authorize_service_token() {
+        let mock_repo = MockMemberRepository::new();
+
+        let access = authorize(&mock_repo, &identity(None, None), Permission::DeleteStudio)
+            .await
+            .expect("The tokens of the studio backend can do everything");
+        assert_eq!(access.role, MemberRole::Owner);
+    }
+}
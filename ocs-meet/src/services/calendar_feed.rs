@@ -0,0 +1,118 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{calendar_feed::CalendarFeedToken, meeting::Meeting, studio::StudioId},
+    ports::output::{
+        calendar_feed_repository::{CalendarFeedRepository, CalendarFeedRepositoryError},
+        meeting_repository::{MeetingQuery, MeetingRepository, MeetingRepositoryError},
+    },
+};
+
+/// How far back the feed goes, so calendars keep the recent history
+pub const CALENDAR_FEED_HISTORY: TimeDelta = TimeDelta::days(90);
+
+#[derive(Debug, Error)]
+pub enum CalendarFeedError {
+    /// Also returned for a wrong or revoked token, so feeds cannot be probed
+    #[error("The calendar feed you're looking for does not exist")]
+    NotFound,
+    #[error(transparent)]
+    CalendarFeedRepository(#[from] CalendarFeedRepositoryError),
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+}
+
+/// Meetings to publish in the calendar feed of the studio, cancelled ones included so that
+/// subscribed calendars remove them
+pub async fn calendar_feed(
+    repo: &(impl CalendarFeedRepository + MeetingRepository),
+    studio_id: StudioId,
+    token: CalendarFeedToken,
+    today: DateTime<Utc>,
+) -> Result<Vec<Meeting>, CalendarFeedError> {
+    let Some(feed) = repo.find_calendar_feed(&studio_id).await? else {
+        return Err(CalendarFeedError::NotFound);
+    };
+
+    if !feed.accepts(&token) {
+        return Err(CalendarFeedError::NotFound);
+    }
+
+    let query = MeetingQuery {
+        from: Some(today - CALENDAR_FEED_HISTORY),
+        ..MeetingQuery::new(studio_id)
+    };
+
+    Ok(repo.list_meetings(&query).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::{
+        domain::{
+            calendar_feed::{CalendarFeed, CalendarFeedToken},
+            studio::StudioId,
+        },
+        ports::output::repository::MockRepository,
+        services::{calendar_feed, CalendarFeedError, CALENDAR_FEED_HISTORY},
+    };
+
+    fn expect_feed(mock_repo: &mut MockRepository, token: &CalendarFeedToken) {
+        let token_hash = token.hash();
+        mock_repo
+            .expect_find_calendar_feed()
+            .once()
+            .return_once(move |studio_id| {
+                let studio_id = studio_id.clone();
+                Box::pin(async move {
+                    Ok(Some(CalendarFeed {
+                        studio_id,
+                        token_hash,
+                        created_at: Utc::now(),
+                    }))
+                })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_feed_ok() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let token = CalendarFeedToken::generate();
+        let today = Utc::now();
+
+        let mut mock_repo = MockRepository::new();
+        expect_feed(&mut mock_repo, &token);
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(move |query| {
+                query.include_cancelled && query.from == Some(today - CALENDAR_FEED_HISTORY)
+            })
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        calendar_feed(&mock_repo, studio_id, token, today)
+            .await
+            .expect("The feed should be readable with its token");
+    }
+
+    #[tokio::test]
+    async fn test_feed_wrong_token() {
+        let mut mock_repo = MockRepository::new();
+        expect_feed(&mut mock_repo, &CalendarFeedToken::generate());
+        mock_repo.expect_list_meetings().never();
+
+        let Err(CalendarFeedError::NotFound) = calendar_feed(
+            &mock_repo,
+            StudioId::from(uuid::Uuid::new_v4()),
+            CalendarFeedToken::generate(),
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The feed should not be readable with another token")
+        };
+    }
+}
@@ -0,0 +1,225 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        meeting::{Meeting, MeetingId, MeetingStatus},
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum CancelMeetingError {
+    #[error("The meeting you're looking for does not exist")]
+    NotFound,
+    #[error("The meeting has already started")]
+    AlreadyStarted,
+    #[error("The meeting has already ended")]
+    AlreadyEnded,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Cancels the meeting and closes its room, in case participants have opened it already
+///
+/// Cancelling it again closes the room again, in case it failed the first time.
+pub async fn cancel_meeting(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+) -> Result<Meeting, CancelMeetingError> {
+    let Some(mut meeting) = repo
+        .find_meeting(&meeting_id)
+        .await
+        .map_err(CancelMeetingError::from)?
+    else {
+        return Err(CancelMeetingError::NotFound);
+    };
+
+    if meeting.studio_id != studio_id {
+        return Err(CancelMeetingError::NotFound);
+    }
+
+    match meeting.status {
+        MeetingStatus::Scheduled => {
+            meeting.status = MeetingStatus::Cancelled;
+            repo.update_meeting(&meeting)
+                .await
+                .map_err(CancelMeetingError::from)?;
+        }
+        MeetingStatus::Cancelled => {}
+        MeetingStatus::Live => return Err(CancelMeetingError::AlreadyStarted),
+        MeetingStatus::Ended => return Err(CancelMeetingError::AlreadyEnded),
+    }
+
+    room_manager
+        .delete_room(&meeting.id)
+        .await
+        .map_err(CancelMeetingError::from)?;
+
+    Ok(meeting)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            studio::StudioId,
+        },
+        ports::output::{meeting_repository::MockMeetingRepository, room_manager::MockRoomManager},
+        services::{cancel_meeting, CancelMeetingError},
+    };
+
+    fn expect_find_meeting(
+        mock_repo: &mut MockMeetingRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+        status: MeetingStatus,
+    ) {
+        let studio_id = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(move |meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_cancel_ok() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            MeetingStatus::Scheduled,
+        );
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .withf(|meeting| meeting.status == MeetingStatus::Cancelled)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let meeting = cancel_meeting(&mock_repo, &mock_room_manager, meeting_id, studio_id)
+            .await
+            .expect("The meeting should be cancelled");
+        assert_eq!(meeting.status, MeetingStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_twice() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            MeetingStatus::Cancelled,
+        );
+        mock_repo.expect_update_meeting().never();
+        // The room is closed again, in case it failed the first time
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        cancel_meeting(&mock_repo, &mock_room_manager, meeting_id, studio_id)
+            .await
+            .expect("Cancelling a cancelled meeting should be a no-op");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_ended() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            MeetingStatus::Ended,
+        );
+        mock_repo.expect_update_meeting().never();
+
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_delete_room().never();
+
+        let Err(CancelMeetingError::AlreadyEnded) =
+            cancel_meeting(&mock_repo, &mock_room_manager, meeting_id, studio_id).await
+        else {
+            panic!("An ended meeting cannot be cancelled")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_cancel_other_studio() {
+        let meeting_id = MeetingId::new();
+        let owner = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &owner,
+            MeetingStatus::Scheduled,
+        );
+        mock_repo.expect_update_meeting().never();
+
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_delete_room().never();
+
+        let Err(CancelMeetingError::NotFound) = cancel_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id,
+            StudioId::from(uuid::Uuid::new_v4()),
+        )
+        .await
+        else {
+            panic!("The meeting should not be visible from another studio")
+        };
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::update_meeting_series::find_upcoming_occurrences;
+use crate::{
+    domain::{
+        meeting::{Meeting, MeetingSeries, MeetingSeriesId, MeetingStatus},
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum CancelMeetingSeriesError {
+    #[error("The meeting series you're looking for does not exist")]
+    NotFound,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Cancels the upcoming occurrences of the series and closes their rooms, the past ones are kept
+/// as they happened
+pub async fn cancel_meeting_series(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    series_id: MeetingSeriesId,
+    studio_id: StudioId,
+    today: DateTime<Utc>,
+) -> Result<(MeetingSeries, Vec<Meeting>), CancelMeetingSeriesError> {
+    let Some((series, mut meetings)) =
+        find_upcoming_occurrences(repo, &series_id, &studio_id, today).await?
+    else {
+        return Err(CancelMeetingSeriesError::NotFound);
+    };
+
+    for meeting in &mut meetings {
+        meeting.status = MeetingStatus::Cancelled;
+    }
+
+    repo.update_meetings(&meetings)
+        .await
+        .map_err(CancelMeetingSeriesError::from)?;
+
+    // Rooms are only opened by the participants joining, so within the join window
+    for meeting in meetings
+        .iter()
+        .filter(|meeting| meeting.join_opens_at() <= today)
+    {
+        room_manager
+            .delete_room(&meeting.id)
+            .await
+            .map_err(CancelMeetingSeriesError::from)?;
+    }
+
+    Ok((series, meetings))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+
+    use crate::{
+        domain::{
+            meeting::{MeetingSeriesId, MeetingStatus},
+            studio::StudioId,
+        },
+        ports::output::{meeting_repository::MockMeetingRepository, room_manager::MockRoomManager},
+        services::{cancel_meeting_series, update_meeting_series::tests::expect_series},
+    };
+
+    #[tokio::test]
+    async fn test_cancel_series() {
+        let series_id = MeetingSeriesId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_series(&mut mock_repo, &series_id, &studio_id, 5);
+        mock_repo
+            .expect_update_meetings()
+            .once()
+            .withf(|meetings| {
+                meetings.len() == 5
+                    && meetings
+                        .iter()
+                        .all(|meeting| meeting.status == MeetingStatus::Cancelled)
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        // Only the room of the first occurrence, which can be joined already, may be open
+        let today = Utc::now() + TimeDelta::days(1);
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let (_, meetings) =
+            cancel_meeting_series(&mock_repo, &mock_room_manager, series_id, studio_id, today)
+                .await
+                .expect("The series should be cancelled");
+        assert_eq!(meetings.len(), 5);
+    }
+}
@@ -0,0 +1,155 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        member::{Access, Member, MemberRole},
+        user::UserId,
+    },
+    ports::output::member_repository::{MemberRepository, MemberRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum ChangeMemberRoleError {
+    #[error("The user is not a member of the studio")]
+    NotFound,
+    #[error("Only the owners can change the owners")]
+    Forbidden,
+    #[error("The studio needs at least one owner")]
+    LastOwner,
+    #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+}
+
+/// Gives another role to a member, the owners are only managed by the other owners
+pub async fn change_member_role(
+    repo: &impl MemberRepository,
+    access: &Access,
+    user_id: UserId,
+    role: MemberRole,
+) -> Result<Member, ChangeMemberRoleError> {
+    let Some(mut member) = repo
+        .find_member(&access.studio_id, &user_id)
+        .await
+        .map_err(ChangeMemberRoleError::from)?
+    else {
+        return Err(ChangeMemberRoleError::NotFound);
+    };
+
+    if (member.role == MemberRole::Owner || role == MemberRole::Owner)
+        && access.role != MemberRole::Owner
+    {
+        return Err(ChangeMemberRoleError::Forbidden);
+    }
+
+    if member.role == MemberRole::Owner
+        && role != MemberRole::Owner
+        && is_last_owner(repo, access).await?
+    {
+        return Err(ChangeMemberRoleError::LastOwner);
+    }
+
+    member.role = role;
+    repo.update_member(&member)
+        .await
+        .map_err(ChangeMemberRoleError::from)?;
+
+    Ok(member)
+}
+
+pub(super) async fn is_last_owner(
+    repo: &impl MemberRepository,
+    access: &Access,
+) -> Result<bool, MemberRepositoryError> {
+    let owners = repo
+        .list_members(&access.studio_id)
+        .await?
+        .into_iter()
+        .filter(|member| member.role == MemberRole::Owner)
+        .count();
+    Ok(owners <= 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::{
+        domain::{
+            member::{Access, Member, MemberRole},
+            studio::StudioId,
+            user::UserId,
+        },
+        ports::output::member_repository::MockMemberRepository,
+        services::{change_member_role, ChangeMemberRoleError},
+    };
+
+    fn owner(studio_id: &StudioId, user_id: &UserId) -> Member {
+        Member {
+            studio_id: studio_id.clone(),
+            user_id: user_id.clone(),
+            display_name: None,
+            role: MemberRole::Owner,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_last_owner_is_kept() {
+        let access = Access {
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            role: MemberRole::Owner,
+        };
+
+        let mut mock_repo = MockMemberRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = owner(studio_id, user_id);
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo.expect_list_members().returning(|studio_id| {
+            let members = vec![owner(studio_id, &UserId::from("alice".to_string()))];
+            Box::pin(async move { Ok(members) })
+        });
+        mock_repo.expect_update_member().never();
+
+        let Err(ChangeMemberRoleError::LastOwner) = change_member_role(
+            &mock_repo,
+            &access,
+            UserId::from("alice".to_string()),
+            MemberRole::Admin,
+        )
+        .await
+        else {
+            panic!("The last owner should not be demoted")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_demote_owner() {
+        let access = Access {
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            role: MemberRole::Admin,
+        };
+
+        let mut mock_repo = MockMemberRepository::new();
+        mock_repo
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = owner(studio_id, user_id);
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo.expect_update_member().never();
+
+        let Err(ChangeMemberRoleError::Forbidden) = change_member_role(
+            &mock_repo,
+            &access,
+            UserId::from("alice".to_string()),
+            MemberRole::Viewer,
+        )
+        .await
+        else {
+            panic!("Admins should not touch the owners")
+        };
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        api_key::{ApiKey, ApiKeyId, ApiKeyScope, ApiKeyToken},
+        studio::StudioId,
+    },
+    ports::output::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum CreateApiKeyError {
+    #[error("An API key needs at least one scope")]
+    NoScope,
+    #[error("Cannot create an API key already expired")]
+    ExpiryInThePast,
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+}
+
+/// Creates a key for the studio, the returned token is the only time its secret is known
+pub async fn create_api_key(
+    repo: &impl ApiKeyRepository,
+    studio_id: StudioId,
+    name: String,
+    scopes: Vec<ApiKeyScope>,
+    expires_at: Option<DateTime<Utc>>,
+    today: DateTime<Utc>,
+) -> Result<(ApiKey, ApiKeyToken), CreateApiKeyError> {
+    let scopes = scopes.into_iter().fold(Vec::new(), |mut scopes, scope| {
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+        scopes
+    });
+    if scopes.is_empty() {
+        return Err(CreateApiKeyError::NoScope);
+    }
+
+    if expires_at.is_some_and(|expires_at| expires_at <= today) {
+        return Err(CreateApiKeyError::ExpiryInThePast);
+    }
+
+    let token = ApiKeyToken::generate();
+    let api_key = ApiKey {
+        id: ApiKeyId::new(),
+        studio_id,
+        name,
+        token_hash: token.hash(),
+        scopes,
+        expires_at,
+        created_at: today,
+    };
+
+    repo.create_api_key(&api_key)
+        .await
+        .map_err(CreateApiKeyError::from)?;
+
+    Ok((api_key, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+
+    use crate::{
+        domain::{api_key::ApiKeyScope, studio::StudioId},
+        ports::output::api_key_repository::MockApiKeyRepository,
+        services::{create_api_key, CreateApiKeyError},
+    };
+
+    #[tokio::test]
+    async fn test_only_the_hash_is_saved() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockApiKeyRepository::new();
+        mock_repo
+            .expect_create_api_key()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let (api_key, token) = create_api_key(
+            &mock_repo,
+            studio_id,
+            "Booking backend".to_string(),
+            vec![ApiKeyScope::Write, ApiKeyScope::Read, ApiKeyScope::Write],
+            None,
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(api_key.token_hash, token.hash());
+        assert_eq!(api_key.scopes, vec![ApiKeyScope::Write, ApiKeyScope::Read]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_keys() {
+        let mut mock_repo = MockApiKeyRepository::new();
+        mock_repo.expect_create_api_key().never();
+
+        let Err(CreateApiKeyError::NoScope) = create_api_key(
+            &mock_repo,
+            StudioId::from(uuid::Uuid::new_v4()),
+            "Booking backend".to_string(),
+            vec![],
+            None,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("A key without scope is useless")
+        };
+
+        let Err(CreateApiKeyError::ExpiryInThePast) = create_api_key(
+            &mock_repo,
+            StudioId::from(uuid::Uuid::new_v4()),
+            "Booking backend".to_string(),
+            vec![ApiKeyScope::Read],
+            Some(Utc::now() - TimeDelta::days(1)),
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("A key cannot be expired from the start")
+        };
+    }
+}
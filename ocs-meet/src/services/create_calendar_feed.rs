@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        calendar_feed::{CalendarFeed, CalendarFeedToken},
+        studio::StudioId,
+    },
+    ports::output::calendar_feed_repository::{
+        CalendarFeedRepository, CalendarFeedRepositoryError,
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum CreateCalendarFeedError {
+    #[error(transparent)]
+    CalendarFeedRepository(#[from] CalendarFeedRepositoryError),
+}
+
+/// Issues a new token for the calendar feed of the studio, the previous one stops working
+pub async fn create_calendar_feed(
+    repo: &impl CalendarFeedRepository,
+    studio_id: StudioId,
+    today: DateTime<Utc>,
+) -> Result<CalendarFeedToken, CreateCalendarFeedError> {
+    let token = CalendarFeedToken::generate();
+    let feed = CalendarFeed {
+        studio_id,
+        token_hash: token.hash(),
+        created_at: today,
+    };
+
+    repo.save_calendar_feed(&feed)
+        .await
+        .map_err(CreateCalendarFeedError::from)?;
+
+    Ok(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::{
+        domain::studio::StudioId,
+        ports::output::calendar_feed_repository::MockCalendarFeedRepository,
+        services::create_calendar_feed,
+    };
+
+    #[tokio::test]
+    async fn test_only_the_hash_is_saved() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockCalendarFeedRepository::new();
+        mock_repo
+            .expect_save_calendar_feed()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let token = create_calendar_feed(&mock_repo, studio_id, Utc::now())
+            .await
+            .unwrap();
+        assert_ne!(token.as_ref().as_bytes(), token.hash().as_ref().as_slice());
+    }
+}
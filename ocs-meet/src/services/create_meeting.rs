@@ -3,26 +3,45 @@ use thiserror::Error;
 
 use crate::{
     domain::{
-        meeting::{Meeting, MeetingId, MeetingName},
-        studio::StudioId,
+        meeting::{JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus},
+        passcode::MeetingPasscode,
+        studio::{Studio, StudioId},
+    },
+    ports::output::{
+        meeting_repository::{MeetingQuery, MeetingRepository, MeetingRepositoryError},
+        studio_repository::{StudioRepository, StudioRepositoryError},
     },
-    ports::output::meeting_repository::{MeetingRepository, MeetingRepositoryError},
 };
 
 #[derive(Debug, Error)]
 pub enum CreateMeetingError {
     #[error("Cannot create a meeting in the past")]
     DateInThePast,
-    #[error("A meeting with the same date already exists")]
-    DateAlreadyTaken,
+    #[error("The meeting overlaps with meeting {}", .0.as_ref())]
+    DateAlreadyTaken(MeetingId),
+    #[error("The studio has not been created")]
+    StudioNotFound,
     #[error(transparent)]
     MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
 }
 
+/// Schedules a meeting for the studio, lasting the default duration of the studio when none is
+/// given and asking for the passcode if any to the participants who are not members, who wait for
+/// a host to let them in when the meeting has a waiting room
+///
+/// The room of the meeting is only opened once it is joined, holding at most `max_participants`.
+#[allow(clippy::too_many_arguments)]
 pub async fn create_meeting(
-    repo: &impl MeetingRepository,
+    repo: &(impl MeetingRepository + StudioRepository),
     name: MeetingName,
     date: DateTime<Utc>,
+    duration: Option<MeetingDuration>,
+    join_window: JoinWindow,
+    passcode: Option<MeetingPasscode>,
+    waiting_room: bool,
+    max_participants: Option<u32>,
     studio_id: StudioId,
     today: DateTime<Utc>,
 ) -> Result<Meeting, CreateMeetingError> {
@@ -30,23 +49,31 @@ pub async fn create_meeting(
         return Err(CreateMeetingError::DateInThePast);
     }
 
-    let meetings = repo
-        .list_meetings(&studio_id)
-        .await
-        .map_err(CreateMeetingError::from)?;
-
-    let None = meetings.iter().find(|meeting| meeting.date == date) else {
-        return Err(CreateMeetingError::DateAlreadyTaken);
-    };
+    let studio = find_meeting_studio(repo, &studio_id, CreateMeetingError::StudioNotFound).await?;
 
     let id = MeetingId::new();
     let meeting = Meeting {
         id,
         name,
         date,
+        duration: studio.meeting_duration(duration),
+        join_window,
         studio_id,
+        status: MeetingStatus::Scheduled,
+        series_id: None,
+        passcode: passcode.as_ref().map(MeetingPasscode::hash),
+        waiting_room,
+        max_participants,
+        started_at: None,
+        ended_at: None,
     };
 
+    if let Some(conflict) =
+        find_overlapping_meeting(repo, &meeting.studio_id, date, meeting.end_date(), None).await?
+    {
+        return Err(CreateMeetingError::DateAlreadyTaken(conflict.id));
+    }
+
     repo.create_meeting(&meeting)
         .await
         .map_err(CreateMeetingError::from)?;
@@ -54,20 +81,67 @@ pub async fn create_meeting(
     Ok(meeting)
 }
 
+/// The studio the meetings are created for, whose settings apply to them, `not_found` when it
+/// has not been created or was deleted
+pub(crate) async fn find_meeting_studio<E: From<StudioRepositoryError>>(
+    repo: &impl StudioRepository,
+    studio_id: &StudioId,
+    not_found: E,
+) -> Result<Studio, E> {
+    repo.find_studio(studio_id).await?.ok_or(not_found)
+}
+
+/// Finds a scheduled meeting of the studio sharing some time with `[start, end)`, except the
+/// `ignored` one
+pub(crate) async fn find_overlapping_meeting(
+    repo: &impl MeetingRepository,
+    studio_id: &StudioId,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    ignored: Option<&MeetingId>,
+) -> Result<Option<Meeting>, MeetingRepositoryError> {
+    // No meeting lasts longer than the maximum duration, so one starting earlier cannot overlap
+    let query = MeetingQuery {
+        from: Some(start - MeetingDuration::MAX),
+        to: Some(end),
+        include_cancelled: false,
+        ..MeetingQuery::new(studio_id.clone())
+    };
+
+    let meetings = repo.list_meetings(&query).await?;
+
+    Ok(meetings
+        .into_iter()
+        .filter(|meeting| Some(&meeting.id) != ignored)
+        .find(|meeting| meeting.overlaps(start, end)))
+}
+
 #[cfg(test)]
 mod test {
-    use chrono::{Days, Timelike, Utc};
+    use chrono::{Days, TimeDelta, Timelike, Utc};
     use mockall::predicate::eq;
 
     use crate::{
         domain::{
-            meeting::{Meeting, MeetingId, MeetingName},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
             studio::StudioId,
         },
-        ports::output::meeting_repository::MockMeetingRepository,
-        services::{create_meeting::create_meeting, CreateMeetingError},
+        ports::output::{meeting_repository::MeetingQuery, repository::MockRepository},
+        services::{
+            create_meeting::create_meeting, create_studio::tests::studio, CreateMeetingError,
+        },
     };
 
+    fn expect_find_studio(mock_repo: &mut MockRepository, studio_id: &StudioId) {
+        let found = studio(studio_id);
+        mock_repo
+            .expect_find_studio()
+            .with(eq(studio_id.clone()))
+            .return_once(move |_| Box::pin(async move { Ok(Some(found)) }));
+    }
+
     #[tokio::test]
     async fn test_create_meeting_ok() {
         let name = MeetingName::try_from("Hello meeting".to_string()).unwrap();
@@ -76,7 +150,8 @@ mod test {
 
         let today = date.with_hour(0).unwrap();
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo, &studio_id);
         mock_repo
             .expect_create_meeting()
             .once()
@@ -84,12 +159,24 @@ mod test {
         mock_repo
             .expect_list_meetings()
             .once()
-            .with(eq(studio_id.clone()))
+            .withf(MeetingQuery::on_studio(&studio_id))
             .returning(|_| Box::pin(async { Ok(vec![]) }));
 
-        let _meeting = create_meeting(&mock_repo, name, date, studio_id, today)
-            .await
-            .expect("The meeting should be created");
+        let meeting = create_meeting(
+            &mock_repo,
+            name,
+            date,
+            Some(MeetingDuration::default()),
+            JoinWindow::default(),
+            None,
+            false,
+            Some(50),
+            studio_id,
+            today,
+        )
+        .await
+        .expect("The meeting should be created");
+        assert_eq!(meeting.max_participants, Some(50));
     }
 
     #[tokio::test]
@@ -99,7 +186,7 @@ mod test {
         let studio_id = StudioId::from(uuid::Uuid::new_v4());
         let today = Utc::now();
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
         mock_repo
             .expect_create_meeting()
             .never()
@@ -107,11 +194,22 @@ mod test {
         mock_repo
             .expect_list_meetings()
             .never()
-            .with(eq(studio_id.clone()))
+            .withf(MeetingQuery::on_studio(&studio_id))
             .returning(|_| Box::pin(async { Ok(vec![]) }));
 
-        let Err(CreateMeetingError::DateInThePast) =
-            create_meeting(&mock_repo, name, date, studio_id, today).await
+        let Err(CreateMeetingError::DateInThePast) = create_meeting(
+            &mock_repo,
+            name,
+            date,
+            Some(MeetingDuration::default()),
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            studio_id,
+            today,
+        )
+        .await
         else {
             panic!("The meeting should not be created")
         };
@@ -124,7 +222,8 @@ mod test {
         let studio_id = StudioId::from(uuid::Uuid::new_v4());
         let today = date.with_hour(0).unwrap();
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo, &studio_id);
         mock_repo
             .expect_create_meeting()
             .never()
@@ -132,9 +231,9 @@ mod test {
         mock_repo
             .expect_list_meetings()
             .once()
-            .with(eq(studio_id.clone()))
-            .returning(move |studio_id| {
-                let studio_id = studio_id.clone();
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
                 let date = date;
                 Box::pin(async move {
                     Ok(vec![Meeting {
@@ -142,14 +241,256 @@ mod test {
                         studio_id,
                         name: MeetingName::try_from("Another meeting".to_string()).unwrap(),
                         date,
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
                     }])
                 })
             });
 
-        let Err(CreateMeetingError::DateAlreadyTaken) =
-            create_meeting(&mock_repo, name, date, studio_id, today).await
+        let Err(CreateMeetingError::DateAlreadyTaken(_)) = create_meeting(
+            &mock_repo,
+            name,
+            date,
+            Some(MeetingDuration::default()),
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            studio_id,
+            today,
+        )
+        .await
         else {
             panic!("The meeting should not be created")
         };
     }
+
+    #[tokio::test]
+    async fn test_create_meeting_ignores_cancelled() {
+        let name = MeetingName::try_from("Hello meeting".to_string()).unwrap();
+        let date: chrono::DateTime<Utc> = Utc::now();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let today = date.with_hour(0).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo, &studio_id);
+        mock_repo
+            .expect_create_meeting()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(move |query| {
+                !query.include_cancelled
+                    && query.from == Some(date - MeetingDuration::MAX)
+                    && query.to == Some(date + TimeDelta::hours(1))
+            })
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        create_meeting(
+            &mock_repo,
+            name,
+            date,
+            Some(MeetingDuration::default()),
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            studio_id,
+            today,
+        )
+        .await
+        .expect("Only the meetings around the slot should be looked up");
+    }
+
+    #[tokio::test]
+    async fn test_create_meeting_overlap() {
+        let name = MeetingName::try_from("Hello meeting".to_string()).unwrap();
+        let existing_date: chrono::DateTime<Utc> = Utc::now();
+        let date = existing_date + TimeDelta::minutes(1);
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let today = existing_date.with_hour(0).unwrap();
+        let existing_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo, &studio_id);
+        mock_repo.expect_create_meeting().never();
+        let conflicting_id = existing_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
+                let id = conflicting_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id,
+                        studio_id,
+                        name: MeetingName::try_from("Another meeting".to_string()).unwrap(),
+                        date: existing_date,
+                        duration: MeetingDuration::try_from(30).unwrap(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+
+        let Err(CreateMeetingError::DateAlreadyTaken(conflict)) = create_meeting(
+            &mock_repo,
+            name,
+            date,
+            Some(MeetingDuration::default()),
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            studio_id,
+            today,
+        )
+        .await
+        else {
+            panic!("The meeting should not be created")
+        };
+        assert_eq!(conflict, existing_id);
+    }
+
+    #[tokio::test]
+    async fn test_create_meeting_right_after_another() {
+        let name = MeetingName::try_from("Hello meeting".to_string()).unwrap();
+        let existing_date: chrono::DateTime<Utc> = Utc::now();
+        let date = existing_date + TimeDelta::minutes(30);
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let today = existing_date.with_hour(0).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo, &studio_id);
+        mock_repo
+            .expect_create_meeting()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id: MeetingId::new(),
+                        studio_id,
+                        name: MeetingName::try_from("Another meeting".to_string()).unwrap(),
+                        date: existing_date,
+                        duration: MeetingDuration::try_from(30).unwrap(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+
+        create_meeting(
+            &mock_repo,
+            name,
+            date,
+            Some(MeetingDuration::default()),
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            studio_id,
+            today,
+        )
+        .await
+        .expect("Back to back meetings do not overlap");
+    }
+
+    #[tokio::test]
+    async fn test_create_meeting_unknown_studio() {
+        let name = MeetingName::try_from("Hello meeting".to_string()).unwrap();
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo.expect_list_meetings().never();
+        mock_repo.expect_create_meeting().never();
+
+        let Err(CreateMeetingError::StudioNotFound) = create_meeting(
+            &mock_repo,
+            name,
+            date,
+            None,
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            StudioId::from(uuid::Uuid::new_v4()),
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("A meeting needs an existing studio")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_create_meeting_default_duration_of_the_studio() {
+        let name = MeetingName::try_from("Hello meeting".to_string()).unwrap();
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut found = studio(&studio_id);
+        found.default_duration = MeetingDuration::try_from(45).unwrap();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .return_once(move |_| Box::pin(async move { Ok(Some(found)) }));
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo
+            .expect_create_meeting()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let meeting = create_meeting(
+            &mock_repo,
+            name,
+            date,
+            None,
+            JoinWindow::default(),
+            None,
+            false,
+            None,
+            studio_id,
+            Utc::now(),
+        )
+        .await
+        .expect("The meeting should be created");
+        assert_eq!(meeting.duration.minutes(), 45);
+    }
 }
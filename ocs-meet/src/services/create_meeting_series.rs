@@ -0,0 +1,338 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::create_meeting::find_meeting_studio;
+use crate::{
+    domain::{
+        meeting::{
+            JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingSeries,
+            MeetingSeriesId, MeetingStatus,
+        },
+        recurrence::{RecurrenceRule, RecurrenceRuleError},
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingQuery, MeetingRepository, MeetingRepositoryError},
+        studio_repository::{StudioRepository, StudioRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum CreateMeetingSeriesError {
+    #[error("Cannot create a meeting in the past")]
+    DateInThePast,
+    #[error(transparent)]
+    Recurrence(#[from] RecurrenceRuleError),
+    #[error("The occurrence of {} overlaps with meeting {}", .0, .1.as_ref())]
+    DateAlreadyTaken(DateTime<Utc>, MeetingId),
+    #[error("The studio has not been created")]
+    StudioNotFound,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+}
+
+/// Schedules the occurrences of a series for the studio, on the days and at the time of day of
+/// its timezone, lasting the default duration of the studio when none is given
+#[allow(clippy::too_many_arguments)]
+pub async fn create_meeting_series(
+    repo: &(impl MeetingRepository + StudioRepository),
+    name: MeetingName,
+    date: DateTime<Utc>,
+    duration: Option<MeetingDuration>,
+    join_window: JoinWindow,
+    max_participants: Option<u32>,
+    rule: RecurrenceRule,
+    studio_id: StudioId,
+    today: DateTime<Utc>,
+) -> Result<(MeetingSeries, Vec<Meeting>), CreateMeetingSeriesError> {
+    if date < today {
+        return Err(CreateMeetingSeriesError::DateInThePast);
+    }
+
+    let studio =
+        find_meeting_studio(repo, &studio_id, CreateMeetingSeriesError::StudioNotFound).await?;
+    let duration = studio.meeting_duration(duration);
+
+    let series = MeetingSeries {
+        id: MeetingSeriesId::new(),
+        studio_id,
+        rule,
+        starts_at: date,
+    };
+
+    let meetings: Vec<Meeting> = series
+        .rule
+        .occurrences(date, studio.timezone)?
+        .into_iter()
+        .map(|date| Meeting {
+            id: MeetingId::new(),
+            studio_id: series.studio_id.clone(),
+            name: name.clone(),
+            date,
+            duration,
+            join_window,
+            status: MeetingStatus::Scheduled,
+            series_id: Some(series.id.clone()),
+            passcode: None,
+            waiting_room: false,
+            max_participants,
+            started_at: None,
+            ended_at: None,
+        })
+        .collect();
+
+    if let Some((occurrence, conflict)) =
+        find_occurrence_conflict(repo, &series.studio_id, &meetings, None).await?
+    {
+        return Err(CreateMeetingSeriesError::DateAlreadyTaken(
+            occurrence, conflict,
+        ));
+    }
+
+    repo.create_series(&series, &meetings)
+        .await
+        .map_err(CreateMeetingSeriesError::from)?;
+
+    Ok((series, meetings))
+}
+
+/// Finds the first occurrence sharing some time with a scheduled meeting of the studio, along with
+/// that meeting, the meetings of the `ignored` series left aside
+///
+/// `occurrences` must be sorted by date, the existing meetings are fetched all at once.
+pub(crate) async fn find_occurrence_conflict(
+    repo: &impl MeetingRepository,
+    studio_id: &StudioId,
+    occurrences: &[Meeting],
+    ignored: Option<&MeetingSeriesId>,
+) -> Result<Option<(DateTime<Utc>, MeetingId)>, MeetingRepositoryError> {
+    let (Some(first), Some(last)) = (occurrences.first(), occurrences.last()) else {
+        return Ok(None);
+    };
+
+    // No meeting lasts longer than the maximum duration, so one starting earlier cannot overlap
+    let query = MeetingQuery {
+        from: Some(first.date - MeetingDuration::MAX),
+        to: Some(last.end_date()),
+        include_cancelled: false,
+        ..MeetingQuery::new(studio_id.clone())
+    };
+
+    let meetings: Vec<Meeting> = repo
+        .list_meetings(&query)
+        .await?
+        .into_iter()
+        .filter(|meeting| ignored.is_none() || meeting.series_id.as_ref() != ignored)
+        .collect();
+
+    Ok(occurrences.iter().find_map(|occurrence| {
+        meetings
+            .iter()
+            .find(|meeting| meeting.overlaps(occurrence.date, occurrence.end_date()))
+            .map(|meeting| (occurrence.date, meeting.id.clone()))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Days, TimeDelta, Utc};
+    use std::str::FromStr;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            recurrence::{RecurrenceRule, RecurrenceRuleError},
+            studio::StudioId,
+        },
+        ports::output::{meeting_repository::MeetingQuery, repository::MockRepository},
+        services::{create_meeting_series, create_studio::tests::studio, CreateMeetingSeriesError},
+    };
+
+    fn expect_find_studio(mock_repo: &mut MockRepository) {
+        mock_repo.expect_find_studio().returning(|studio_id| {
+            let found = studio(studio_id);
+            Box::pin(async move { Ok(Some(found)) })
+        });
+    }
+
+    async fn create(
+        mock_repo: &MockRepository,
+        rule: &str,
+        date: DateTime<Utc>,
+        studio_id: StudioId,
+    ) -> Result<(), CreateMeetingSeriesError> {
+        create_meeting_series(
+            mock_repo,
+            MeetingName::try_from("Stand-up".to_string()).unwrap(),
+            date,
+            Some(MeetingDuration::try_from(15).unwrap()),
+            JoinWindow::default(),
+            None,
+            RecurrenceRule::from_str(rule).unwrap(),
+            studio_id,
+            Utc::now(),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    #[tokio::test]
+    async fn test_create_series_ok() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(1)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo);
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(move |query| {
+                query.to == Some(date + TimeDelta::weeks(3) + TimeDelta::minutes(15))
+                    && !query.include_cancelled
+            })
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo
+            .expect_create_series()
+            .once()
+            .withf(|series, meetings| {
+                meetings.len() == 4
+                    && meetings
+                        .iter()
+                        .all(|meeting| meeting.series_id.as_ref() == Some(&series.id))
+            })
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        create(&mock_repo, "FREQ=WEEKLY;COUNT=4", date, studio_id)
+            .await
+            .expect("The series should be created");
+    }
+
+    #[tokio::test]
+    async fn test_create_series_conflict_on_one_occurrence() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(1)).unwrap();
+        let conflict_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo);
+        let listed_id = conflict_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
+                let id = listed_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id,
+                        studio_id,
+                        name: MeetingName::try_from("Workshop".to_string()).unwrap(),
+                        date: date + TimeDelta::days(14) - TimeDelta::minutes(30),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+        mock_repo.expect_create_series().never();
+
+        let Err(CreateMeetingSeriesError::DateAlreadyTaken(occurrence, conflict)) =
+            create(&mock_repo, "FREQ=WEEKLY;COUNT=4", date, studio_id).await
+        else {
+            panic!("The third occurrence should conflict")
+        };
+        assert_eq!(occurrence, date + TimeDelta::days(14));
+        assert_eq!(conflict, conflict_id);
+    }
+
+    #[tokio::test]
+    async fn test_create_series_too_many_occurrences() {
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo);
+        mock_repo.expect_create_series().never();
+
+        let date = Utc::now().checked_add_days(Days::new(1)).unwrap();
+        let Err(CreateMeetingSeriesError::Recurrence(RecurrenceRuleError::TooManyOccurrences)) =
+            create(
+                &mock_repo,
+                "FREQ=DAILY;UNTIL=21000101T000000Z",
+                date,
+                StudioId::from(uuid::Uuid::new_v4()),
+            )
+            .await
+        else {
+            panic!("The series should be refused")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_create_series_unknown_studio() {
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo.expect_create_series().never();
+
+        let date = Utc::now().checked_add_days(Days::new(1)).unwrap();
+        let Err(CreateMeetingSeriesError::StudioNotFound) = create(
+            &mock_repo,
+            "FREQ=WEEKLY;COUNT=4",
+            date,
+            StudioId::from(uuid::Uuid::new_v4()),
+        )
+        .await
+        else {
+            panic!("A series needs an existing studio")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_create_series_default_duration_of_the_studio() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(1)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        let mut found = studio(&studio_id);
+        found.default_duration = MeetingDuration::try_from(45).unwrap();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .return_once(move |_| Box::pin(async move { Ok(Some(found)) }));
+        mock_repo
+            .expect_list_meetings()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo
+            .expect_create_series()
+            .once()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let (_, meetings) = create_meeting_series(
+            &mock_repo,
+            MeetingName::try_from("Stand-up".to_string()).unwrap(),
+            date,
+            None,
+            JoinWindow::default(),
+            Some(12),
+            RecurrenceRule::from_str("FREQ=WEEKLY;COUNT=2").unwrap(),
+            studio_id,
+            Utc::now(),
+        )
+        .await
+        .expect("The series should be created");
+        assert!(meetings.iter().all(
+            |meeting| meeting.duration.minutes() == 45 && meeting.max_participants == Some(12)
+        ));
+    }
+}
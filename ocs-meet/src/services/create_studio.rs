@@ -0,0 +1,166 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        meeting::MeetingDuration,
+        member::{Member, MemberRole},
+        studio::{Studio, StudioId, StudioName},
+        user::UserId,
+    },
+    ports::output::{
+        member_repository::{MemberRepository, MemberRepositoryError},
+        studio_repository::{StudioRepository, StudioRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum CreateStudioError {
+    #[error("The studio already exists")]
+    AlreadyExists,
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+    #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+}
+
+/// Registers the studio, in UTC with one hour meetings unless told otherwise, the user creating it
+/// becomes its owner
+pub async fn create_studio(
+    repo: &(impl StudioRepository + MemberRepository),
+    studio_id: StudioId,
+    owner: Option<UserId>,
+    name: StudioName,
+    timezone: Option<Tz>,
+    default_duration: Option<MeetingDuration>,
+    today: DateTime<Utc>,
+) -> Result<Studio, CreateStudioError> {
+    if repo
+        .find_studio(&studio_id)
+        .await
+        .map_err(CreateStudioError::from)?
+        .is_some()
+    {
+        return Err(CreateStudioError::AlreadyExists);
+    }
+
+    let studio = Studio {
+        id: studio_id,
+        name,
+        timezone: timezone.unwrap_or(Tz::UTC),
+        default_duration: default_duration.unwrap_or_default(),
+        created_at: today,
+    };
+
+    repo.create_studio(&studio)
+        .await
+        .map_err(CreateStudioError::from)?;
+
+    if let Some(user_id) = owner {
+        let member = Member {
+            studio_id: studio.id.clone(),
+            user_id,
+            display_name: None,
+            role: MemberRole::Owner,
+            created_at: today,
+        };
+        repo.add_member(&member)
+            .await
+            .map_err(CreateStudioError::from)?;
+    }
+
+    Ok(studio)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            meeting::MeetingDuration,
+            member::MemberRole,
+            studio::{Studio, StudioId, StudioName},
+            user::UserId,
+        },
+        ports::output::repository::MockRepository,
+        services::{create_studio, CreateStudioError},
+    };
+
+    /// A studio with the default settings
+    pub(crate) fn studio(studio_id: &StudioId) -> Studio {
+        Studio {
+            id: studio_id.clone(),
+            name: StudioName::try_from("Hello studio".to_string()).unwrap(),
+            timezone: chrono_tz::UTC,
+            default_duration: MeetingDuration::default(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_studio_ok() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .with(eq(studio_id.clone()))
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo
+            .expect_create_studio()
+            .once()
+            .withf(|studio| studio.timezone == chrono_tz::UTC)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_add_member()
+            .once()
+            .withf(|member| member.role == MemberRole::Owner)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let studio = create_studio(
+            &mock_repo,
+            studio_id,
+            Some(UserId::from("alice".to_string())),
+            StudioName::try_from("Yoga studio".to_string()).unwrap(),
+            None,
+            None,
+            Utc::now(),
+        )
+        .await
+        .expect("The studio should be created");
+        assert_eq!(studio.default_duration, MeetingDuration::default());
+    }
+
+    #[tokio::test]
+    async fn test_create_studio_twice() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .returning(|studio_id| {
+                let existing = studio(studio_id);
+                Box::pin(async move { Ok(Some(existing)) })
+            });
+        mock_repo.expect_create_studio().never();
+
+        let Err(CreateStudioError::AlreadyExists) = create_studio(
+            &mock_repo,
+            studio_id,
+            None,
+            StudioName::try_from("Yoga studio".to_string()).unwrap(),
+            None,
+            None,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The studio should not be created twice")
+        };
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::{
+    domain::studio::StudioId,
+    ports::output::studio_repository::{StudioRepository, StudioRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum DeleteStudioError {
+    #[error("The studio has not been created")]
+    NotFound,
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+}
+
+/// Deletes the studio, its meetings are kept but no new one can be created
+pub async fn delete_studio(
+    repo: &impl StudioRepository,
+    studio_id: StudioId,
+) -> Result<(), DeleteStudioError> {
+    let deleted = repo
+        .delete_studio(&studio_id)
+        .await
+        .map_err(DeleteStudioError::from)?;
+
+    if !deleted {
+        return Err(DeleteStudioError::NotFound);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        meeting::{Meeting, MeetingId, MeetingStatus},
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum EndMeetingError {
+    #[error("The meeting you're looking for does not exist")]
+    NotFound,
+    #[error("The meeting has been cancelled")]
+    Cancelled,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Ends the meeting for everyone: nobody can join it anymore and its room is closed
+///
+/// The meeting is marked as ended before closing the room, so nobody gets back in meanwhile. Ending
+/// it again closes the room again, in case it failed the first time.
+pub async fn end_meeting(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+) -> Result<Meeting, EndMeetingError> {
+    let Some(mut meeting) = repo
+        .find_meeting(&meeting_id)
+        .await
+        .map_err(EndMeetingError::from)?
+        .filter(|meeting| meeting.studio_id == studio_id)
+    else {
+        return Err(EndMeetingError::NotFound);
+    };
+
+    match meeting.status {
+        MeetingStatus::Scheduled | MeetingStatus::Live => {
+            meeting.status = MeetingStatus::Ended;
+            repo.update_meeting(&meeting)
+                .await
+                .map_err(EndMeetingError::from)?;
+        }
+        MeetingStatus::Ended => {}
+        MeetingStatus::Cancelled => return Err(EndMeetingError::Cancelled),
+    }
+
+    room_manager
+        .delete_room(&meeting.id)
+        .await
+        .map_err(EndMeetingError::from)?;
+
+    Ok(meeting)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            studio::StudioId,
+        },
+        ports::output::{meeting_repository::MockMeetingRepository, room_manager::MockRoomManager},
+        services::{end_meeting, EndMeetingError},
+    };
+
+    fn expect_find_meeting(
+        mock_repo: &mut MockMeetingRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+        status: MeetingStatus,
+    ) {
+        let studio_id = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(move |meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date: Utc::now(),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_end_ok() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            MeetingStatus::Scheduled,
+        );
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .withf(|meeting| meeting.status == MeetingStatus::Ended)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let meeting = end_meeting(&mock_repo, &mock_room_manager, meeting_id, studio_id)
+            .await
+            .expect("The meeting should be ended");
+        assert_eq!(meeting.status, MeetingStatus::Ended);
+    }
+
+    #[tokio::test]
+    async fn test_end_twice() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            MeetingStatus::Ended,
+        );
+        mock_repo.expect_update_meeting().never();
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        end_meeting(&mock_repo, &mock_room_manager, meeting_id, studio_id)
+            .await
+            .expect("Ending a meeting again should close its room again");
+    }
+
+    #[tokio::test]
+    async fn test_end_cancelled() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            MeetingStatus::Cancelled,
+        );
+        mock_repo.expect_update_meeting().never();
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_delete_room().never();
+
+        let Err(EndMeetingError::Cancelled) =
+            end_meeting(&mock_repo, &mock_room_manager, meeting_id, studio_id).await
+        else {
+            panic!("A cancelled meeting cannot be ended");
+        };
+    }
+}
@@ -0,0 +1,131 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        meeting::{Meeting, MeetingId},
+        studio::StudioId,
+    },
+    ports::output::meeting_repository::{MeetingRepository, MeetingRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum GetMeetingError {
+    #[error("The meeting you're looking for does not exist")]
+    NotFound,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+}
+
+pub async fn get_meeting(
+    repo: &impl MeetingRepository,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+) -> Result<Meeting, GetMeetingError> {
+    let meeting = repo
+        .find_meeting(&meeting_id)
+        .await
+        .map_err(GetMeetingError::from)?;
+
+    // A meeting of another studio is reported as missing, so its existence is not leaked
+    match meeting {
+        Some(meeting) if meeting.studio_id == studio_id => Ok(meeting),
+        _ => Err(GetMeetingError::NotFound),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            studio::StudioId,
+        },
+        ports::output::meeting_repository::MockMeetingRepository,
+        services::{get_meeting, GetMeetingError},
+    };
+
+    fn expect_find_meeting(
+        mock_repo: &mut MockMeetingRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+    ) {
+        let studio_id = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(move |meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_get_ok() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id);
+
+        let meeting = get_meeting(&mock_repo, meeting_id.clone(), studio_id)
+            .await
+            .expect("The meeting should be found");
+        assert_eq!(meeting.id, meeting_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_unknown() {
+        let mut mock_repo = MockMeetingRepository::new();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let Err(GetMeetingError::NotFound) = get_meeting(
+            &mock_repo,
+            MeetingId::new(),
+            StudioId::from(uuid::Uuid::new_v4()),
+        )
+        .await
+        else {
+            panic!("An unknown meeting should not be found")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_get_other_studio() {
+        let meeting_id = MeetingId::new();
+        let owner = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &owner);
+
+        let Err(GetMeetingError::NotFound) =
+            get_meeting(&mock_repo, meeting_id, StudioId::from(uuid::Uuid::new_v4())).await
+        else {
+            panic!("The meeting should not be visible from another studio")
+        };
+    }
+}
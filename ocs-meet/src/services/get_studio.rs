@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+use crate::{
+    domain::studio::{Studio, StudioId},
+    ports::output::studio_repository::{StudioRepository, StudioRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum GetStudioError {
+    #[error("The studio has not been created")]
+    NotFound,
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+}
+
+pub async fn get_studio(
+    repo: &impl StudioRepository,
+    studio_id: StudioId,
+) -> Result<Studio, GetStudioError> {
+    repo.find_studio(&studio_id)
+        .await
+        .map_err(GetStudioError::from)?
+        .ok_or(GetStudioError::NotFound)
+}
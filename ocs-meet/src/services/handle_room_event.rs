@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::room::{RoomEvent, RoomEventKind},
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_event_repository::{RoomEventRepository, RoomEventRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum HandleRoomEventError {
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    RoomEventRepository(#[from] RoomEventRepositoryError),
+}
+
+/// Follows the meeting through what happens in its room: it goes live when the first participant
+/// enters and ends when the room is closed
+///
+/// The room is opened as the first participant is let in, before they enter it, so its start
+/// leaves the meeting as is. It is also closed when the meeting is rescheduled, so a meeting
+/// nobody entered only ends once its join window has closed. The events of rooms which are not
+/// the room of a meeting are ignored.
+///
+/// LiveKit delivers an event again when unsure we got it, so the events already handled are
+/// skipped, and the meeting only moves forward so that late deliveries leave it untouched.
+pub async fn handle_room_event(
+    repo: &(impl MeetingRepository + RoomEventRepository),
+    event: RoomEvent,
+    today: DateTime<Utc>,
+) -> Result<(), HandleRoomEventError> {
+    if repo.has_event(&event.id).await? {
+        return Ok(());
+    }
+
+    let Some(mut meeting) = repo.find_meeting(&event.meeting_id).await? else {
+        return Ok(());
+    };
+
+    let changed = match event.kind {
+        RoomEventKind::ParticipantJoined => meeting.start(event.occurred_at),
+        RoomEventKind::RoomFinished => meeting.finish(event.occurred_at),
+        RoomEventKind::RoomStarted | RoomEventKind::ParticipantLeft => false,
+    };
+    if changed {
+        repo.update_meeting(&meeting).await?;
+    }
+
+    // Kept once handled, so that a failure lets the next delivery try again
+    repo.record_event(&event, today).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::{RoomEvent, RoomEventKind},
+            studio::StudioId,
+        },
+        ports::output::repository::MockRepository,
+        services::handle_room_event,
+    };
+
+    fn meeting(meeting_id: &MeetingId, status: MeetingStatus) -> Meeting {
+        Meeting {
+            id: meeting_id.clone(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date: Utc::now(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        }
+    }
+
+    fn event(meeting_id: &MeetingId, kind: RoomEventKind) -> RoomEvent {
+        RoomEvent {
+            id: "EV_4Xk9wTz".to_string(),
+            meeting_id: meeting_id.clone(),
+            kind,
+            occurred_at: Utc::now() - TimeDelta::seconds(3),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_participant_joined() {
+        let meeting_id = MeetingId::new();
+        let room_event = event(&meeting_id, RoomEventKind::ParticipantJoined);
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_has_event()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        mock_repo.expect_find_meeting().returning(|meeting_id| {
+            let meeting = meeting(meeting_id, MeetingStatus::Scheduled);
+            Box::pin(async move { Ok(Some(meeting)) })
+        });
+        let started_at = room_event.occurred_at;
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .withf(move |meeting| {
+                meeting.status == MeetingStatus::Live && meeting.started_at == Some(started_at)
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_record_event()
+            .once()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        handle_room_event(&mock_repo, room_event, Utc::now())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_room_finished_before_the_window_closes() {
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_has_event()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        mock_repo.expect_find_meeting().returning(|meeting_id| {
+            let meeting = meeting(meeting_id, MeetingStatus::Scheduled);
+            Box::pin(async move { Ok(Some(meeting)) })
+        });
+        mock_repo.expect_update_meeting().never();
+        mock_repo
+            .expect_record_event()
+            .once()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        handle_room_event(
+            &mock_repo,
+            event(&meeting_id, RoomEventKind::RoomFinished),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_event_delivered_again() {
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_has_event()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        mock_repo.expect_find_meeting().never();
+        mock_repo.expect_update_meeting().never();
+        mock_repo.expect_record_event().never();
+
+        handle_room_event(
+            &mock_repo,
+            event(&meeting_id, RoomEventKind::RoomFinished),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_meeting_untouched() {
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_has_event()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        mock_repo.expect_find_meeting().returning(|meeting_id| {
+            let meeting = meeting(meeting_id, MeetingStatus::Cancelled);
+            Box::pin(async move { Ok(Some(meeting)) })
+        });
+        mock_repo.expect_update_meeting().never();
+        mock_repo
+            .expect_record_event()
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        for kind in [
+            RoomEventKind::ParticipantJoined,
+            RoomEventKind::RoomFinished,
+        ] {
+            handle_room_event(&mock_repo, event(&meeting_id, kind), Utc::now())
+                .await
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::create_meeting::{find_meeting_studio, find_overlapping_meeting};
+use crate::{
+    domain::{
+        meeting::{JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus},
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        studio_repository::{StudioRepository, StudioRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum ImportMeetingsError {
+    #[error("The studio has not been created")]
+    StudioNotFound,
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+}
+
+/// An event read from an imported calendar
+pub struct ImportedEvent {
+    /// UID of the event in the calendar, to match the report with the file
+    pub uid: Option<String>,
+    /// The event fields, or why they could not be read from the calendar
+    pub fields: Result<ImportedEventFields, String>,
+}
+
+pub struct ImportedEventFields {
+    pub summary: String,
+    pub date: DateTime<Utc>,
+    /// Duration of the event in minutes, the default one of the studio when the event does not tell
+    pub duration_minutes: Option<u32>,
+}
+
+pub enum ImportOutcome {
+    /// The meeting created from the event, or that would be on a dry run
+    Created(Meeting),
+    SkippedPast,
+    /// The event overlaps this meeting, already in the studio or imported before it
+    Conflict(MeetingId),
+    Invalid(String),
+    /// The meeting could not be checked or saved, the event can be imported again on its own
+    Failed(String),
+}
+
+pub struct ImportReportEntry {
+    pub uid: Option<String>,
+    pub outcome: ImportOutcome,
+}
+
+/// Creates a meeting for each event of an imported calendar following the rules of
+/// `create_meeting`, reporting what happened to every event
+///
+/// A dry run goes through the same checks but creates nothing. An event failing to be saved is
+/// reported as such and the import carries on, the meetings created so far being kept.
+pub async fn import_meetings(
+    repo: &(impl MeetingRepository + StudioRepository),
+    studio_id: StudioId,
+    events: Vec<ImportedEvent>,
+    max_participants: Option<u32>,
+    dry_run: bool,
+    today: DateTime<Utc>,
+) -> Result<Vec<ImportReportEntry>, ImportMeetingsError> {
+    let studio = find_meeting_studio(repo, &studio_id, ImportMeetingsError::StudioNotFound).await?;
+
+    // The meetings accepted so far, for the events of the calendar to not overlap each other on a
+    // dry run as well
+    let mut accepted: Vec<Meeting> = Vec::new();
+    let mut report = Vec::with_capacity(events.len());
+
+    for ImportedEvent { uid, fields } in events {
+        let meeting = fields.and_then(|fields| {
+            Ok(Meeting {
+                id: MeetingId::new(),
+                studio_id: studio_id.clone(),
+                name: MeetingName::try_from(fields.summary).map_err(|error| error.to_string())?,
+                date: fields.date,
+                duration: studio.meeting_duration(
+                    fields
+                        .duration_minutes
+                        .map(MeetingDuration::try_from)
+                        .transpose()
+                        .map_err(|error| error.to_string())?,
+                ),
+                join_window: JoinWindow::default(),
+                status: MeetingStatus::Scheduled,
+                series_id: None,
+                passcode: None,
+                waiting_room: false,
+                max_participants,
+                started_at: None,
+                ended_at: None,
+            })
+        });
+
+        let outcome = match meeting {
+            Err(reason) => ImportOutcome::Invalid(reason),
+            Ok(meeting) if meeting.date < today => ImportOutcome::SkippedPast,
+            Ok(meeting) => import_meeting(repo, &studio_id, &mut accepted, meeting, dry_run)
+                .await
+                .unwrap_or_else(|error| ImportOutcome::Failed(error.to_string())),
+        };
+
+        report.push(ImportReportEntry { uid, outcome });
+    }
+
+    Ok(report)
+}
+
+/// Creates the meeting of an event unless it overlaps another one, `accepted` holding the meetings
+/// of the events imported before it
+async fn import_meeting(
+    repo: &impl MeetingRepository,
+    studio_id: &StudioId,
+    accepted: &mut Vec<Meeting>,
+    meeting: Meeting,
+    dry_run: bool,
+) -> Result<ImportOutcome, MeetingRepositoryError> {
+    let conflict = match accepted
+        .iter()
+        .find(|other| other.overlaps(meeting.date, meeting.end_date()))
+    {
+        Some(other) => Some(other.id.clone()),
+        None => find_overlapping_meeting(repo, studio_id, meeting.date, meeting.end_date(), None)
+            .await?
+            .map(|other| other.id),
+    };
+
+    if let Some(conflict) = conflict {
+        return Ok(ImportOutcome::Conflict(conflict));
+    }
+
+    if !dry_run {
+        repo.create_meeting(&meeting).await?;
+    }
+    accepted.push(meeting.clone());
+    Ok(ImportOutcome::Created(meeting))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Days, TimeDelta, Utc};
+
+    use crate::{
+        domain::{meeting::MeetingDuration, studio::StudioId},
+        ports::output::{
+            meeting_repository::{MeetingQuery, MeetingRepositoryError},
+            repository::MockRepository,
+        },
+        services::{
+            create_studio::tests::studio, import_meetings, ImportMeetingsError, ImportOutcome,
+            ImportedEvent, ImportedEventFields,
+        },
+    };
+
+    fn expect_find_studio(mock_repo: &mut MockRepository) {
+        mock_repo.expect_find_studio().returning(|studio_id| {
+            let found = studio(studio_id);
+            Box::pin(async move { Ok(Some(found)) })
+        });
+    }
+
+    fn event(uid: &str, summary: &str, date: DateTime<Utc>) -> ImportedEvent {
+        ImportedEvent {
+            uid: Some(uid.to_string()),
+            fields: Ok(ImportedEventFields {
+                summary: summary.to_string(),
+                date,
+                duration_minutes: Some(45),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_report() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let events = vec![
+            event("created", "Kick-off", date),
+            event("past", "Retrospective", Utc::now() - TimeDelta::days(2)),
+            event("overlapping", "Review", date + TimeDelta::minutes(30)),
+            event("empty", "", date + TimeDelta::days(1)),
+            ImportedEvent {
+                uid: None,
+                fields: Err("All-day events are not supported".to_string()),
+            },
+        ];
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo);
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo
+            .expect_create_meeting()
+            .once()
+            .withf(|meeting| {
+                meeting.name.as_ref() == "Kick-off"
+                    && meeting.duration.minutes() == 45
+                    && meeting.max_participants == Some(8)
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let report = import_meetings(&mock_repo, studio_id, events, Some(8), false, Utc::now())
+            .await
+            .unwrap();
+
+        let ImportOutcome::Created(created) = &report[0].outcome else {
+            panic!("The first event should be created")
+        };
+        assert!(matches!(report[1].outcome, ImportOutcome::SkippedPast));
+        let ImportOutcome::Conflict(conflict) = &report[2].outcome else {
+            panic!("The third event should overlap the first one")
+        };
+        assert_eq!(conflict, &created.id);
+        assert!(matches!(report[3].outcome, ImportOutcome::Invalid(_)));
+        assert!(matches!(report[4].outcome, ImportOutcome::Invalid(_)));
+    }
+
+    #[tokio::test]
+    async fn test_import_carries_on_after_a_failure() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo);
+        mock_repo
+            .expect_list_meetings()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo
+            .expect_create_meeting()
+            .times(2)
+            .returning(|meeting| {
+                let failed = meeting.name.as_ref() == "Kick-off";
+                Box::pin(async move {
+                    match failed {
+                        true => Err(MeetingRepositoryError::SqlxError(sqlx::Error::PoolTimedOut)),
+                        false => Ok(()),
+                    }
+                })
+            });
+
+        let events = vec![
+            event("first", "Kick-off", date),
+            event("second", "Review", date + TimeDelta::days(1)),
+        ];
+        let report = import_meetings(&mock_repo, studio_id, events, None, false, Utc::now())
+            .await
+            .expect("The import should report the failed event");
+        assert!(matches!(report[0].outcome, ImportOutcome::Failed(_)));
+        assert!(matches!(report[1].outcome, ImportOutcome::Created(_)));
+    }
+
+    #[tokio::test]
+    async fn test_import_dry_run() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        expect_find_studio(&mut mock_repo);
+        mock_repo
+            .expect_list_meetings()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo.expect_create_meeting().never();
+
+        let events = vec![
+            event("first", "Kick-off", date),
+            event("second", "Review", date + TimeDelta::days(1)),
+        ];
+        let report = import_meetings(&mock_repo, studio_id, events, None, true, Utc::now())
+            .await
+            .unwrap();
+        assert!(report
+            .iter()
+            .all(|entry| matches!(entry.outcome, ImportOutcome::Created(_))));
+    }
+
+    #[tokio::test]
+    async fn test_import_unknown_studio() {
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_repo.expect_create_meeting().never();
+
+        let Err(ImportMeetingsError::StudioNotFound) = import_meetings(
+            &mock_repo,
+            StudioId::from(uuid::Uuid::new_v4()),
+            vec![event("first", "Kick-off", date)],
+            None,
+            false,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("An import needs an existing studio")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_import_default_duration_of_the_studio() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockRepository::new();
+        let mut found = studio(&studio_id);
+        found.default_duration = MeetingDuration::try_from(30).unwrap();
+        mock_repo
+            .expect_find_studio()
+            .once()
+            .return_once(move |_| Box::pin(async move { Ok(Some(found)) }));
+        mock_repo
+            .expect_list_meetings()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo
+            .expect_create_meeting()
+            .once()
+            .withf(|meeting| meeting.duration.minutes() == 30)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let mut untimed = event("untimed", "Kick-off", date);
+        if let Ok(fields) = &mut untimed.fields {
+            fields.duration_minutes = None;
+        }
+        let report = import_meetings(
+            &mock_repo,
+            studio_id,
+            vec![untimed],
+            None,
+            false,
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(report[0].outcome, ImportOutcome::Created(_)));
+    }
+}
@@ -0,0 +1,185 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        invitation::{Invitation, InvitationId, InvitationKey, InvitationToken},
+        meeting::{MeetingId, MeetingStatus},
+        room::ParticipantRole,
+        studio::StudioId,
+    },
+    ports::output::{
+        invitation_repository::{InvitationRepository, InvitationRepositoryError},
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum InviteGuestError {
+    #[error("The meeting you're looking for does not exist")]
+    NotFound,
+    #[error("The meeting has been cancelled")]
+    Cancelled,
+    #[error("Cannot create an invitation already expired")]
+    ExpiryInThePast,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    InvitationRepository(#[from] InvitationRepositoryError),
+}
+
+/// Invites a guest to a meeting of the studio, the returned token is what the link carries
+///
+/// The invitation expires when the meeting cannot be joined anymore unless told otherwise.
+#[allow(clippy::too_many_arguments)]
+pub async fn invite_guest(
+    repository: &(impl MeetingRepository + InvitationRepository),
+    key: &InvitationKey,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+    display_name: String,
+    role: ParticipantRole,
+    max_uses: Option<u32>,
+    expires_at: Option<DateTime<Utc>>,
+    today: DateTime<Utc>,
+) -> Result<(Invitation, InvitationToken), InviteGuestError> {
+    let Some(meeting) = repository
+        .find_meeting(&meeting_id)
+        .await
+        .map_err(InviteGuestError::from)?
+        .filter(|meeting| meeting.studio_id == studio_id)
+    else {
+        return Err(InviteGuestError::NotFound);
+    };
+
+    if meeting.status == MeetingStatus::Cancelled {
+        return Err(InviteGuestError::Cancelled);
+    }
+
+    let expires_at = expires_at.unwrap_or_else(|| meeting.join_closes_at());
+    if expires_at <= today {
+        return Err(InviteGuestError::ExpiryInThePast);
+    }
+
+    let invitation = Invitation {
+        id: InvitationId::new(),
+        meeting_id: meeting.id,
+        studio_id,
+        display_name,
+        role,
+        max_uses,
+        uses: 0,
+        expires_at,
+        revoked_at: None,
+        created_at: today,
+    };
+
+    repository
+        .create_invitation(&invitation)
+        .await
+        .map_err(InviteGuestError::from)?;
+
+    let token = key.sign(&invitation.id);
+    Ok((invitation, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InviteGuestError;
+    use crate::{
+        domain::{
+            invitation::InvitationKey,
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::ParticipantRole,
+            studio::StudioId,
+        },
+        ports::output::repository::MockRepository,
+        services::invite_guest,
+    };
+    use chrono::{TimeDelta, Utc};
+
+    #[tokio::test]
+    async fn test_invite_guest() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meeting_id = MeetingId::new();
+        let date = Utc::now() + TimeDelta::days(1);
+        let key = InvitationKey::from("a secret only known by the tests".to_string());
+
+        let mut mock_repo = MockRepository::new();
+        let meeting_studio = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: meeting_studio.clone(),
+                    name: MeetingName::try_from("Hello there!".to_string()).unwrap(),
+                    date,
+                    duration: MeetingDuration::try_from(60).unwrap(),
+                    join_window: JoinWindow::new(15, 5).unwrap(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+        mock_repo
+            .expect_create_invitation()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let (invitation, token) = invite_guest(
+            &mock_repo,
+            &key,
+            meeting_id.clone(),
+            studio_id.clone(),
+            "Jane Doe".to_string(),
+            ParticipantRole::Speaker,
+            Some(1),
+            None,
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(key.verify(&token), Some(invitation.id));
+        assert_eq!(invitation.expires_at, date + TimeDelta::minutes(65));
+
+        let Err(InviteGuestError::ExpiryInThePast) = invite_guest(
+            &mock_repo,
+            &key,
+            meeting_id.clone(),
+            studio_id,
+            "Jane Doe".to_string(),
+            ParticipantRole::Speaker,
+            None,
+            Some(Utc::now() - TimeDelta::hours(1)),
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("An invitation cannot be expired from the start");
+        };
+
+        let Err(InviteGuestError::NotFound) = invite_guest(
+            &mock_repo,
+            &key,
+            meeting_id,
+            StudioId::from(uuid::Uuid::new_v4()),
+            "Jane Doe".to_string(),
+            ParticipantRole::Speaker,
+            None,
+            None,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meetings of other studios cannot be shared");
+        };
+    }
+}
@@ -1,46 +1,246 @@
 use crate::{
-    domain::{meeting::MeetingId, room::RoomToken},
+    domain::{
+        meeting::{Meeting, MeetingId, MeetingStatus},
+        member::Permission,
+        passcode::PasscodeHash,
+        room::{Participant, ParticipantRole, RoomSettings, RoomToken},
+        user::Identity,
+        waiting_room::Admission,
+    },
     ports::output::{
         meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        member_repository::{MemberRepository, MemberRepositoryError},
         room_manager::{RoomManager, RoomManagerError},
+        waiting_room_repository::{WaitingRoomRepository, WaitingRoomRepositoryError},
+    },
+    services::{
+        authorize,
+        waiting_room::{enter_waiting_room, must_wait},
+        AuthorizeError,
     },
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum JoinMeetingError {
     #[error("The meeting you're looking for does not exist")]
     NotFound,
+    #[error("It's too early to join the meeting")]
+    TooEarly,
     #[error("It's too late to join the meeting")]
     TooLate,
+    #[error("The meeting has been cancelled")]
+    Cancelled,
+    #[error("The meeting has ended")]
+    Ended,
+    #[error("The meeting is full")]
+    Full,
+    #[error("The participant has to be named when the token names no user")]
+    ParticipantMissing,
+    #[error("Only the members managing the meetings can let hosts and recorders in")]
+    RoleNotAllowed,
+    #[error("The meeting needs a passcode")]
+    PasscodeRequired,
+    #[error("Wrong passcode")]
+    WrongPasscode,
+    #[error("Too many wrong passcodes, try again later")]
+    TooManyAttempts,
+    #[error(transparent)]
+    Authorize(#[from] AuthorizeError),
     #[error(transparent)]
     MeetingRepository(#[from] MeetingRepositoryError),
     #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+    #[error(transparent)]
+    WaitingRoomRepository(#[from] WaitingRoomRepositoryError),
+    #[error(transparent)]
     RoomManager(#[from] RoomManagerError),
 }
 
-pub async fn join_meeting(
+/// Wrong passcodes accepted from a caller for a meeting within `PASSCODE_FAILURE_WINDOW` before
+/// refusing any
+const MAX_PASSCODE_FAILURES: u32 = 5;
+const PASSCODE_FAILURE_WINDOW: TimeDelta = TimeDelta::minutes(15);
+
+/// Tells apart the callers entering passcodes: the API key used or the backend of the studio
+///
+/// The participant they name is left out, it is chosen by the caller and would let it guess
+/// without limit under a new name each time.
+fn passcode_caller(identity: &Identity) -> String {
+    match &identity.api_key_id {
+        Some(api_key_id) => api_key_id.as_ref().to_string(),
+        None => "backend".to_string(),
+    }
+}
+
+/// Checks the passcode given to join a meeting, refusing all of them from the caller for a while
+/// after a few wrong ones so they cannot be guessed, the other callers being let in meanwhile
+pub(crate) async fn check_passcode(
     repository: &impl MeetingRepository,
+    meeting_id: &MeetingId,
+    caller: &str,
+    hash: &PasscodeHash,
+    passcode: Option<&str>,
+    today: DateTime<Utc>,
+) -> Result<(), JoinMeetingError> {
+    let failures = repository
+        .count_passcode_failures(meeting_id, caller, today - PASSCODE_FAILURE_WINDOW)
+        .await
+        .map_err(JoinMeetingError::from)?;
+    if failures >= MAX_PASSCODE_FAILURES {
+        return Err(JoinMeetingError::TooManyAttempts);
+    }
+
+    let Some(passcode) = passcode else {
+        return Err(JoinMeetingError::PasscodeRequired);
+    };
+
+    if !hash.verify(passcode) {
+        repository
+            .record_passcode_failure(meeting_id, caller, today)
+            .await
+            .map_err(JoinMeetingError::from)?;
+        return Err(JoinMeetingError::WrongPasscode);
+    }
+
+    Ok(())
+}
+
+/// Whether the room of the meeting holds as many participants as it can, not counting the one
+/// coming back with the same identity if any
+pub(crate) async fn is_full(
+    room_manager: &impl RoomManager,
+    meeting: &Meeting,
+    identity: Option<&str>,
+) -> Result<bool, RoomManagerError> {
+    let Some(max_participants) = meeting.max_participants else {
+        return Ok(false);
+    };
+
+    let participants = room_manager.list_participants(&meeting.id).await?;
+    let others = participants
+        .iter()
+        .filter(|participant| Some(participant.identity.as_str()) != identity)
+        .count();
+
+    Ok(others >= max_participants as usize)
+}
+
+/// Hands out a token to enter the room of the meeting, opening the room first with the settings
+/// of the meeting
+///
+/// Rooms are opened once their meeting is joined rather than when it is scheduled, so that LiveKit
+/// does not keep them for the months before. Opening a room already open leaves it as it is.
+pub(crate) async fn enter_room(
+    room_manager: &impl RoomManager,
+    meeting: &Meeting,
+    participant: Participant,
+    role: ParticipantRole,
+    today: DateTime<Utc>,
+) -> Result<RoomToken, RoomManagerError> {
+    room_manager
+        .create_room(&meeting.id, RoomSettings::for_meeting(meeting, today))
+        .await?;
+    room_manager
+        .create_token(meeting.id.clone(), participant, role)
+        .await
+}
+
+/// Hands out a token to enter the room of a meeting of the studio while it is open
+///
+/// Users enter under their id and the name given by their membership or their token, as hosts
+/// unless they are viewers of the studio. The tokens naming no user, used by backends and API keys
+/// on behalf of someone else, name the participant and their role, a speaker by default, and give
+/// the passcode of the meeting if it has one. When the meeting has a waiting room, these
+/// participants get a ticket to wait for a host instead of a token, unless they host or record.
+#[allow(clippy::too_many_arguments)]
+pub async fn join_meeting(
+    repository: &(impl MeetingRepository + MemberRepository + WaitingRoomRepository),
     room_manager: &impl RoomManager,
+    identity: &Identity,
+    on_behalf_of: Option<Participant>,
+    role: Option<ParticipantRole>,
+    passcode: Option<String>,
     meeting_id: MeetingId,
     today: DateTime<Utc>,
-) -> Result<RoomToken, JoinMeetingError> {
+) -> Result<Admission, JoinMeetingError> {
+    let access = authorize(repository, identity, Permission::JoinMeetings).await?;
+
     let Some(meeting) = repository
         .find_meeting(&meeting_id)
         .await
         .map_err(JoinMeetingError::from)?
+        .filter(|meeting| meeting.studio_id == access.studio_id)
     else {
         return Err(JoinMeetingError::NotFound);
     };
 
-    if meeting.date < today {
+    match meeting.status {
+        MeetingStatus::Scheduled | MeetingStatus::Live => {}
+        MeetingStatus::Cancelled => return Err(JoinMeetingError::Cancelled),
+        MeetingStatus::Ended => return Err(JoinMeetingError::Ended),
+    }
+
+    if today < meeting.join_opens_at() {
+        return Err(JoinMeetingError::TooEarly);
+    }
+
+    if today > meeting.join_closes_at() {
         return Err(JoinMeetingError::TooLate);
     }
 
-    room_manager
-        .create_token(meeting.id)
+    let (participant, role) = match &identity.user_id {
+        Some(user_id) => {
+            let member = repository
+                .find_member(&access.studio_id, user_id)
+                .await
+                .map_err(JoinMeetingError::from)?;
+            let name = member
+                .and_then(|member| member.display_name)
+                .or_else(|| identity.display_name.clone())
+                .unwrap_or_else(|| user_id.as_ref().clone());
+            let participant = Participant {
+                identity: user_id.as_ref().clone(),
+                name,
+            };
+            (participant, ParticipantRole::from(access.role))
+        }
+        None => {
+            let participant = on_behalf_of.ok_or(JoinMeetingError::ParticipantMissing)?;
+            let role = role.unwrap_or(ParticipantRole::Speaker);
+            if role.is_privileged() && !access.role.allows(Permission::ManageMeetings) {
+                return Err(JoinMeetingError::RoleNotAllowed);
+            }
+            if let Some(hash) = &meeting.passcode {
+                let caller = passcode_caller(identity);
+                check_passcode(
+                    repository,
+                    &meeting.id,
+                    &caller,
+                    hash,
+                    passcode.as_deref(),
+                    today,
+                )
+                .await?;
+            }
+            if must_wait(&meeting, role) {
+                let ticket = enter_waiting_room(repository, &meeting, participant, role, today)
+                    .await
+                    .map_err(JoinMeetingError::from)?;
+                return Ok(Admission::Waiting(ticket));
+            }
+            (participant, role)
+        }
+    };
+
+    if is_full(room_manager, &meeting, Some(&participant.identity)).await? {
+        return Err(JoinMeetingError::Full);
+    }
+
+    enter_room(room_manager, &meeting, participant, role, today)
         .await
+        .map(Admission::Granted)
         .map_err(JoinMeetingError::from)
 }
 
@@ -49,21 +249,81 @@ mod tests {
     use super::JoinMeetingError;
     use crate::{
         domain::{
-            meeting::{Meeting, MeetingId, MeetingName},
-            room::RoomToken,
+            api_key::{ApiKeyId, ApiKeyScope},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            member::{Member, MemberRole},
+            passcode::MeetingPasscode,
+            room::{Participant, ParticipantRole, RoomParticipant, RoomToken},
             studio::StudioId,
+            user::{Identity, UserId},
+            waiting_room::{Admission, AdmissionStatus},
         },
-        ports::output::{meeting_repository::MockMeetingRepository, room_manager::MockRoomManager},
+        ports::output::{repository::MockRepository, room_manager::MockRoomManager},
         services::join_meeting,
     };
-    use chrono::{Days, Utc};
+    use chrono::{DateTime, Days, TimeDelta, Utc};
     use mockall::predicate::eq;
 
+    /// The backend of the studio, acting as its owner
+    fn backend(studio_id: &StudioId) -> Identity {
+        Identity {
+            studio_id: studio_id.clone(),
+            user_id: None,
+            display_name: None,
+            scopes: None,
+            api_key_id: None,
+        }
+    }
+
+    fn customer() -> Option<Participant> {
+        Some(Participant {
+            identity: "customer-42".to_string(),
+            name: "Jane Doe".to_string(),
+        })
+    }
+
+    fn expect_find_meeting(
+        mock_repo: &mut MockRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+        date: DateTime<Utc>,
+        status: MeetingStatus,
+    ) {
+        let studio_id = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(move |meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello there!".to_string()).unwrap(),
+                        date,
+                        duration: MeetingDuration::try_from(60).unwrap(),
+                        join_window: JoinWindow::new(15, 5).unwrap(),
+                        status,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+    }
+
     #[tokio::test]
     async fn test_not_found() {
         let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
         let mock_room_manager = MockRoomManager::new();
 
         mock_repo
@@ -72,75 +332,790 @@ mod tests {
             .with(eq(meeting_id.clone()))
             .return_once(|_| Box::pin(async { Ok(None) }));
 
-        let Err(JoinMeetingError::NotFound) =
-            join_meeting(&mock_repo, &mock_room_manager, meeting_id, Utc::now()).await
+        let Err(JoinMeetingError::NotFound) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
         else {
             panic!("The meeting should not be found");
         };
     }
 
+    #[tokio::test]
+    async fn test_meeting_of_another_studio() {
+        let meeting_id = MeetingId::new();
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        let other_studio = StudioId::from(uuid::Uuid::new_v4());
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &other_studio,
+            Utc::now(),
+            MeetingStatus::Scheduled,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::NotFound) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&StudioId::from(uuid::Uuid::new_v4())),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meetings of other studios should not be joined");
+        };
+    }
+
     #[tokio::test]
     async fn test_too_late() {
         let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
         let mock_room_manager = MockRoomManager::new();
 
+        let date = Utc::now().checked_sub_days(Days::new(2)).unwrap();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            date,
+            MeetingStatus::Scheduled,
+        );
+
+        let Err(JoinMeetingError::TooLate) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should exist but in the past");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_cancelled() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            date,
+            MeetingStatus::Cancelled,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::Cancelled) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("No token should be handed out for a cancelled meeting");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_ended() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        // Ended by a host while its join window is still open
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            Utc::now(),
+            MeetingStatus::Ended,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::Ended) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("No token should be handed out once the meeting has ended");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_full() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let meeting_studio = studio_id.clone();
         mock_repo
             .expect_find_meeting()
-            .once()
-            .with(eq(meeting_id.clone()))
-            .return_once(move |meeting_id| {
-                let meeting_id = meeting_id.clone();
-                Box::pin(async move {
-                    Ok(Some(Meeting {
-                        id: meeting_id,
-                        studio_id: StudioId::from(uuid::Uuid::new_v4()),
-                        name: MeetingName::try_from("An old meeting".to_string()).unwrap(),
-                        date: Utc::now().checked_sub_days(Days::new(2)).unwrap(),
-                    }))
-                })
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: meeting_studio.clone(),
+                    name: MeetingName::try_from("Hello there!".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::try_from(60).unwrap(),
+                    join_window: JoinWindow::new(15, 5).unwrap(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: Some(2),
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
             });
 
-        let Err(JoinMeetingError::TooLate) =
-            join_meeting(&mock_repo, &mock_room_manager, meeting_id, Utc::now()).await
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_list_participants().returning(|_| {
+            let participants = ["customer-42", "customer-43"]
+                .into_iter()
+                .map(|identity| RoomParticipant {
+                    identity: identity.to_string(),
+                    name: "Jane Doe".to_string(),
+                    joined_at: Utc::now(),
+                    is_publishing: false,
+                })
+                .collect();
+            Box::pin(async move { Ok(participants) })
+        });
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .withf(|_, participant, _| participant.identity == "customer-42")
+            .return_once(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let Err(JoinMeetingError::Full) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            Some(Participant {
+                identity: "customer-44".to_string(),
+                name: "John Doe".to_string(),
+            }),
+            None,
+            None,
+            meeting_id.clone(),
+            Utc::now(),
+        )
+        .await
         else {
-            panic!("The meeting should exist but in the past");
+            panic!("Nobody else should enter a full meeting");
         };
+
+        // Someone already in the room can come back, e.g. after losing their connection
+        join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        .expect("A participant of the meeting should be able to come back");
     }
 
     #[tokio::test]
     async fn test_ok() {
         let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        let date = Utc::now() + TimeDelta::minutes(5);
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            date,
+            MeetingStatus::Scheduled,
+        );
+
+        let today = Utc::now();
+        let mut sequence = mockall::Sequence::new();
+        let room_studio = studio_id.clone();
+        mock_room_manager
+            .expect_create_room()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf(move |_, settings| {
+                settings.meeting_name == "Hello there!"
+                    && settings.studio_id == room_studio
+                    // The room waits until the join window closes
+                    && settings.empty_timeout == date + TimeDelta::minutes(65) - today
+            })
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .in_sequence(&mut sequence)
+            .with(
+                eq(meeting_id.clone()),
+                eq(customer().unwrap()),
+                eq(ParticipantRole::Speaker),
+            )
+            .return_once(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let Ok(_) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            today,
+        )
+        .await
+        else {
+            panic!("A token should be returned");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_member_joins_under_their_name() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
 
-        let mut mock_repo = MockMeetingRepository::new();
+        let mut mock_repo = MockRepository::new();
         let mut mock_room_manager = MockRoomManager::new();
 
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            Utc::now(),
+            MeetingStatus::Scheduled,
+        );
         mock_repo
-            .expect_find_meeting()
+            .expect_find_member()
+            .returning(|studio_id, user_id| {
+                let member = Member {
+                    studio_id: studio_id.clone(),
+                    user_id: user_id.clone(),
+                    display_name: Some("Alice Martin".to_string()),
+                    role: MemberRole::Viewer,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
             .once()
+            .with(
+                eq(meeting_id.clone()),
+                eq(Participant {
+                    identity: "alice".to_string(),
+                    name: "Alice Martin".to_string(),
+                }),
+                eq(ParticipantRole::Viewer),
+            )
+            .return_once(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let identity = Identity {
+            user_id: Some(UserId::from("alice".to_string())),
+            // The customer supplied participant is ignored for users
+            ..backend(&studio_id)
+        };
+        let Ok(_) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &identity,
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("A token should be returned");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_participant_missing() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            Utc::now(),
+            MeetingStatus::Scheduled,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::ParticipantMissing) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            None,
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("Backends should name the participant");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_api_key_cannot_let_hosts_in() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            Utc::now(),
+            MeetingStatus::Scheduled,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let identity = Identity {
+            scopes: Some(vec![ApiKeyScope::Join]),
+            ..backend(&studio_id)
+        };
+        let Err(JoinMeetingError::RoleNotAllowed) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &identity,
+            customer(),
+            Some(ParticipantRole::Host),
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("A key without the write scope should not make hosts");
+        };
+    }
+
+    fn expect_meeting_with_passcode(
+        mock_repo: &mut MockRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+    ) {
+        let studio_id = studio_id.clone();
+        let passcode = MeetingPasscode::try_from("sunrise-42".to_string())
+            .unwrap()
+            .hash();
+        mock_repo
+            .expect_find_meeting()
             .with(eq(meeting_id.clone()))
-            .return_once(move |meeting_id| {
-                let meeting_id = meeting_id.clone();
-                Box::pin(async move {
-                    Ok(Some(Meeting {
-                        id: meeting_id,
-                        studio_id: StudioId::from(uuid::Uuid::new_v4()),
-                        name: MeetingName::try_from("Hello there!".to_string()).unwrap(),
-                        date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
-                    }))
-                })
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: studio_id.clone(),
+                    name: MeetingName::try_from("Open class".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: Some(passcode.clone()),
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_passcode() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        expect_meeting_with_passcode(&mut mock_repo, &meeting_id, &studio_id);
+        mock_repo
+            .expect_count_passcode_failures()
+            .returning(|_, _, _| Box::pin(async { Ok(0) }));
+        mock_repo
+            .expect_record_passcode_failure()
+            .once()
+            .withf(|_, caller, _| caller == "backend")
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        for (passcode, expected) in [
+            (None, Err(JoinMeetingError::PasscodeRequired)),
+            (Some("sunrise-41"), Err(JoinMeetingError::WrongPasscode)),
+            (Some("sunrise-42"), Ok(())),
+        ] {
+            let result = join_meeting(
+                &mock_repo,
+                &mock_room_manager,
+                &backend(&studio_id),
+                customer(),
+                None,
+                passcode.map(str::to_string),
+                meeting_id.clone(),
+                Utc::now(),
+            )
+            .await;
+            assert_eq!(
+                result.map(|_| ()).map_err(|err| err.to_string()),
+                expected.map_err(|err| err.to_string())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_passcode_attempts_limited() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        expect_meeting_with_passcode(&mut mock_repo, &meeting_id, &studio_id);
+        mock_repo
+            .expect_count_passcode_failures()
+            .returning(|_, _, _| Box::pin(async { Ok(5) }));
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::TooManyAttempts) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            Some("sunrise-42".to_string()),
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("Even the right passcode should wait after too many wrong ones");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_passcode_attempts_limited_per_caller() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let api_key_id = ApiKeyId::new();
+
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        expect_meeting_with_passcode(&mut mock_repo, &meeting_id, &studio_id);
+        // Too many guesses went through the key, none through the backend
+        let locked_out = api_key_id.as_ref().to_string();
+        mock_repo
+            .expect_count_passcode_failures()
+            .returning(move |_, caller, _| {
+                let failures = if caller == locked_out { 5 } else { 0 };
+                Box::pin(async move { Ok(failures) })
             });
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
 
+        let through_key = Identity {
+            scopes: Some(vec![ApiKeyScope::Join]),
+            api_key_id: Some(api_key_id),
+            ..backend(&studio_id)
+        };
+        // Naming another participant does not give the key more guesses
+        let other_customer = Some(Participant {
+            identity: "customer-7".to_string(),
+            name: "John Roe".to_string(),
+        });
+        for (identity, participant, expected) in [
+            (
+                &through_key,
+                customer(),
+                Err(JoinMeetingError::TooManyAttempts),
+            ),
+            (
+                &through_key,
+                other_customer,
+                Err(JoinMeetingError::TooManyAttempts),
+            ),
+            (&backend(&studio_id), customer(), Ok(())),
+        ] {
+            let result = join_meeting(
+                &mock_repo,
+                &mock_room_manager,
+                identity,
+                participant,
+                None,
+                Some("sunrise-42".to_string()),
+                meeting_id.clone(),
+                Utc::now(),
+            )
+            .await;
+            assert_eq!(
+                result.map(|_| ()).map_err(|err| err.to_string()),
+                expected.map_err(|err| err.to_string())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_too_early() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        let date = Utc::now() + TimeDelta::minutes(16);
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            date,
+            MeetingStatus::Scheduled,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::TooEarly) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should not be open yet");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_late_arrival() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        let date = Utc::now() - TimeDelta::minutes(62);
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            date,
+            MeetingStatus::Scheduled,
+        );
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
         mock_room_manager
             .expect_create_token()
             .once()
+            .return_once(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let Ok(_) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The late join grace should let the participant in");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_after_grace() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let mut mock_repo = MockRepository::new();
+        let mut mock_room_manager = MockRoomManager::new();
+
+        let date = Utc::now() - TimeDelta::minutes(66);
+        expect_find_meeting(
+            &mut mock_repo,
+            &meeting_id,
+            &studio_id,
+            date,
+            MeetingStatus::Scheduled,
+        );
+        mock_room_manager.expect_create_token().never();
+
+        let Err(JoinMeetingError::TooLate) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should be closed");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_waiting_room() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let meeting_studio = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
             .with(eq(meeting_id.clone()))
-            .return_once(|_| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: meeting_studio.clone(),
+                    name: MeetingName::try_from("Consultation".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: true,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+        mock_repo
+            .expect_create_ticket()
+            .once()
+            .withf(|ticket| {
+                ticket.participant.identity == "customer-42"
+                    && ticket.status == AdmissionStatus::Pending
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .withf(|_, _, role| *role == ParticipantRole::Host)
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
 
-        let Ok(_) = join_meeting(&mock_repo, &mock_room_manager, meeting_id, Utc::now()).await
+        let Ok(Admission::Waiting(_)) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            customer(),
+            None,
+            None,
+            meeting_id.clone(),
+            Utc::now(),
+        )
+        .await
         else {
-            panic!("A token should be returned");
+            panic!("The customer should wait for the host");
+        };
+
+        let Ok(Admission::Granted(_)) = join_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            &backend(&studio_id),
+            Some(Participant {
+                identity: "coach-7".to_string(),
+                name: "John Doe".to_string(),
+            }),
+            Some(ParticipantRole::Host),
+            None,
+            meeting_id,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The host should not wait");
         };
     }
 }
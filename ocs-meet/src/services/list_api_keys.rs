@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{api_key::ApiKey, studio::StudioId},
+    ports::output::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum ListApiKeysError {
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+}
+
+pub async fn list_api_keys(
+    repo: &impl ApiKeyRepository,
+    studio_id: StudioId,
+) -> Result<Vec<ApiKey>, ListApiKeysError> {
+    repo.list_api_keys(&studio_id)
+        .await
+        .map_err(ListApiKeysError::from)
+}
@@ -1,74 +1,248 @@
 use crate::{
     domain::{meeting::Meeting, studio::StudioId},
-    ports::output::meeting_repository::{MeetingRepository, MeetingRepositoryError},
+    ports::output::meeting_repository::{
+        MeetingCursor, MeetingOrder, MeetingQuery, MeetingRepository, MeetingRepositoryError,
+    },
 };
 use chrono::{DateTime, Utc};
 use thiserror::Error;
 
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
 #[derive(Debug, Error)]
 pub enum ListMeetingError {
+    #[error("A page holds between 1 and {MAX_PAGE_SIZE} meetings")]
+    InvalidLimit,
     #[error(transparent)]
     MeetingRepository(#[from] MeetingRepositoryError),
 }
 
+/// Meetings to list, only the upcoming ones when no date bound is given
+pub struct ListMeetingFilter {
+    pub studio_id: StudioId,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub include_cancelled: bool,
+    pub order: MeetingOrder,
+    pub limit: Option<u32>,
+    pub cursor: Option<MeetingCursor>,
+}
+
+impl ListMeetingFilter {
+    pub fn new(studio_id: StudioId) -> Self {
+        ListMeetingFilter {
+            studio_id,
+            from: None,
+            to: None,
+            include_cancelled: false,
+            order: MeetingOrder::default(),
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+pub struct MeetingPage {
+    pub meetings: Vec<Meeting>,
+    /// Where to resume the listing, `None` on the last page
+    pub next_cursor: Option<MeetingCursor>,
+}
+
 pub async fn list_meeting(
     repo: &impl MeetingRepository,
-    studio_id: StudioId,
-    after: DateTime<Utc>,
-) -> Result<Vec<Meeting>, ListMeetingError> {
-    let meetings = repo
-        .list_meetings(&studio_id)
+    filter: ListMeetingFilter,
+    today: DateTime<Utc>,
+) -> Result<MeetingPage, ListMeetingError> {
+    let limit = filter.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+        return Err(ListMeetingError::InvalidLimit);
+    }
+
+    let from = match (filter.from, filter.to) {
+        (None, None) => Some(today),
+        (from, _) => from,
+    };
+
+    // One more meeting than asked tells whether there is a next page
+    let query = MeetingQuery {
+        from,
+        to: filter.to,
+        include_cancelled: filter.include_cancelled,
+        order: filter.order,
+        after: filter.cursor,
+        limit: Some(limit + 1),
+        ..MeetingQuery::new(filter.studio_id)
+    };
+
+    let mut meetings = repo
+        .list_meetings(&query)
         .await
         .map_err(ListMeetingError::from)?;
 
-    let meetings = meetings
-        .into_iter()
-        .filter(|meeting| meeting.date > after)
-        .collect();
+    let next_cursor = if meetings.len() > limit as usize {
+        meetings.truncate(limit as usize);
+        meetings.last().map(MeetingCursor::from)
+    } else {
+        None
+    };
 
-    Ok(meetings)
+    Ok(MeetingPage {
+        meetings,
+        next_cursor,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         domain::{
-            meeting::{Meeting, MeetingId, MeetingName},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
             studio::StudioId,
         },
-        ports::output::meeting_repository::MockMeetingRepository,
-        services::list_meeting,
+        ports::output::meeting_repository::{
+            MeetingCursor, MeetingOrder, MeetingQuery, MockMeetingRepository,
+        },
+        services::{list_meeting, ListMeetingError, ListMeetingFilter},
     };
-    use chrono::{Days, Utc};
+    use chrono::{Days, TimeDelta, Utc};
     use mockall::predicate::eq;
 
+    fn meeting(studio_id: &StudioId, days: u64) -> Meeting {
+        Meeting {
+            id: MeetingId::new(),
+            studio_id: studio_id.clone(),
+            name: MeetingName::try_from("I am the fresh new one".to_string()).unwrap(),
+            date: Utc::now().checked_add_days(Days::new(days)).unwrap(),
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Scheduled,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: None,
+            ended_at: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_filter() {
         let mut repo = MockMeetingRepository::new();
         let studio_id = StudioId::from(uuid::Uuid::new_v4());
-        let old_meeting = Meeting {
-            id: MeetingId::new(),
-            studio_id: studio_id.clone(),
-            name: MeetingName::try_from("I am an old meeting".to_string()).unwrap(),
-            date: Utc::now().checked_sub_days(Days::new(2)).unwrap(),
-        };
-        let next_meeting = Meeting {
-            id: MeetingId::new(),
-            studio_id: studio_id.clone(),
-            name: MeetingName::try_from("I am the fresh new one".to_string()).unwrap(),
-            date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
-        };
+        let today = Utc::now();
+        let next_meeting = meeting(&studio_id, 2);
 
         repo.expect_list_meetings()
-            .with(eq(studio_id.clone()))
-            .return_once(move |_| Box::pin(async { Ok(vec![old_meeting, next_meeting]) }));
+            .once()
+            .with(eq(MeetingQuery {
+                studio_id: studio_id.clone(),
+                from: Some(today),
+                to: None,
+                include_cancelled: false,
+                order: MeetingOrder::Ascending,
+                after: None,
+                limit: Some(51),
+                series_id: None,
+            }))
+            .return_once(move |_| Box::pin(async { Ok(vec![next_meeting]) }));
 
-        let meetings = list_meeting(&repo, studio_id, Utc::now()).await.unwrap();
-        assert_eq!(meetings.len(), 1);
-        assert!(meetings.iter().all(|meeting| meeting.date > Utc::now()));
+        let page = list_meeting(&repo, ListMeetingFilter::new(studio_id), today)
+            .await
+            .unwrap();
+        assert_eq!(page.meetings.len(), 1);
         assert_eq!(
-            meetings.first().unwrap().name.as_ref(),
+            page.meetings.first().unwrap().name.as_ref(),
             "I am the fresh new one"
         );
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_include_cancelled() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut repo = MockMeetingRepository::new();
+        repo.expect_list_meetings()
+            .once()
+            .withf(|query| query.include_cancelled)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let filter = ListMeetingFilter {
+            include_cancelled: true,
+            ..ListMeetingFilter::new(studio_id)
+        };
+        list_meeting(&repo, filter, Utc::now()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_history() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let today = Utc::now();
+
+        let mut repo = MockMeetingRepository::new();
+        repo.expect_list_meetings()
+            .once()
+            .withf(move |query| {
+                query.from.is_none()
+                    && query.to == Some(today)
+                    && query.order == MeetingOrder::Descending
+            })
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let filter = ListMeetingFilter {
+            to: Some(today),
+            order: MeetingOrder::Descending,
+            ..ListMeetingFilter::new(studio_id)
+        };
+        list_meeting(&repo, filter, today).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_next_cursor() {
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let meetings = vec![
+            meeting(&studio_id, 1),
+            meeting(&studio_id, 2),
+            meeting(&studio_id, 3),
+        ];
+        let expected_cursor = MeetingCursor::from(&meetings[1]);
+        let cursor = MeetingCursor {
+            date: Utc::now() - TimeDelta::days(1),
+            id: MeetingId::new(),
+        };
+
+        let mut repo = MockMeetingRepository::new();
+        let after = cursor.clone();
+        repo.expect_list_meetings()
+            .once()
+            .withf(move |query| query.limit == Some(3) && query.after.as_ref() == Some(&after))
+            .return_once(move |_| Box::pin(async { Ok(meetings) }));
+
+        let filter = ListMeetingFilter {
+            limit: Some(2),
+            cursor: Some(cursor),
+            ..ListMeetingFilter::new(studio_id)
+        };
+        let page = list_meeting(&repo, filter, Utc::now()).await.unwrap();
+        assert_eq!(page.meetings.len(), 2);
+        assert_eq!(page.next_cursor, Some(expected_cursor));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_limit() {
+        let mut repo = MockMeetingRepository::new();
+        repo.expect_list_meetings().never();
+
+        let filter = ListMeetingFilter {
+            limit: Some(0),
+            ..ListMeetingFilter::new(StudioId::from(uuid::Uuid::new_v4()))
+        };
+        let Err(ListMeetingError::InvalidLimit) = list_meeting(&repo, filter, Utc::now()).await
+        else {
+            panic!("An empty page should be refused");
+        };
     }
 }
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{member::Member, studio::StudioId},
+    ports::output::member_repository::{MemberRepository, MemberRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum ListMembersError {
+    #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+}
+
+pub async fn list_members(
+    repo: &impl MemberRepository,
+    studio_id: StudioId,
+) -> Result<Vec<Member>, ListMembersError> {
+    repo.list_members(&studio_id)
+        .await
+        .map_err(ListMembersError::from)
+}
@@ -1,7 +1,67 @@
+mod add_member;
+mod authenticate_api_key;
+mod authorize;
+mod calendar_feed;
+mod cancel_meeting;
+mod cancel_meeting_series;
+mod change_member_role;
+mod create_api_key;
+mod create_calendar_feed;
 mod create_meeting;
+mod create_meeting_series;
+mod create_studio;
+mod delete_studio;
+mod end_meeting;
+mod get_meeting;
+mod get_studio;
+mod handle_room_event;
+mod import_meetings;
+mod invite_guest;
 mod join_meeting;
+mod list_api_keys;
 mod list_meeting;
+mod list_members;
+mod redeem_invitation;
+mod remove_member;
+mod reschedule_meeting;
+mod revoke_api_key;
+mod revoke_calendar_feed;
+mod revoke_invitation;
+mod room_participants;
+mod update_meeting_series;
+mod update_studio;
+mod waiting_room;
 
+pub use add_member::*;
+pub use authenticate_api_key::*;
+pub use authorize::*;
+pub use calendar_feed::*;
+pub use cancel_meeting::*;
+pub use cancel_meeting_series::*;
+pub use change_member_role::*;
+pub use create_api_key::*;
+pub use create_calendar_feed::*;
 pub use create_meeting::*;
+pub use create_meeting_series::*;
+pub use create_studio::*;
+pub use delete_studio::*;
+pub use end_meeting::*;
+pub use get_meeting::*;
+pub use get_studio::*;
+pub use handle_room_event::*;
+pub use import_meetings::*;
+pub use invite_guest::*;
 pub use join_meeting::*;
+pub use list_api_keys::*;
 pub use list_meeting::*;
+pub use list_members::*;
+pub use redeem_invitation::*;
+pub use remove_member::*;
+pub use reschedule_meeting::*;
+pub use revoke_api_key::*;
+pub use revoke_calendar_feed::*;
+pub use revoke_invitation::*;
+pub use room_participants::*;
+pub use update_meeting_series::*;
+pub use update_studio::*;
+pub use waiting_room::*;
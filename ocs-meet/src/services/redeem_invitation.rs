@@ -0,0 +1,331 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        invitation::{InvitationKey, InvitationToken},
+        meeting::MeetingStatus,
+        room::Participant,
+        waiting_room::Admission,
+    },
+    ports::output::{
+        invitation_repository::{InvitationRepository, InvitationRepositoryError},
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+        waiting_room_repository::{WaitingRoomRepository, WaitingRoomRepositoryError},
+    },
+    services::{
+        join_meeting::{enter_room, is_full},
+        waiting_room::{enter_waiting_room, must_wait},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum RedeemInvitationError {
+    #[error("The invitation link is not valid")]
+    Invalid,
+    #[error("The invitation has been revoked")]
+    Revoked,
+    #[error("The invitation has expired")]
+    Expired,
+    #[error("The invitation has already been used as many times as allowed")]
+    Exhausted,
+    #[error("It's too early to join the meeting")]
+    TooEarly,
+    #[error("It's too late to join the meeting")]
+    TooLate,
+    #[error("The meeting has been cancelled")]
+    Cancelled,
+    #[error("The meeting has ended")]
+    Ended,
+    #[error("The meeting is full")]
+    Full,
+    #[error(transparent)]
+    InvitationRepository(#[from] InvitationRepositoryError),
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    WaitingRoomRepository(#[from] WaitingRoomRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Hands out a token to enter the room to the guest holding an invitation link, under the name
+/// and role of the invitation
+///
+/// Every use enters as a participant of its own, numbered after the invitation, so a link shared
+/// with several guests does not kick them out of the room in turn. The guests wait for a host when
+/// the meeting has a waiting room.
+pub async fn redeem_invitation(
+    repository: &(impl MeetingRepository + InvitationRepository + WaitingRoomRepository),
+    room_manager: &impl RoomManager,
+    key: &InvitationKey,
+    token: InvitationToken,
+    today: DateTime<Utc>,
+) -> Result<Admission, RedeemInvitationError> {
+    let invitation_id = key.verify(&token).ok_or(RedeemInvitationError::Invalid)?;
+
+    let Some(invitation) = repository
+        .find_invitation(&invitation_id)
+        .await
+        .map_err(RedeemInvitationError::from)?
+    else {
+        return Err(RedeemInvitationError::Invalid);
+    };
+
+    if invitation.revoked_at.is_some() {
+        return Err(RedeemInvitationError::Revoked);
+    }
+
+    if invitation.is_expired(today) {
+        return Err(RedeemInvitationError::Expired);
+    }
+
+    if invitation.is_exhausted() {
+        return Err(RedeemInvitationError::Exhausted);
+    }
+
+    let Some(meeting) = repository
+        .find_meeting(&invitation.meeting_id)
+        .await
+        .map_err(RedeemInvitationError::from)?
+    else {
+        return Err(RedeemInvitationError::Invalid);
+    };
+
+    match meeting.status {
+        MeetingStatus::Scheduled | MeetingStatus::Live => {}
+        MeetingStatus::Cancelled => return Err(RedeemInvitationError::Cancelled),
+        MeetingStatus::Ended => return Err(RedeemInvitationError::Ended),
+    }
+
+    if today < meeting.join_opens_at() {
+        return Err(RedeemInvitationError::TooEarly);
+    }
+
+    if today > meeting.join_closes_at() {
+        return Err(RedeemInvitationError::TooLate);
+    }
+
+    // The guests waiting for a host are let in as long as there is room once admitted, the others
+    // do not take a use of the invitation when the meeting is full
+    let waits = must_wait(&meeting, invitation.role);
+    if !waits && is_full(room_manager, &meeting, None).await? {
+        return Err(RedeemInvitationError::Full);
+    }
+
+    // Another guest may have taken the last use since the invitation was read
+    let Some(uses) = repository
+        .use_invitation(&invitation.id, today)
+        .await
+        .map_err(RedeemInvitationError::from)?
+    else {
+        return Err(RedeemInvitationError::Exhausted);
+    };
+
+    let participant = Participant {
+        identity: format!("guest-{}-{uses}", invitation.id.as_ref().simple()),
+        name: invitation.display_name,
+    };
+
+    if waits {
+        let ticket = enter_waiting_room(repository, &meeting, participant, invitation.role, today)
+            .await
+            .map_err(RedeemInvitationError::from)?;
+        return Ok(Admission::Waiting(ticket));
+    }
+
+    enter_room(room_manager, &meeting, participant, invitation.role, today)
+        .await
+        .map(Admission::Granted)
+        .map_err(RedeemInvitationError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedeemInvitationError;
+    use crate::{
+        domain::{
+            invitation::{Invitation, InvitationId, InvitationKey, InvitationToken},
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::{ParticipantRole, RoomToken},
+            studio::StudioId,
+            waiting_room::Admission,
+        },
+        ports::output::{repository::MockRepository, room_manager::MockRoomManager},
+        services::redeem_invitation,
+    };
+    use chrono::{DateTime, TimeDelta, Utc};
+    use mockall::predicate::eq;
+
+    fn key() -> InvitationKey {
+        InvitationKey::from("a secret only known by the tests".to_string())
+    }
+
+    fn invitation(meeting_id: &MeetingId, now: DateTime<Utc>) -> Invitation {
+        Invitation {
+            id: InvitationId::new(),
+            meeting_id: meeting_id.clone(),
+            studio_id: StudioId::from(uuid::Uuid::new_v4()),
+            display_name: "Jane Doe".to_string(),
+            role: ParticipantRole::Viewer,
+            max_uses: Some(3),
+            uses: 0,
+            expires_at: now + TimeDelta::hours(2),
+            revoked_at: None,
+            created_at: now,
+        }
+    }
+
+    fn expect_invitation(mock_repo: &mut MockRepository, invitation: &Invitation) {
+        let invitation = invitation.clone();
+        mock_repo
+            .expect_find_invitation()
+            .with(eq(invitation.id.clone()))
+            .return_once(move |_| Box::pin(async move { Ok(Some(invitation)) }));
+    }
+
+    fn expect_meeting(mock_repo: &mut MockRepository, date: DateTime<Utc>) {
+        mock_repo
+            .expect_find_meeting()
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: StudioId::from(uuid::Uuid::new_v4()),
+                    name: MeetingName::try_from("Hello there!".to_string()).unwrap(),
+                    date,
+                    duration: MeetingDuration::try_from(60).unwrap(),
+                    join_window: JoinWindow::new(15, 5).unwrap(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_redeem() {
+        let now = Utc::now();
+        let invitation = invitation(&MeetingId::new(), now);
+        let token = key().sign(&invitation.id);
+
+        let mut mock_repo = MockRepository::new();
+        expect_invitation(&mut mock_repo, &invitation);
+        expect_meeting(&mut mock_repo, now);
+        mock_repo
+            .expect_use_invitation()
+            .once()
+            .with(eq(invitation.id.clone()), eq(now))
+            .returning(|_, _| Box::pin(async { Ok(Some(2)) }));
+
+        let mut mock_room_manager = MockRoomManager::new();
+        let identity = format!("guest-{}-2", invitation.id.as_ref().simple());
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .withf(move |meeting_id, participant, role| {
+                *meeting_id == invitation.meeting_id
+                    && participant.identity == identity
+                    && participant.name == "Jane Doe"
+                    && *role == ParticipantRole::Viewer
+            })
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let Admission::Granted(token) =
+            redeem_invitation(&mock_repo, &mock_room_manager, &key(), token, now)
+                .await
+                .unwrap()
+        else {
+            panic!("The guest should enter right away");
+        };
+        assert_eq!(token.as_ref(), "mytoken");
+    }
+
+    #[tokio::test]
+    async fn test_unusable_invitations() {
+        let now = Utc::now();
+        let meeting_id = MeetingId::new();
+        let mock_room_manager = MockRoomManager::new();
+
+        let mut mock_repo = MockRepository::new();
+        mock_repo.expect_find_invitation().never();
+        let forged = InvitationToken::from(format!("{}.c2lnbmF0dXJl", uuid::Uuid::new_v4()));
+        let Err(RedeemInvitationError::Invalid) =
+            redeem_invitation(&mock_repo, &mock_room_manager, &key(), forged, now).await
+        else {
+            panic!("A token not signed by the server should be refused");
+        };
+
+        let cases = [
+            (
+                Invitation {
+                    revoked_at: Some(now),
+                    ..invitation(&meeting_id, now)
+                },
+                "revoked",
+            ),
+            (
+                Invitation {
+                    expires_at: now - TimeDelta::minutes(1),
+                    ..invitation(&meeting_id, now)
+                },
+                "expired",
+            ),
+            (
+                Invitation {
+                    uses: 3,
+                    ..invitation(&meeting_id, now)
+                },
+                "exhausted",
+            ),
+        ];
+        for (invitation, case) in cases {
+            let mut mock_repo = MockRepository::new();
+            expect_invitation(&mut mock_repo, &invitation);
+            mock_repo.expect_use_invitation().never();
+
+            let token = key().sign(&invitation.id);
+            let result =
+                redeem_invitation(&mock_repo, &mock_room_manager, &key(), token, now).await;
+            match (result, case) {
+                (Err(RedeemInvitationError::Revoked), "revoked") => {}
+                (Err(RedeemInvitationError::Expired), "expired") => {}
+                (Err(RedeemInvitationError::Exhausted), "exhausted") => {}
+                (result, case) => panic!("The {case} invitation gave {result:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_last_use_taken_meanwhile() {
+        let now = Utc::now();
+        let invitation = invitation(&MeetingId::new(), now);
+        let token = key().sign(&invitation.id);
+
+        let mut mock_repo = MockRepository::new();
+        expect_invitation(&mut mock_repo, &invitation);
+        expect_meeting(&mut mock_repo, now);
+        mock_repo
+            .expect_use_invitation()
+            .once()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_create_token().never();
+
+        let Err(RedeemInvitationError::Exhausted) =
+            redeem_invitation(&mock_repo, &mock_room_manager, &key(), token, now).await
+        else {
+            panic!("The invitation should be exhausted");
+        };
+    }
+}
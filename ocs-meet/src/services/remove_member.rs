@@ -0,0 +1,52 @@
+use thiserror::Error;
+
+use super::change_member_role::is_last_owner;
+use crate::{
+    domain::{
+        member::{Access, MemberRole},
+        user::UserId,
+    },
+    ports::output::member_repository::{MemberRepository, MemberRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum RemoveMemberError {
+    #[error("The user is not a member of the studio")]
+    NotFound,
+    #[error("Only the owners can remove the owners")]
+    Forbidden,
+    #[error("The studio needs at least one owner")]
+    LastOwner,
+    #[error(transparent)]
+    MemberRepository(#[from] MemberRepositoryError),
+}
+
+/// Removes the user from the studio, the last owner has to delete the studio instead
+pub async fn remove_member(
+    repo: &impl MemberRepository,
+    access: &Access,
+    user_id: UserId,
+) -> Result<(), RemoveMemberError> {
+    let Some(member) = repo
+        .find_member(&access.studio_id, &user_id)
+        .await
+        .map_err(RemoveMemberError::from)?
+    else {
+        return Err(RemoveMemberError::NotFound);
+    };
+
+    if member.role == MemberRole::Owner {
+        if access.role != MemberRole::Owner {
+            return Err(RemoveMemberError::Forbidden);
+        }
+        if is_last_owner(repo, access).await? {
+            return Err(RemoveMemberError::LastOwner);
+        }
+    }
+
+    repo.remove_member(&access.studio_id, &user_id)
+        .await
+        .map_err(RemoveMemberError::from)?;
+
+    Ok(())
+}
@@ -0,0 +1,576 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::create_meeting::find_overlapping_meeting;
+use crate::{
+    domain::{
+        meeting::{JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus},
+        passcode::MeetingPasscode,
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum RescheduleMeetingError {
+    #[error("The meeting you're looking for does not exist")]
+    NotFound,
+    #[error("The meeting has been cancelled")]
+    Cancelled,
+    #[error("The meeting has ended")]
+    Ended,
+    #[error("Cannot move a meeting in the past")]
+    DateInThePast,
+    #[error("The meeting overlaps with meeting {}", .0.as_ref())]
+    DateAlreadyTaken(MeetingId),
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Fields of a meeting to change, the ones left to `None` are kept as is
+#[derive(Default)]
+pub struct MeetingChanges {
+    pub name: Option<MeetingName>,
+    pub date: Option<DateTime<Utc>>,
+    pub duration: Option<MeetingDuration>,
+    pub join_window: Option<JoinWindow>,
+    /// `Some(None)` removes the passcode
+    pub passcode: Option<Option<MeetingPasscode>>,
+    pub waiting_room: Option<bool>,
+}
+
+/// Changes the meeting, closing its room when its timing changes so that the next participant
+/// opens it again with the new settings, LiveKit keeping the settings of a room already open
+///
+/// The room of a live meeting is left open, for the participants in it to carry on.
+pub async fn reschedule_meeting(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+    changes: MeetingChanges,
+    today: DateTime<Utc>,
+) -> Result<Meeting, RescheduleMeetingError> {
+    let MeetingChanges {
+        name,
+        date,
+        duration,
+        join_window,
+        passcode,
+        waiting_room,
+    } = changes;
+
+    let Some(mut meeting) = repo
+        .find_meeting(&meeting_id)
+        .await
+        .map_err(RescheduleMeetingError::from)?
+    else {
+        return Err(RescheduleMeetingError::NotFound);
+    };
+
+    // A meeting of another studio is reported as missing, so its existence is not leaked
+    if meeting.studio_id != studio_id {
+        return Err(RescheduleMeetingError::NotFound);
+    }
+
+    if meeting.status == MeetingStatus::Cancelled {
+        return Err(RescheduleMeetingError::Cancelled);
+    }
+
+    if meeting.status == MeetingStatus::Ended {
+        return Err(RescheduleMeetingError::Ended);
+    }
+
+    if let Some(date) = date {
+        if date < today {
+            return Err(RescheduleMeetingError::DateInThePast);
+        }
+        meeting.date = date;
+    }
+
+    if let Some(duration) = duration {
+        meeting.duration = duration;
+    }
+
+    if date.is_some() || duration.is_some() {
+        if let Some(conflict) = find_overlapping_meeting(
+            repo,
+            &studio_id,
+            meeting.date,
+            meeting.end_date(),
+            Some(&meeting.id),
+        )
+        .await?
+        {
+            return Err(RescheduleMeetingError::DateAlreadyTaken(conflict.id));
+        }
+    }
+
+    if let Some(name) = name {
+        meeting.name = name;
+    }
+
+    if let Some(join_window) = join_window {
+        meeting.join_window = join_window;
+    }
+
+    if let Some(passcode) = passcode {
+        meeting.passcode = passcode.as_ref().map(MeetingPasscode::hash);
+    }
+
+    if let Some(waiting_room) = waiting_room {
+        meeting.waiting_room = waiting_room;
+    }
+
+    repo.update_meeting(&meeting)
+        .await
+        .map_err(RescheduleMeetingError::from)?;
+
+    let timing_changed = date.is_some() || duration.is_some() || join_window.is_some();
+    if timing_changed && meeting.status == MeetingStatus::Scheduled {
+        room_manager
+            .delete_room(&meeting.id)
+            .await
+            .map_err(RescheduleMeetingError::from)?;
+    }
+
+    Ok(meeting)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Days, TimeDelta, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            passcode::MeetingPasscode,
+            studio::StudioId,
+        },
+        ports::output::{
+            meeting_repository::{MeetingQuery, MockMeetingRepository},
+            room_manager::MockRoomManager,
+        },
+        services::{reschedule_meeting, MeetingChanges, RescheduleMeetingError},
+    };
+
+    fn expect_find_meeting(
+        mock_repo: &mut MockMeetingRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+        date: DateTime<Utc>,
+    ) {
+        let studio_id = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .return_once(move |meeting_id| {
+                let meeting_id = meeting_id.clone();
+                Box::pin(async move {
+                    Ok(Some(Meeting {
+                        id: meeting_id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date,
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }))
+                })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_rename_ok() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        mock_repo.expect_list_meetings().never();
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .withf(|meeting| meeting.name.as_ref() == "Renamed meeting")
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let name = MeetingName::try_from("Renamed meeting".to_string()).unwrap();
+        let meeting = reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                name: Some(name),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        .expect("The meeting should be renamed");
+        assert_eq!(meeting.date, date);
+    }
+
+    #[tokio::test]
+    async fn test_set_passcode() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .withf(|meeting| {
+                meeting
+                    .passcode
+                    .as_ref()
+                    .is_some_and(|hash| hash.verify("sunrise-42"))
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let passcode = MeetingPasscode::try_from("sunrise-42".to_string()).unwrap();
+        reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                passcode: Some(Some(passcode)),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        .expect("The passcode should be set");
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_keeps_its_own_date() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        let listed_id = meeting_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
+                let id = listed_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id,
+                        studio_id,
+                        name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                        date,
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        reschedule_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                date: Some(date),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        .expect("The meeting should not conflict with itself");
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_date_already_taken() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let new_date = Utc::now().checked_add_days(Days::new(3)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id: MeetingId::new(),
+                        studio_id,
+                        name: MeetingName::try_from("Another meeting".to_string()).unwrap(),
+                        date: new_date,
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+        mock_repo.expect_update_meeting().never();
+
+        let Err(RescheduleMeetingError::DateAlreadyTaken(_)) = reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                date: Some(new_date),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should not be moved")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_in_the_past() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let new_date = Utc::now().checked_sub_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        mock_repo.expect_list_meetings().never();
+        mock_repo.expect_update_meeting().never();
+
+        let Err(RescheduleMeetingError::DateInThePast) = reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                date: Some(new_date),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should not be moved")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_other_studio() {
+        let meeting_id = MeetingId::new();
+        let owner = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &owner, date);
+        mock_repo.expect_update_meeting().never();
+
+        let name = MeetingName::try_from("Hijacked".to_string()).unwrap();
+        let Err(RescheduleMeetingError::NotFound) = reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            StudioId::from(uuid::Uuid::new_v4()),
+            MeetingChanges {
+                name: Some(name),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should not be visible from another studio")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_ended() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_sub_days(Days::new(1)).unwrap();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        let ended = Meeting {
+            id: meeting_id.clone(),
+            studio_id: studio_id.clone(),
+            name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+            date,
+            duration: MeetingDuration::default(),
+            join_window: JoinWindow::default(),
+            status: MeetingStatus::Ended,
+            series_id: None,
+            passcode: None,
+            waiting_room: false,
+            max_participants: None,
+            started_at: Some(date),
+            ended_at: Some(date + TimeDelta::minutes(30)),
+        };
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .return_once(move |_| Box::pin(async move { Ok(Some(ended)) }));
+        mock_repo.expect_update_meeting().never();
+
+        let Err(RescheduleMeetingError::Ended) = reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                date: Some(Utc::now().checked_add_days(Days::new(2)).unwrap()),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("An ended meeting should not be moved")
+        };
+    }
+
+    #[tokio::test]
+    async fn test_extend_into_next_meeting() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let next_id = MeetingId::new();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        let listed_id = next_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf(MeetingQuery::on_studio(&studio_id))
+            .returning(move |query| {
+                let studio_id = query.studio_id.clone();
+                let id = listed_id.clone();
+                Box::pin(async move {
+                    Ok(vec![Meeting {
+                        id,
+                        studio_id,
+                        name: MeetingName::try_from("Next meeting".to_string()).unwrap(),
+                        date: date + TimeDelta::minutes(90),
+                        duration: MeetingDuration::default(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: None,
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    }])
+                })
+            });
+        mock_repo.expect_update_meeting().never();
+
+        let Err(RescheduleMeetingError::DateAlreadyTaken(conflict)) = reschedule_meeting(
+            &mock_repo,
+            &MockRoomManager::new(),
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                duration: Some(MeetingDuration::try_from(120).unwrap()),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The meeting should not be extended")
+        };
+        assert_eq!(conflict, next_id);
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_closes_the_room() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let date = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        let new_date = date + TimeDelta::days(7);
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id, date);
+        mock_repo
+            .expect_list_meetings()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        // The room is closed once the meeting is saved, to be opened with the new settings
+        let mut sequence = mockall::Sequence::new();
+        mock_repo
+            .expect_update_meeting()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .in_sequence(&mut sequence)
+            .with(eq(meeting_id.clone()))
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_room_manager.expect_create_room().never();
+
+        reschedule_meeting(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id,
+            studio_id,
+            MeetingChanges {
+                date: Some(new_date),
+                ..Default::default()
+            },
+            Utc::now(),
+        )
+        .await
+        .expect("The meeting should be moved");
+    }
+}
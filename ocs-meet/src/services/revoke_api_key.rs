@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+use crate::{
+    domain::{api_key::ApiKeyId, studio::StudioId},
+    ports::output::api_key_repository::{ApiKeyRepository, ApiKeyRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum RevokeApiKeyError {
+    #[error("The API key you're looking for does not exist")]
+    NotFound,
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+}
+
+pub async fn revoke_api_key(
+    repo: &impl ApiKeyRepository,
+    studio_id: StudioId,
+    api_key_id: ApiKeyId,
+) -> Result<(), RevokeApiKeyError> {
+    let deleted = repo
+        .delete_api_key(&studio_id, &api_key_id)
+        .await
+        .map_err(RevokeApiKeyError::from)?;
+
+    // The keys of other studios are reported as missing as well
+    if !deleted {
+        return Err(RevokeApiKeyError::NotFound);
+    }
+
+    Ok(())
+}
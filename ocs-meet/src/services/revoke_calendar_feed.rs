@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+use crate::{
+    domain::studio::StudioId,
+    ports::output::calendar_feed_repository::{
+        CalendarFeedRepository, CalendarFeedRepositoryError,
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum RevokeCalendarFeedError {
+    #[error(transparent)]
+    CalendarFeedRepository(#[from] CalendarFeedRepositoryError),
+}
+
+/// Stops the calendar feed of the studio, revoking a feed that does not exist is a no-op
+pub async fn revoke_calendar_feed(
+    repo: &impl CalendarFeedRepository,
+    studio_id: StudioId,
+) -> Result<(), RevokeCalendarFeedError> {
+    repo.delete_calendar_feed(&studio_id)
+        .await
+        .map_err(RevokeCalendarFeedError::from)
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{invitation::InvitationId, studio::StudioId},
+    ports::output::invitation_repository::{InvitationRepository, InvitationRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum RevokeInvitationError {
+    #[error("The invitation you're looking for does not exist")]
+    NotFound,
+    #[error(transparent)]
+    InvitationRepository(#[from] InvitationRepositoryError),
+}
+
+/// Stops the link of the invitation from letting anyone in, the guests already in the room stay
+pub async fn revoke_invitation(
+    repo: &impl InvitationRepository,
+    studio_id: StudioId,
+    invitation_id: InvitationId,
+    today: DateTime<Utc>,
+) -> Result<(), RevokeInvitationError> {
+    let revoked = repo
+        .revoke_invitation(&studio_id, &invitation_id, today)
+        .await
+        .map_err(RevokeInvitationError::from)?;
+
+    // The invitations of other studios are reported as missing as well
+    if !revoked {
+        return Err(RevokeInvitationError::NotFound);
+    }
+
+    Ok(())
+}
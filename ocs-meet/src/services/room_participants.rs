@@ -0,0 +1,368 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        meeting::{Meeting, MeetingId},
+        moderation::{ModerationAction, ModerationActionId, ModerationKind},
+        room::{PermissionChanges, RoomParticipant, TrackSource},
+        studio::StudioId,
+        user::UserId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        moderation_repository::{ModerationRepository, ModerationRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum RoomParticipantsError {
+    #[error("The meeting you're looking for does not exist")]
+    MeetingNotFound,
+    #[error("Nobody with this identity is in the room")]
+    ParticipantNotFound,
+    #[error("The participant is not publishing this track")]
+    TrackNotFound,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    ModerationRepository(#[from] ModerationRepositoryError),
+    #[error(transparent)]
+    RoomManager(RoomManagerError),
+}
+
+impl From<RoomManagerError> for RoomParticipantsError {
+    fn from(err: RoomManagerError) -> Self {
+        match err {
+            RoomManagerError::ParticipantNotFound => RoomParticipantsError::ParticipantNotFound,
+            RoomManagerError::TrackNotFound => RoomParticipantsError::TrackNotFound,
+            err => RoomParticipantsError::RoomManager(err),
+        }
+    }
+}
+
+/// The meetings of other studios are reported as missing
+async fn studio_meeting(
+    repo: &impl MeetingRepository,
+    meeting_id: &MeetingId,
+    studio_id: &StudioId,
+) -> Result<Meeting, RoomParticipantsError> {
+    repo.find_meeting(meeting_id)
+        .await?
+        .filter(|meeting| &meeting.studio_id == studio_id)
+        .ok_or(RoomParticipantsError::MeetingNotFound)
+}
+
+/// Who is connected to the room of the meeting right now, nobody when the room is not open
+pub async fn list_participants(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+) -> Result<Vec<RoomParticipant>, RoomParticipantsError> {
+    let meeting = studio_meeting(repo, &meeting_id, &studio_id).await?;
+
+    Ok(room_manager.list_participants(&meeting.id).await?)
+}
+
+/// Disconnects the participant from the room of the meeting, nothing prevents them from joining
+/// again as long as they can get a token
+pub async fn remove_participant(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+    identity: String,
+) -> Result<(), RoomParticipantsError> {
+    let meeting = studio_meeting(repo, &meeting_id, &studio_id).await?;
+
+    Ok(room_manager
+        .remove_participant(&meeting.id, &identity)
+        .await?)
+}
+
+/// Keeps the action of the host in the audit trail of the meeting
+async fn record_action(
+    repo: &impl ModerationRepository,
+    meeting: Meeting,
+    performed_by: Option<UserId>,
+    participant_identity: String,
+    kind: ModerationKind,
+    today: DateTime<Utc>,
+) -> Result<ModerationAction, RoomParticipantsError> {
+    let action = ModerationAction {
+        id: ModerationActionId::new(),
+        meeting_id: meeting.id,
+        studio_id: meeting.studio_id,
+        performed_by,
+        participant_identity,
+        kind,
+        created_at: today,
+    };
+    repo.record_action(&action).await?;
+
+    Ok(action)
+}
+
+/// Mutes what the participant publishes from the source
+#[allow(clippy::too_many_arguments)]
+pub async fn mute_participant(
+    repo: &(impl MeetingRepository + ModerationRepository),
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+    performed_by: Option<UserId>,
+    identity: String,
+    source: TrackSource,
+    today: DateTime<Utc>,
+) -> Result<ModerationAction, RoomParticipantsError> {
+    let meeting = studio_meeting(repo, &meeting_id, &studio_id).await?;
+
+    room_manager
+        .mute_track(&meeting.id, &identity, source)
+        .await?;
+
+    record_action(
+        repo,
+        meeting,
+        performed_by,
+        identity,
+        ModerationKind::Mute(source),
+        today,
+    )
+    .await
+}
+
+/// Grants or revokes the permissions of the participant for the rest of their visit
+#[allow(clippy::too_many_arguments)]
+pub async fn update_participant_permissions(
+    repo: &(impl MeetingRepository + ModerationRepository),
+    room_manager: &impl RoomManager,
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+    performed_by: Option<UserId>,
+    identity: String,
+    changes: PermissionChanges,
+    today: DateTime<Utc>,
+) -> Result<ModerationAction, RoomParticipantsError> {
+    let meeting = studio_meeting(repo, &meeting_id, &studio_id).await?;
+
+    room_manager
+        .update_permissions(&meeting.id, &identity, changes)
+        .await?;
+
+    record_action(
+        repo,
+        meeting,
+        performed_by,
+        identity,
+        ModerationKind::UpdatePermissions(changes),
+        today,
+    )
+    .await
+}
+
+/// What the hosts did to the participants of the meeting, the oldest first
+pub async fn list_moderation_actions(
+    repo: &(impl MeetingRepository + ModerationRepository),
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+) -> Result<Vec<ModerationAction>, RoomParticipantsError> {
+    let meeting = studio_meeting(repo, &meeting_id, &studio_id).await?;
+
+    Ok(repo.list_actions(&meeting.id).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, Utc};
+    use mockall::predicate::eq;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            moderation::ModerationKind,
+            room::TrackSource,
+            studio::StudioId,
+            user::UserId,
+        },
+        ports::output::{
+            meeting_repository::MockMeetingRepository,
+            repository::MockRepository,
+            room_manager::{MockRoomManager, RoomManagerError},
+        },
+        services::{
+            list_participants, mute_participant, remove_participant, RoomParticipantsError,
+        },
+    };
+
+    fn expect_find_meeting(
+        mock_repo: &mut MockMeetingRepository,
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+    ) {
+        let studio_id = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .with(eq(meeting_id.clone()))
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: studio_id.clone(),
+                    name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                    date: Utc::now().checked_add_days(Days::new(2)).unwrap(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_other_studio() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id);
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_list_participants().never();
+        mock_room_manager.expect_remove_participant().never();
+
+        let other_studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let Err(RoomParticipantsError::MeetingNotFound) = list_participants(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id.clone(),
+            other_studio_id.clone(),
+        )
+        .await
+        else {
+            panic!("The rooms of other studios should stay hidden");
+        };
+        let Err(RoomParticipantsError::MeetingNotFound) = remove_participant(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id,
+            other_studio_id,
+            "customer-42".to_string(),
+        )
+        .await
+        else {
+            panic!("The rooms of other studios should stay out of reach");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_remove_absent_participant() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_find_meeting(&mut mock_repo, &meeting_id, &studio_id);
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_remove_participant()
+            .once()
+            .withf(|_, identity| identity == "customer-42")
+            .returning(|_, _| Box::pin(async { Err(RoomManagerError::ParticipantNotFound) }));
+
+        let Err(RoomParticipantsError::ParticipantNotFound) = remove_participant(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id,
+            studio_id,
+            "customer-42".to_string(),
+        )
+        .await
+        else {
+            panic!("Removing someone who is not in the room should be reported");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_mute_is_audited() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockRepository::new();
+        let meeting_studio = studio_id.clone();
+        mock_repo
+            .expect_find_meeting()
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: meeting_studio.clone(),
+                    name: MeetingName::try_from("Hello meeting".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+        // Only the actions which went through are kept
+        mock_repo
+            .expect_record_action()
+            .once()
+            .withf(|action| {
+                action.performed_by == Some(UserId::from("bob".to_string()))
+                    && action.participant_identity == "customer-42"
+                    && action.kind == ModerationKind::Mute(TrackSource::Microphone)
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_mute_track()
+            .withf(|_, _, source| *source == TrackSource::Microphone)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_mute_track()
+            .withf(|_, _, source| *source == TrackSource::Camera)
+            .returning(|_, _, _| Box::pin(async { Err(RoomManagerError::TrackNotFound) }));
+
+        mute_participant(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id.clone(),
+            studio_id.clone(),
+            Some(UserId::from("bob".to_string())),
+            "customer-42".to_string(),
+            TrackSource::Microphone,
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        let Err(RoomParticipantsError::TrackNotFound) = mute_participant(
+            &mock_repo,
+            &mock_room_manager,
+            meeting_id,
+            studio_id,
+            Some(UserId::from("bob".to_string())),
+            "customer-42".to_string(),
+            TrackSource::Camera,
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("Muting a track which is not published should be reported");
+        };
+    }
+}
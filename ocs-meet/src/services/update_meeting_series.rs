@@ -0,0 +1,343 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::create_meeting_series::find_occurrence_conflict;
+use crate::{
+    domain::{
+        meeting::{
+            JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingSeries,
+            MeetingSeriesId, MeetingStatus,
+        },
+        studio::StudioId,
+    },
+    ports::output::{
+        meeting_repository::{MeetingQuery, MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum UpdateMeetingSeriesError {
+    #[error("The meeting series you're looking for does not exist")]
+    NotFound,
+    #[error("The occurrence of {} overlaps with meeting {}", .0, .1.as_ref())]
+    DateAlreadyTaken(DateTime<Utc>, MeetingId),
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Fields to change on every upcoming occurrence of a series, the ones left to `None` are kept
+#[derive(Default)]
+pub struct MeetingSeriesChanges {
+    pub name: Option<MeetingName>,
+    pub duration: Option<MeetingDuration>,
+    pub join_window: Option<JoinWindow>,
+}
+
+/// The series along with its occurrences still to come and not cancelled, `None` when the series
+/// does not exist or belongs to another studio
+pub(crate) async fn find_upcoming_occurrences(
+    repo: &impl MeetingRepository,
+    series_id: &MeetingSeriesId,
+    studio_id: &StudioId,
+    today: DateTime<Utc>,
+) -> Result<Option<(MeetingSeries, Vec<Meeting>)>, MeetingRepositoryError> {
+    let Some(series) = repo.find_series(series_id).await? else {
+        return Ok(None);
+    };
+
+    // A series of another studio is reported as missing, so its existence is not leaked
+    if &series.studio_id != studio_id {
+        return Ok(None);
+    }
+
+    let query = MeetingQuery {
+        from: Some(today),
+        include_cancelled: false,
+        series_id: Some(series.id.clone()),
+        ..MeetingQuery::new(studio_id.clone())
+    };
+    let meetings = repo
+        .list_meetings(&query)
+        .await?
+        .into_iter()
+        .filter(|meeting| meeting.status == MeetingStatus::Scheduled)
+        .collect();
+
+    Ok(Some((series, meetings)))
+}
+
+/// Applies the changes to every upcoming scheduled occurrence, including the ones edited on their
+/// own, the past and cancelled ones keep their values
+///
+/// The rooms participants may have opened already are closed when the join window moves, to be
+/// opened again with the new settings.
+pub async fn update_meeting_series(
+    repo: &impl MeetingRepository,
+    room_manager: &impl RoomManager,
+    series_id: MeetingSeriesId,
+    studio_id: StudioId,
+    changes: MeetingSeriesChanges,
+    today: DateTime<Utc>,
+) -> Result<(MeetingSeries, Vec<Meeting>), UpdateMeetingSeriesError> {
+    let Some((series, mut meetings)) =
+        find_upcoming_occurrences(repo, &series_id, &studio_id, today).await?
+    else {
+        return Err(UpdateMeetingSeriesError::NotFound);
+    };
+
+    // Rooms are only opened by the participants joining, so within the join window
+    let opened: Vec<MeetingId> = meetings
+        .iter()
+        .filter(|meeting| meeting.join_opens_at() <= today)
+        .map(|meeting| meeting.id.clone())
+        .collect();
+
+    for meeting in &mut meetings {
+        if let Some(name) = &changes.name {
+            meeting.name = name.clone();
+        }
+        if let Some(duration) = changes.duration {
+            meeting.duration = duration;
+        }
+        if let Some(join_window) = changes.join_window {
+            meeting.join_window = join_window;
+        }
+    }
+
+    // Only a longer duration can make an occurrence run into another meeting
+    if changes.duration.is_some() {
+        if let Some((occurrence, conflict)) =
+            find_occurrence_conflict(repo, &studio_id, &meetings, Some(&series.id)).await?
+        {
+            return Err(UpdateMeetingSeriesError::DateAlreadyTaken(
+                occurrence, conflict,
+            ));
+        }
+    }
+
+    repo.update_meetings(&meetings)
+        .await
+        .map_err(UpdateMeetingSeriesError::from)?;
+
+    if changes.duration.is_some() || changes.join_window.is_some() {
+        for meeting_id in &opened {
+            room_manager
+                .delete_room(meeting_id)
+                .await
+                .map_err(UpdateMeetingSeriesError::from)?;
+        }
+    }
+
+    Ok((series, meetings))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use chrono::{Days, TimeDelta, Utc};
+    use mockall::predicate::eq;
+    use std::str::FromStr;
+
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingSeries,
+                MeetingSeriesId, MeetingStatus,
+            },
+            recurrence::RecurrenceRule,
+            studio::StudioId,
+        },
+        ports::output::{meeting_repository::MockMeetingRepository, room_manager::MockRoomManager},
+        services::{update_meeting_series, MeetingSeriesChanges, UpdateMeetingSeriesError},
+    };
+
+    /// Expects the lookup of a daily series of `count` upcoming occurrences
+    pub(crate) fn expect_series(
+        mock_repo: &mut MockMeetingRepository,
+        series_id: &MeetingSeriesId,
+        studio_id: &StudioId,
+        count: u64,
+    ) {
+        let start = Utc::now().checked_add_days(Days::new(1)).unwrap();
+        let owner = studio_id.clone();
+        mock_repo
+            .expect_find_series()
+            .once()
+            .with(eq(series_id.clone()))
+            .return_once(move |series_id| {
+                let series_id = series_id.clone();
+                Box::pin(async move {
+                    Ok(Some(MeetingSeries {
+                        id: series_id,
+                        studio_id: owner,
+                        rule: RecurrenceRule::from_str(&format!("FREQ=DAILY;COUNT={count}"))
+                            .unwrap(),
+                        starts_at: start,
+                    }))
+                })
+            });
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .withf({
+                let series_id = series_id.clone();
+                move |query| query.series_id.as_ref() == Some(&series_id)
+            })
+            .return_once(move |query| {
+                let meetings = (0..count)
+                    .map(|day| Meeting {
+                        id: MeetingId::new(),
+                        studio_id: query.studio_id.clone(),
+                        name: MeetingName::try_from("Stand-up".to_string()).unwrap(),
+                        date: start + TimeDelta::days(day as i64),
+                        duration: MeetingDuration::try_from(15).unwrap(),
+                        join_window: JoinWindow::default(),
+                        status: MeetingStatus::Scheduled,
+                        series_id: query.series_id.clone(),
+                        passcode: None,
+                        waiting_room: false,
+                        max_participants: None,
+                        started_at: None,
+                        ended_at: None,
+                    })
+                    .collect();
+                Box::pin(async move { Ok(meetings) })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_rename_series() {
+        let series_id = MeetingSeriesId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_series(&mut mock_repo, &series_id, &studio_id, 3);
+        mock_repo
+            .expect_update_meetings()
+            .once()
+            .withf(|meetings| {
+                meetings.len() == 3
+                    && meetings
+                        .iter()
+                        .all(|meeting| meeting.name.as_ref() == "Daily sync")
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let changes = MeetingSeriesChanges {
+            name: Some(MeetingName::try_from("Daily sync".to_string()).unwrap()),
+            ..Default::default()
+        };
+        // A new name leaves the rooms as they are
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager.expect_delete_room().never();
+        mock_room_manager.expect_create_room().never();
+
+        update_meeting_series(
+            &mock_repo,
+            &mock_room_manager,
+            series_id,
+            studio_id,
+            changes,
+            Utc::now(),
+        )
+        .await
+        .expect("The series should be renamed");
+    }
+
+    #[tokio::test]
+    async fn test_extend_series_over_itself() {
+        let series_id = MeetingSeriesId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+
+        let mut mock_repo = MockMeetingRepository::new();
+        expect_series(&mut mock_repo, &series_id, &studio_id, 3);
+        // The conflict lookup sees the occurrences of the series themselves
+        let own_series = series_id.clone();
+        mock_repo
+            .expect_list_meetings()
+            .once()
+            .returning(move |query| {
+                let meeting = Meeting {
+                    id: MeetingId::new(),
+                    studio_id: query.studio_id.clone(),
+                    name: MeetingName::try_from("Stand-up".to_string()).unwrap(),
+                    date: query.from.unwrap() + MeetingDuration::MAX,
+                    duration: MeetingDuration::try_from(15).unwrap(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: Some(own_series.clone()),
+                    passcode: None,
+                    waiting_room: false,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(vec![meeting]) })
+            });
+        mock_repo
+            .expect_update_meetings()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let changes = MeetingSeriesChanges {
+            duration: Some(MeetingDuration::try_from(30).unwrap()),
+            ..Default::default()
+        };
+        // Only the room of the first occurrence, which can be joined already, may be open
+        let today = Utc::now() + TimeDelta::days(1);
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_delete_room()
+            .once()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        update_meeting_series(
+            &mock_repo,
+            &mock_room_manager,
+            series_id,
+            studio_id,
+            changes,
+            today,
+        )
+        .await
+        .expect("The series should not conflict with itself");
+    }
+
+    #[tokio::test]
+    async fn test_update_series_of_other_studio() {
+        let series_id = MeetingSeriesId::new();
+
+        let mut mock_repo = MockMeetingRepository::new();
+        let other_studio = StudioId::from(uuid::Uuid::new_v4());
+        mock_repo
+            .expect_find_series()
+            .once()
+            .return_once(move |series_id| {
+                let series_id = series_id.clone();
+                Box::pin(async move {
+                    Ok(Some(MeetingSeries {
+                        id: series_id,
+                        studio_id: other_studio,
+                        rule: RecurrenceRule::from_str("FREQ=DAILY;COUNT=2").unwrap(),
+                        starts_at: Utc::now(),
+                    }))
+                })
+            });
+        mock_repo.expect_update_meetings().never();
+
+        let Err(UpdateMeetingSeriesError::NotFound) = update_meeting_series(
+            &mock_repo,
+            &MockRoomManager::new(),
+            series_id,
+            StudioId::from(uuid::Uuid::new_v4()),
+            MeetingSeriesChanges::default(),
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The series should not be visible from another studio")
+        };
+    }
+}
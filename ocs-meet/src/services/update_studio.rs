@@ -0,0 +1,65 @@
+use chrono_tz::Tz;
+use thiserror::Error;
+
+use crate::{
+    domain::{
+        meeting::MeetingDuration,
+        studio::{Studio, StudioId, StudioName},
+    },
+    ports::output::studio_repository::{StudioRepository, StudioRepositoryError},
+};
+
+#[derive(Debug, Error)]
+pub enum UpdateStudioError {
+    #[error("The studio has not been created")]
+    NotFound,
+    #[error(transparent)]
+    StudioRepository(#[from] StudioRepositoryError),
+}
+
+/// Settings of a studio to change, the ones left to `None` are kept as is
+#[derive(Default)]
+pub struct StudioChanges {
+    pub name: Option<StudioName>,
+    pub timezone: Option<Tz>,
+    pub default_duration: Option<MeetingDuration>,
+}
+
+/// Changes the settings of the studio, the existing meetings are left untouched
+pub async fn update_studio(
+    repo: &impl StudioRepository,
+    studio_id: StudioId,
+    changes: StudioChanges,
+) -> Result<Studio, UpdateStudioError> {
+    let StudioChanges {
+        name,
+        timezone,
+        default_duration,
+    } = changes;
+
+    let Some(mut studio) = repo
+        .find_studio(&studio_id)
+        .await
+        .map_err(UpdateStudioError::from)?
+    else {
+        return Err(UpdateStudioError::NotFound);
+    };
+
+    if let Some(name) = name {
+        studio.name = name;
+    }
+
+    if let Some(timezone) = timezone {
+        studio.timezone = timezone;
+    }
+
+    if let Some(default_duration) = default_duration {
+        studio.default_duration = default_duration;
+    }
+
+    repo.update_studio(&studio)
+        .await
+        .map_err(UpdateStudioError::from)?;
+
+    Ok(studio)
+}
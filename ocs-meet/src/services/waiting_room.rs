@@ -0,0 +1,375 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use super::join_meeting::{enter_room, is_full};
+use crate::{
+    domain::{
+        meeting::{Meeting, MeetingId, MeetingStatus},
+        room::{Participant, ParticipantRole},
+        studio::StudioId,
+        waiting_room::{Admission, AdmissionStatus, WaitingTicket, WaitingTicketId},
+    },
+    ports::output::{
+        meeting_repository::{MeetingRepository, MeetingRepositoryError},
+        room_manager::{RoomManager, RoomManagerError},
+        waiting_room_repository::{WaitingRoomRepository, WaitingRoomRepositoryError},
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum WaitingRoomError {
+    #[error("The meeting you're looking for does not exist")]
+    MeetingNotFound,
+    #[error("Nobody is waiting with this ticket")]
+    TicketNotFound,
+    #[error("The participant has already been let in or turned away")]
+    AlreadyDecided,
+    #[error("It's too late to join the meeting")]
+    TooLate,
+    #[error("The meeting has been cancelled")]
+    Cancelled,
+    #[error("The meeting has ended")]
+    Ended,
+    #[error("The meeting is full")]
+    Full,
+    #[error(transparent)]
+    MeetingRepository(#[from] MeetingRepositoryError),
+    #[error(transparent)]
+    WaitingRoomRepository(#[from] WaitingRoomRepositoryError),
+    #[error(transparent)]
+    RoomManager(#[from] RoomManagerError),
+}
+
+/// Whether the participant has to wait for a host before entering the room of the meeting, the
+/// hosts and recorders never wait
+pub(crate) fn must_wait(meeting: &Meeting, role: ParticipantRole) -> bool {
+    meeting.waiting_room && !role.is_privileged()
+}
+
+/// Puts the participant in the waiting room of the meeting until a host answers
+pub(crate) async fn enter_waiting_room(
+    repository: &impl WaitingRoomRepository,
+    meeting: &Meeting,
+    participant: Participant,
+    role: ParticipantRole,
+    today: DateTime<Utc>,
+) -> Result<WaitingTicket, WaitingRoomRepositoryError> {
+    let ticket = WaitingTicket {
+        id: WaitingTicketId::new(),
+        meeting_id: meeting.id.clone(),
+        studio_id: meeting.studio_id.clone(),
+        participant,
+        role,
+        status: AdmissionStatus::Pending,
+        created_at: today,
+        decided_at: None,
+    };
+
+    repository.create_ticket(&ticket).await?;
+
+    Ok(ticket)
+}
+
+/// Participants waiting to enter a meeting of the studio, in their order of arrival
+pub async fn list_waiting_guests(
+    repository: &(impl MeetingRepository + WaitingRoomRepository),
+    meeting_id: MeetingId,
+    studio_id: StudioId,
+) -> Result<Vec<WaitingTicket>, WaitingRoomError> {
+    let Some(meeting) = repository
+        .find_meeting(&meeting_id)
+        .await
+        .map_err(WaitingRoomError::from)?
+        .filter(|meeting| meeting.studio_id == studio_id)
+    else {
+        return Err(WaitingRoomError::MeetingNotFound);
+    };
+
+    repository
+        .list_tickets(&meeting.id, AdmissionStatus::Pending)
+        .await
+        .map_err(WaitingRoomError::from)
+}
+
+async fn decide(
+    repository: &impl WaitingRoomRepository,
+    meeting_id: MeetingId,
+    ticket_id: WaitingTicketId,
+    studio_id: StudioId,
+    status: AdmissionStatus,
+    today: DateTime<Utc>,
+) -> Result<WaitingTicket, WaitingRoomError> {
+    let Some(mut ticket) = repository
+        .find_ticket(&ticket_id)
+        .await
+        .map_err(WaitingRoomError::from)?
+        .filter(|ticket| ticket.studio_id == studio_id && ticket.meeting_id == meeting_id)
+    else {
+        return Err(WaitingRoomError::TicketNotFound);
+    };
+
+    if ticket.status != AdmissionStatus::Pending {
+        return Err(WaitingRoomError::AlreadyDecided);
+    }
+
+    ticket.status = status;
+    ticket.decided_at = Some(today);
+
+    repository
+        .update_ticket(&ticket)
+        .await
+        .map_err(WaitingRoomError::from)?;
+
+    Ok(ticket)
+}
+
+/// Lets a waiting participant in, they get their token the next time they check their ticket
+pub async fn admit_guest(
+    repository: &impl WaitingRoomRepository,
+    meeting_id: MeetingId,
+    ticket_id: WaitingTicketId,
+    studio_id: StudioId,
+    today: DateTime<Utc>,
+) -> Result<WaitingTicket, WaitingRoomError> {
+    decide(
+        repository,
+        meeting_id,
+        ticket_id,
+        studio_id,
+        AdmissionStatus::Admitted,
+        today,
+    )
+    .await
+}
+
+pub async fn deny_guest(
+    repository: &impl WaitingRoomRepository,
+    meeting_id: MeetingId,
+    ticket_id: WaitingTicketId,
+    studio_id: StudioId,
+    today: DateTime<Utc>,
+) -> Result<WaitingTicket, WaitingRoomError> {
+    decide(
+        repository,
+        meeting_id,
+        ticket_id,
+        studio_id,
+        AdmissionStatus::Denied,
+        today,
+    )
+    .await
+}
+
+/// Where the participant holding the ticket stands, with a token to enter the room once admitted
+/// and as long as the meeting can still be joined
+pub async fn check_admission(
+    repository: &(impl MeetingRepository + WaitingRoomRepository),
+    room_manager: &impl RoomManager,
+    ticket_id: WaitingTicketId,
+    today: DateTime<Utc>,
+) -> Result<Admission, WaitingRoomError> {
+    let Some(ticket) = repository
+        .find_ticket(&ticket_id)
+        .await
+        .map_err(WaitingRoomError::from)?
+    else {
+        return Err(WaitingRoomError::TicketNotFound);
+    };
+
+    if ticket.status != AdmissionStatus::Admitted {
+        return Ok(Admission::Waiting(ticket));
+    }
+
+    let Some(meeting) = repository
+        .find_meeting(&ticket.meeting_id)
+        .await
+        .map_err(WaitingRoomError::from)?
+    else {
+        return Err(WaitingRoomError::MeetingNotFound);
+    };
+
+    match meeting.status {
+        MeetingStatus::Scheduled | MeetingStatus::Live => {}
+        MeetingStatus::Cancelled => return Err(WaitingRoomError::Cancelled),
+        MeetingStatus::Ended => return Err(WaitingRoomError::Ended),
+    }
+
+    if today > meeting.join_closes_at() {
+        return Err(WaitingRoomError::TooLate);
+    }
+
+    if is_full(room_manager, &meeting, Some(&ticket.participant.identity)).await? {
+        return Err(WaitingRoomError::Full);
+    }
+
+    enter_room(
+        room_manager,
+        &meeting,
+        ticket.participant,
+        ticket.role,
+        today,
+    )
+    .await
+    .map(Admission::Granted)
+    .map_err(WaitingRoomError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WaitingRoomError;
+    use crate::{
+        domain::{
+            meeting::{
+                JoinWindow, Meeting, MeetingDuration, MeetingId, MeetingName, MeetingStatus,
+            },
+            room::{Participant, ParticipantRole, RoomToken},
+            studio::StudioId,
+            waiting_room::{Admission, AdmissionStatus, WaitingTicket, WaitingTicketId},
+        },
+        ports::output::{repository::MockRepository, room_manager::MockRoomManager},
+        services::{admit_guest, check_admission, deny_guest},
+    };
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    fn ticket(
+        meeting_id: &MeetingId,
+        studio_id: &StudioId,
+        status: AdmissionStatus,
+    ) -> WaitingTicket {
+        WaitingTicket {
+            id: WaitingTicketId::new(),
+            meeting_id: meeting_id.clone(),
+            studio_id: studio_id.clone(),
+            participant: Participant {
+                identity: "customer-42".to_string(),
+                name: "Jane Doe".to_string(),
+            },
+            role: ParticipantRole::Speaker,
+            status,
+            created_at: Utc::now(),
+            decided_at: None,
+        }
+    }
+
+    fn expect_ticket(mock_repo: &mut MockRepository, ticket: &WaitingTicket) {
+        let ticket = ticket.clone();
+        mock_repo
+            .expect_find_ticket()
+            .with(eq(ticket.id.clone()))
+            .returning(move |_| {
+                let ticket = ticket.clone();
+                Box::pin(async move { Ok(Some(ticket)) })
+            });
+    }
+
+    #[tokio::test]
+    async fn test_admit_and_deny() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let pending = ticket(&meeting_id, &studio_id, AdmissionStatus::Pending);
+        let denied = ticket(&meeting_id, &studio_id, AdmissionStatus::Denied);
+
+        let mut mock_repo = MockRepository::new();
+        expect_ticket(&mut mock_repo, &pending);
+        expect_ticket(&mut mock_repo, &denied);
+        mock_repo
+            .expect_update_ticket()
+            .once()
+            .withf(|ticket| {
+                ticket.status == AdmissionStatus::Admitted && ticket.decided_at.is_some()
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let Err(WaitingRoomError::TicketNotFound) = admit_guest(
+            &mock_repo,
+            meeting_id.clone(),
+            pending.id.clone(),
+            StudioId::from(uuid::Uuid::new_v4()),
+            Utc::now(),
+        )
+        .await
+        else {
+            panic!("The hosts of other studios cannot let anyone in");
+        };
+
+        admit_guest(
+            &mock_repo,
+            meeting_id.clone(),
+            pending.id,
+            studio_id.clone(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        let Err(WaitingRoomError::AlreadyDecided) =
+            deny_guest(&mock_repo, meeting_id, denied.id, studio_id, Utc::now()).await
+        else {
+            panic!("A participant cannot be turned away twice");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_check_admission() {
+        let meeting_id = MeetingId::new();
+        let studio_id = StudioId::from(uuid::Uuid::new_v4());
+        let pending = ticket(&meeting_id, &studio_id, AdmissionStatus::Pending);
+        let admitted = ticket(&meeting_id, &studio_id, AdmissionStatus::Admitted);
+
+        let mut mock_repo = MockRepository::new();
+        expect_ticket(&mut mock_repo, &pending);
+        expect_ticket(&mut mock_repo, &admitted);
+        mock_repo
+            .expect_find_meeting()
+            .once()
+            .with(eq(meeting_id.clone()))
+            .returning(move |meeting_id| {
+                let meeting = Meeting {
+                    id: meeting_id.clone(),
+                    studio_id: StudioId::from(uuid::Uuid::new_v4()),
+                    name: MeetingName::try_from("Hello there!".to_string()).unwrap(),
+                    date: Utc::now(),
+                    duration: MeetingDuration::default(),
+                    join_window: JoinWindow::default(),
+                    status: MeetingStatus::Scheduled,
+                    series_id: None,
+                    passcode: None,
+                    waiting_room: true,
+                    max_participants: None,
+                    started_at: None,
+                    ended_at: None,
+                };
+                Box::pin(async move { Ok(Some(meeting)) })
+            });
+        let mut mock_room_manager = MockRoomManager::new();
+        mock_room_manager
+            .expect_create_room()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_room_manager
+            .expect_create_token()
+            .once()
+            .withf(|_, participant, role| {
+                participant.identity == "customer-42" && *role == ParticipantRole::Speaker
+            })
+            .returning(|_, _, _| Box::pin(async { Ok(RoomToken::from("mytoken".to_string())) }));
+
+        let Admission::Waiting(ticket) =
+            check_admission(&mock_repo, &mock_room_manager, pending.id, Utc::now())
+                .await
+                .unwrap()
+        else {
+            panic!("The participant should still be waiting");
+        };
+        assert_eq!(ticket.status, AdmissionStatus::Pending);
+
+        let Admission::Granted(token) =
+            check_admission(&mock_repo, &mock_room_manager, admitted.id, Utc::now())
+                .await
+                .unwrap()
+        else {
+            panic!("The participant should get a token");
+        };
+        assert_eq!(token.as_ref(), "mytoken");
+    }
+}